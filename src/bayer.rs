@@ -0,0 +1,371 @@
+//! Raw images from sensors behind a Bayer color filter array, before they are demosaiced.
+
+use core::fmt;
+
+/// A color of the filter in front of a pixel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BayerColor {
+    Red,
+    Green,
+    Blue,
+}
+
+impl BayerColor {
+    /// The index of the color in an RGB pixel.
+    fn channel(self) -> usize {
+        match self {
+            Self::Red => 0,
+            Self::Green => 1,
+            Self::Blue => 2,
+        }
+    }
+}
+
+/// The colors of the 2x2 tile that repeats over a Bayer image, named in row-major order from the top left.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BayerPattern {
+    Rggb,
+    Bggr,
+    Grbg,
+    Gbrg,
+}
+
+impl BayerPattern {
+    /// The colors of the tile, in (row, col).
+    ///
+    /// ```
+    /// use nshare::{BayerColor, BayerPattern};
+    ///
+    /// assert_eq!(BayerPattern::Grbg.tile()[1][0], BayerColor::Blue);
+    /// ```
+    pub fn tile(self) -> [[BayerColor; 2]; 2] {
+        use BayerColor::{Blue as B, Green as G, Red as R};
+        match self {
+            Self::Rggb => [[R, G], [G, B]],
+            Self::Bggr => [[B, G], [G, R]],
+            Self::Grbg => [[G, R], [B, G]],
+            Self::Gbrg => [[G, B], [R, G]],
+        }
+    }
+
+    /// The color of the filter in front of the pixel at column `x` and row `y`.
+    pub fn color_at(self, x: usize, y: usize) -> BayerColor {
+        self.tile()[y % 2][x % 2]
+    }
+}
+
+/// The algorithm used to fill in the two missing colors of every pixel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Demosaic {
+    /// Averages the nearest samples of each color. This is fast, but blurs and fringes edges.
+    #[default]
+    Bilinear,
+    /// Interpolates green along the direction with the smaller gradient, then interpolates the
+    /// differences between red or blue and green, which keeps edges sharp and avoids most color fringes.
+    EdgeAware,
+}
+
+mod sealed {
+    pub trait Sealed: Copy + Default {
+        fn to_f32(self) -> f32;
+        fn from_f32(value: f32) -> Self;
+    }
+}
+
+/// A type of sample of a Bayer image, which is an 8-bit or a 16-bit integer.
+///
+/// Samples of 10, 12 or 14 bits are stored in `u16`.
+pub trait BayerSample: sealed::Sealed {}
+
+macro_rules! bayer_sample {
+    ($($t:ty),*) => {
+        $(
+            impl sealed::Sealed for $t {
+                fn to_f32(self) -> f32 {
+                    f32::from(self)
+                }
+
+                fn from_f32(value: f32) -> Self {
+                    (value.clamp(0.0, f32::from(<$t>::MAX)) + 0.5) as $t
+                }
+            }
+
+            impl BayerSample for $t {}
+        )*
+    };
+}
+
+bayer_sample!(u8, u16);
+
+/// The reason a layout was rejected by the constructors of [`BayerImage`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BayerError {
+    /// Computing the size of the image overflowed.
+    Overflow,
+    /// Rows of `width` samples do not fit in the `stride`.
+    Stride { stride: usize, width: usize },
+    /// The last row ends at `end`, past the end of a slice of `len` samples.
+    OutOfBounds { end: usize, len: usize },
+}
+
+impl fmt::Display for BayerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Overflow => write!(f, "the size of the image overflows"),
+            Self::Stride { stride, width } => write!(
+                f,
+                "rows of {width} samples do not fit in a stride of {stride}"
+            ),
+            Self::OutOfBounds { end, len } => write!(
+                f,
+                "the last row ends at {end}, but the slice has {len} samples"
+            ),
+        }
+    }
+}
+
+impl core::error::Error for BayerError {}
+
+/// A borrowed raw image with one sample per pixel, behind the filter colors of a [`BayerPattern`].
+///
+/// Each of the four positions in the 2x2 tile of the pattern forms a sub-plane that can be viewed
+/// as a ndarray array without copying, and the image can be demosaiced into RGB.
+#[derive(Debug, Clone, Copy)]
+pub struct BayerImage<'a, T> {
+    data: &'a [T],
+    width: usize,
+    height: usize,
+    stride: usize,
+    pattern: BayerPattern,
+}
+
+impl<'a, T> BayerImage<'a, T>
+where
+    T: BayerSample,
+{
+    /// Uses rows of `width` samples without padding.
+    ///
+    /// ```
+    /// use nshare::{BayerError, BayerImage, BayerPattern};
+    ///
+    /// let raw = [0u16; 12];
+    /// assert!(BayerImage::new(&raw, 4, 3, BayerPattern::Rggb).is_ok());
+    /// assert_eq!(
+    ///     BayerImage::new(&raw, 4, 4, BayerPattern::Rggb).unwrap_err(),
+    ///     BayerError::OutOfBounds { end: 16, len: 12 },
+    /// );
+    /// ```
+    pub fn new(
+        data: &'a [T],
+        width: usize,
+        height: usize,
+        pattern: BayerPattern,
+    ) -> Result<Self, BayerError> {
+        Self::with_stride(data, width, height, width, pattern)
+    }
+
+    /// Uses rows that start `stride` samples apart.
+    pub fn with_stride(
+        data: &'a [T],
+        width: usize,
+        height: usize,
+        stride: usize,
+        pattern: BayerPattern,
+    ) -> Result<Self, BayerError> {
+        if width > stride {
+            return Err(BayerError::Stride { stride, width });
+        }
+        if width > 0 && height > 0 {
+            let end = stride
+                .checked_mul(height - 1)
+                .and_then(|last| last.checked_add(width))
+                .ok_or(BayerError::Overflow)?;
+            if end > data.len() {
+                return Err(BayerError::OutOfBounds {
+                    end,
+                    len: data.len(),
+                });
+            }
+        }
+        Ok(Self {
+            data,
+            width,
+            height,
+            stride,
+            pattern,
+        })
+    }
+
+    /// The width of the image in pixels.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// The height of the image in pixels.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The distance in samples between the starts of neighboring rows.
+    pub fn stride(&self) -> usize {
+        self.stride
+    }
+
+    /// The pattern of the filter colors.
+    pub fn pattern(&self) -> BayerPattern {
+        self.pattern
+    }
+
+    /// The samples the image refers to.
+    pub fn data(&self) -> &'a [T] {
+        self.data
+    }
+
+    /// The sample of the pixel at column `x` and row `y`.
+    pub fn sample(&self, x: usize, y: usize) -> T {
+        assert!(
+            x < self.width && y < self.height,
+            "the pixel must be in the image"
+        );
+        self.data[y * self.stride + x]
+    }
+
+    /// Demosaics the image into interleaved RGB subpixels in row-major order, written to `rgb`.
+    ///
+    /// Samples past the borders are mirrored, so the image must be at least 2x2 pixels.
+    ///
+    /// ```
+    /// use nshare::{BayerImage, BayerPattern, Demosaic};
+    ///
+    /// // A flat gray scene looks the same through every filter.
+    /// let raw = [100u8; 16];
+    /// let bayer = BayerImage::new(&raw, 4, 4, BayerPattern::Bggr).unwrap();
+    /// let mut rgb = [0u8; 48];
+    /// bayer.demosaic_into(Demosaic::EdgeAware, &mut rgb);
+    /// assert!(rgb.iter().all(|&x| x == 100));
+    /// ```
+    pub fn demosaic_into(&self, algorithm: Demosaic, rgb: &mut [T]) {
+        let (width, height) = (self.width, self.height);
+        assert_eq!(
+            rgb.len(),
+            width * height * 3,
+            "the output must hold three subpixels for every pixel"
+        );
+        assert!(
+            width >= 2 && height >= 2,
+            "demosaicing needs at least 2x2 pixels"
+        );
+        // Green is filled in first, since the edge-aware algorithm interpolates red and blue relative to it.
+        for y in 0..height {
+            for x in 0..width {
+                let green = match (self.pattern.color_at(x, y), algorithm) {
+                    (BayerColor::Green, _) => self.sample(x, y),
+                    (_, Demosaic::Bilinear) => self.neighborhood_mean(x, y, BayerColor::Green),
+                    (_, Demosaic::EdgeAware) => self.directional_green(x, y),
+                };
+                rgb[(y * width + x) * 3 + 1] = green;
+            }
+        }
+        for y in 0..height {
+            for x in 0..width {
+                let color = self.pattern.color_at(x, y);
+                for other in [BayerColor::Red, BayerColor::Blue] {
+                    let value = match (other == color, algorithm) {
+                        (true, _) => self.sample(x, y),
+                        (false, Demosaic::Bilinear) => self.neighborhood_mean(x, y, other),
+                        (false, Demosaic::EdgeAware) => self.difference_mean(rgb, x, y, other),
+                    };
+                    rgb[(y * width + x) * 3 + other.channel()] = value;
+                }
+            }
+        }
+    }
+
+    /// The pixel at an offset from a pixel, with the borders mirrored so the filter color is preserved.
+    fn neighbor(&self, x: usize, y: usize, dx: isize, dy: isize) -> (usize, usize) {
+        let mirror = |position: usize, delta: isize, len: usize| {
+            let (position, last) = (position as isize + delta, len as isize - 1);
+            let mut position = if position < 0 {
+                -position
+            } else if position > last {
+                2 * last - position
+            } else {
+                position
+            };
+            // Tiny images can mirror out of range again; step by two so the parity, and with it the color, is kept.
+            while position > last && position >= 2 {
+                position -= 2;
+            }
+            while position < 0 && position + 2 <= last {
+                position += 2;
+            }
+            position.clamp(0, last) as usize
+        };
+        (mirror(x, dx, self.width), mirror(y, dy, self.height))
+    }
+
+    /// The sample at an offset from a pixel, with the borders mirrored.
+    fn at(&self, x: usize, y: usize, dx: isize, dy: isize) -> f32 {
+        let (x, y) = self.neighbor(x, y, dx, dy);
+        self.sample(x, y).to_f32()
+    }
+
+    /// The pixels of `color` in the 3x3 neighborhood of a pixel.
+    fn neighbors_of(
+        &self,
+        x: usize,
+        y: usize,
+        color: BayerColor,
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        (-1..=1)
+            .flat_map(|dy| (-1..=1).map(move |dx| (dx, dy)))
+            .map(move |(dx, dy)| self.neighbor(x, y, dx, dy))
+            .filter(move |&(x, y)| self.pattern.color_at(x, y) == color)
+    }
+
+    /// The mean of the samples of `color` in the 3x3 neighborhood of a pixel.
+    fn neighborhood_mean(&self, x: usize, y: usize, color: BayerColor) -> T {
+        let (sum, count) = self
+            .neighbors_of(x, y, color)
+            .fold((0.0, 0.0), |(sum, count), (x, y)| {
+                (sum + self.sample(x, y).to_f32(), count + 1.0)
+            });
+        T::from_f32(sum / count)
+    }
+
+    /// Green at a red or blue pixel, interpolated along the direction with the smaller gradient,
+    /// with a correction from the curvature of the pixel's own color.
+    fn directional_green(&self, x: usize, y: usize) -> T {
+        let at = |dx, dy| self.at(x, y, dx, dy);
+        let center = at(0, 0);
+        let horizontal_curvature = 2.0 * center - at(-2, 0) - at(2, 0);
+        let vertical_curvature = 2.0 * center - at(0, -2) - at(0, 2);
+        let horizontal_gradient = (at(-1, 0) - at(1, 0)).abs() + horizontal_curvature.abs();
+        let vertical_gradient = (at(0, -1) - at(0, 1)).abs() + vertical_curvature.abs();
+        let horizontal = (at(-1, 0) + at(1, 0)) / 2.0 + horizontal_curvature / 4.0;
+        let vertical = (at(0, -1) + at(0, 1)) / 2.0 + vertical_curvature / 4.0;
+        let green = if horizontal_gradient < vertical_gradient {
+            horizontal
+        } else if vertical_gradient < horizontal_gradient {
+            vertical
+        } else {
+            (horizontal + vertical) / 2.0
+        };
+        T::from_f32(green)
+    }
+
+    /// `color` at a pixel, from the mean difference between `color` and the already interpolated green
+    /// of the samples of `color` in its 3x3 neighborhood.
+    fn difference_mean(&self, rgb: &[T], x: usize, y: usize, color: BayerColor) -> T {
+        let green_at = |x: usize, y: usize| rgb[(y * self.width + x) * 3 + 1].to_f32();
+        let (sum, count) =
+            self.neighbors_of(x, y, color)
+                .fold((0.0, 0.0), |(sum, count), (x, y)| {
+                    (
+                        sum + self.sample(x, y).to_f32() - green_at(x, y),
+                        count + 1.0,
+                    )
+                });
+        T::from_f32(green_at(x, y) + sum / count)
+    }
+}
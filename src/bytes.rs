@@ -0,0 +1,225 @@
+//! Byte buffers interpreted as shaped arrays of numbers, as they come from files and the network.
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+use crate::{StridedError, StridedSlice};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::{fmt, marker::PhantomData, mem};
+
+/// The order of the bytes of each element in a buffer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ByteOrder {
+    /// The least significant byte comes first.
+    Little,
+    /// The most significant byte comes first.
+    Big,
+}
+
+impl ByteOrder {
+    /// The byte order of the target.
+    #[cfg(target_endian = "little")]
+    pub const NATIVE: Self = Self::Little;
+    /// The byte order of the target.
+    #[cfg(target_endian = "big")]
+    pub const NATIVE: Self = Self::Big;
+}
+
+mod sealed {
+    use super::ByteOrder;
+
+    pub trait Sealed: Sized {
+        fn read(bytes: &[u8], order: ByteOrder) -> Self;
+    }
+}
+
+/// A primitive number that any bytes are a valid representation of.
+///
+/// This is sealed, since borrowing bytes as elements and elements as bytes is only sound for types
+/// without padding or invalid bit patterns.
+pub trait ByteElement: Copy + sealed::Sealed + 'static {}
+
+macro_rules! byte_element {
+    ($($t:ty),*) => {
+        $(
+            impl sealed::Sealed for $t {
+                fn read(bytes: &[u8], order: ByteOrder) -> Self {
+                    let bytes = bytes.try_into().unwrap();
+                    match order {
+                        ByteOrder::Little => <$t>::from_le_bytes(bytes),
+                        ByteOrder::Big => <$t>::from_be_bytes(bytes),
+                    }
+                }
+            }
+
+            impl ByteElement for $t {}
+        )*
+    };
+}
+
+byte_element!(u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, f32, f64);
+
+/// The reason bytes could not be interpreted by [`TypedBytes`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BytesError {
+    /// The number of bytes needed for the shape overflowed.
+    Overflow,
+    /// The shape needs `expected` bytes, but the buffer has `len`.
+    Length { expected: usize, len: usize },
+    /// The bytes are not in the byte order of the target, so they can only be read by copying.
+    ByteOrder { order: ByteOrder },
+    /// The bytes do not start at a multiple of `align`, so they can only be read by copying.
+    Misaligned { align: usize },
+}
+
+impl fmt::Display for BytesError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Overflow => write!(f, "the size of the shape in bytes overflows"),
+            Self::Length { expected, len } => write!(
+                f,
+                "the shape needs {expected} bytes, but the buffer has {len}"
+            ),
+            Self::ByteOrder { order } => write!(
+                f,
+                "the bytes are in {order:?} endian order, but the target is not"
+            ),
+            Self::Misaligned { align } => write!(
+                f,
+                "the bytes are not aligned to a multiple of {align} bytes"
+            ),
+        }
+    }
+}
+
+impl core::error::Error for BytesError {}
+
+/// Bytes holding elements of type `T` in row-major order with `N` axes.
+///
+/// When the bytes are aligned for `T` and in the byte order of the target, [`TypedBytes::as_strided`]
+/// borrows them without copying, and the result converts to ndarray, nalgebra and image types.
+/// Otherwise [`TypedBytes::to_vec`] copies them, which swaps the bytes and realigns the elements.
+/// The ndarray and nalgebra conversions pick whichever of the two works and return a [`ndarray::CowArray`]
+/// or a [`CowMatrix`](crate::CowMatrix).
+///
+/// ```
+/// use nshare::{ByteOrder, IntoNalgebra, TypedBytes};
+///
+/// let floats = [1.0f32, 2.0, 3.0, 4.0, 5.0, 6.0];
+/// let bytes: Vec<u8> = floats.iter().flat_map(|x| x.to_ne_bytes()).collect();
+/// let typed = TypedBytes::<f32, 2>::new(&bytes, [2, 3], ByteOrder::NATIVE).unwrap();
+/// // A `Vec<u8>` is not guaranteed to be aligned for `f32`, so this may be a copy.
+/// let m = typed.into_nalgebra();
+/// assert_eq!(m.view()[(1, 0)], 4.0);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct TypedBytes<'a, T, const N: usize> {
+    bytes: &'a [u8],
+    shape: [usize; N],
+    order: ByteOrder,
+    element: PhantomData<T>,
+}
+
+impl<'a, T, const N: usize> TypedBytes<'a, T, N>
+where
+    T: ByteElement,
+{
+    /// Checks that `bytes` holds exactly the elements of `shape`.
+    ///
+    /// ```
+    /// use nshare::{ByteOrder, BytesError, TypedBytes};
+    ///
+    /// let bytes = [0u8; 10];
+    /// assert!(TypedBytes::<u16, 2>::new(&bytes, [5, 1], ByteOrder::Big).is_ok());
+    /// assert_eq!(
+    ///     TypedBytes::<u16, 2>::new(&bytes, [2, 3], ByteOrder::Big).unwrap_err(),
+    ///     BytesError::Length { expected: 12, len: 10 },
+    /// );
+    /// ```
+    pub fn new(bytes: &'a [u8], shape: [usize; N], order: ByteOrder) -> Result<Self, BytesError> {
+        let expected = shape
+            .iter()
+            .try_fold(mem::size_of::<T>(), |acc, &len| acc.checked_mul(len))
+            .ok_or(BytesError::Overflow)?;
+        if expected != bytes.len() {
+            return Err(BytesError::Length {
+                expected,
+                len: bytes.len(),
+            });
+        }
+        Ok(Self {
+            bytes,
+            shape,
+            order,
+            element: PhantomData,
+        })
+    }
+
+    /// The length of each axis.
+    pub fn shape(&self) -> [usize; N] {
+        self.shape
+    }
+
+    /// The byte order of the elements.
+    pub fn order(&self) -> ByteOrder {
+        self.order
+    }
+
+    /// The underlying bytes.
+    pub fn bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+
+    /// Borrows the bytes as elements, if they are in the byte order of the target and aligned for `T`.
+    ///
+    /// ```
+    /// use nshare::{ByteOrder, BytesError, IntoNdarray2, TypedBytes};
+    ///
+    /// let values = [1u16, 2, 3, 4];
+    /// let bytes: Vec<u8> = values.iter().flat_map(|x| x.to_be_bytes()).collect();
+    /// let typed = TypedBytes::<u16, 2>::new(&bytes, [2, 2], ByteOrder::Big).unwrap();
+    /// match typed.as_strided() {
+    ///     Ok(s) => assert_eq!(s.into_ndarray2(), ndarray::array![[1, 2], [3, 4]]),
+    ///     Err(err) => assert!(matches!(
+    ///         err,
+    ///         BytesError::ByteOrder { .. } | BytesError::Misaligned { align: 2 }
+    ///     )),
+    /// }
+    /// ```
+    pub fn as_strided(&self) -> Result<StridedSlice<'a, T, N>, BytesError> {
+        if mem::size_of::<T>() > 1 && self.order != ByteOrder::NATIVE {
+            return Err(BytesError::ByteOrder { order: self.order });
+        }
+        let align = mem::align_of::<T>();
+        if self.bytes.as_ptr().align_offset(align) != 0 {
+            return Err(BytesError::Misaligned { align });
+        }
+        let len = self.bytes.len() / mem::size_of::<T>();
+        // SAFETY: The pointer is aligned for `T`, the bytes cover `len` elements, and `ByteElement` is only
+        // implemented for primitive numbers, which have no padding and no invalid bit patterns.
+        let data = unsafe { core::slice::from_raw_parts(self.bytes.as_ptr().cast::<T>(), len) };
+        StridedSlice::contiguous(data, self.shape).map_err(|err| match err {
+            StridedError::Overflow => BytesError::Overflow,
+            _ => unreachable!("the bytes hold exactly the elements of the shape"),
+        })
+    }
+
+    /// Copies the elements in row-major order, swapping their bytes into the byte order of the target.
+    ///
+    /// ```
+    /// use nshare::{ByteOrder, TypedBytes};
+    ///
+    /// // One byte of padding in front, so the elements are misaligned.
+    /// let bytes = [0u8, 0x12, 0x34, 0x56, 0x78];
+    /// let typed = TypedBytes::<u16, 1>::new(&bytes[1..], [2], ByteOrder::Little).unwrap();
+    /// assert_eq!(typed.to_vec(), vec![0x3412, 0x7856]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn to_vec(&self) -> Vec<T> {
+        self.bytes
+            .chunks_exact(mem::size_of::<T>())
+            .map(|chunk| T::read(chunk, self.order))
+            .collect()
+    }
+}
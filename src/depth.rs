@@ -0,0 +1,54 @@
+//! Pinhole cameras that turn depth maps into points and points back into depth maps.
+
+use nalgebra::Matrix3;
+
+/// A pinhole camera that took a depth map.
+///
+/// The depth of a pixel is its raw value times `depth_scale`, measured along the optical axis. A point
+/// `(x, y, z)` in the frame of the camera is seen at the pixel with `col = u / w` and `row = v / w`,
+/// where `(u, v, w)` is the intrinsic matrix times the point.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct DepthCamera {
+    /// The intrinsic matrix, usually `[[fx, 0, cx], [0, fy, cy], [0, 0, 1]]`.
+    pub intrinsics: Matrix3<f32>,
+    /// The factor raw depths are multiplied by, such as `0.001` for depths in millimeters.
+    pub depth_scale: f32,
+    /// The smallest valid depth after scaling.
+    pub min_depth: f32,
+    /// The largest valid depth after scaling.
+    pub max_depth: f32,
+}
+
+impl DepthCamera {
+    /// A camera that accepts every positive finite depth.
+    pub fn new(intrinsics: Matrix3<f32>, depth_scale: f32) -> Self {
+        Self {
+            intrinsics,
+            depth_scale,
+            min_depth: 0.0,
+            max_depth: f32::INFINITY,
+        }
+    }
+
+    /// Whether a depth after scaling is in the valid range.
+    ///
+    /// Zero, negative and non-finite depths are never valid, since depth sensors use them to mark
+    /// pixels without a measurement.
+    ///
+    /// ```
+    /// use nalgebra::Matrix3;
+    /// use nshare::DepthCamera;
+    ///
+    /// let camera = DepthCamera {
+    ///     max_depth: 5.0,
+    ///     ..DepthCamera::new(Matrix3::identity(), 0.001)
+    /// };
+    /// assert!(camera.is_valid(1.5));
+    /// assert!(!camera.is_valid(0.0));
+    /// assert!(!camera.is_valid(6.0));
+    /// assert!(!camera.is_valid(f32::NAN));
+    /// ```
+    pub fn is_valid(&self, depth: f32) -> bool {
+        depth > 0.0 && depth.is_finite() && depth >= self.min_depth && depth <= self.max_depth
+    }
+}
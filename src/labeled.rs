@@ -0,0 +1,373 @@
+//! Arrays whose axes carry their meaning in the type.
+//!
+//! Images index pixels as `(x, y)`, while ndarray indexes them as `(row, col)`, and the 3d conversions put
+//! the channels first. [`Labeled`] records which axis is which, so that permuting the axes or looking up a
+//! pixel by `(x, y)` goes through the labels instead of through positions that are easy to mix up.
+
+use core::fmt;
+use core::marker::PhantomData;
+use ndarray::{
+    ArrayBase, ArrayView, ArrayViewMut, Axis, Data, DataMut, Dimension, Ix2, Ix3, Ix4, OwnedRepr,
+    RawData, RawDataClone, RemoveAxis, ViewRepr,
+};
+
+mod sealed {
+    pub trait AxisLabel {
+        const ID: u8;
+    }
+
+    pub trait Layout {
+        const IDS: &'static [u8];
+    }
+}
+
+/// The meaning of an axis of a [`Labeled`] array.
+///
+/// This is implemented by [`Batch`], [`Z`], [`Channel`], [`Row`] and [`Col`], and cannot be implemented
+/// outside of this crate.
+pub trait AxisLabel: sealed::AxisLabel {}
+
+/// The axis along which a batch of independent arrays is stacked.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Batch;
+
+/// The axis along which the slices of a volume are stacked, which is `z` in `(z, y, x)`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Z;
+
+/// The axis of the color channels, or other samples, of a pixel.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Channel;
+
+/// The vertical axis, which is `y` in `(x, y)`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Row;
+
+/// The horizontal axis, which is `x` in `(x, y)`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Col;
+
+macro_rules! axis_labels {
+    ($($label:ident = $id:literal),*) => {
+        $(
+            impl sealed::AxisLabel for $label {
+                const ID: u8 = $id;
+            }
+
+            impl AxisLabel for $label {}
+        )*
+    };
+}
+
+axis_labels!(Batch = 0, Z = 1, Channel = 2, Row = 3, Col = 4);
+
+/// The labels of the axes of a [`Labeled`] array, as a tuple of [`AxisLabel`]s from the outermost axis to the
+/// innermost one, such as `(Channel, Row, Col)`.
+///
+/// A label may appear only once in a layout, which is checked when the layout is used.
+pub trait Layout: sealed::Layout {
+    /// The dimension of arrays with this layout.
+    type Dim: Dimension;
+}
+
+macro_rules! layouts {
+    ($($dim:ty => ($($label:ident),*);)*) => {
+        $(
+            impl<$($label: AxisLabel),*> sealed::Layout for ($($label,)*) {
+                const IDS: &'static [u8] = &[$(<$label as sealed::AxisLabel>::ID),*];
+            }
+
+            impl<$($label: AxisLabel),*> Layout for ($($label,)*) {
+                type Dim = $dim;
+            }
+        )*
+    };
+}
+
+layouts! {
+    Ix2 => (A, B);
+    Ix3 => (A, B, C);
+    Ix4 => (A, B, C, D);
+}
+
+/// The position of `id` in `ids`.
+const fn position(ids: &[u8], id: u8) -> Option<usize> {
+    let mut i = 0;
+    while i < ids.len() {
+        if ids[i] == id {
+            return Some(i);
+        }
+        i += 1;
+    }
+    None
+}
+
+/// Whether no label appears twice in `ids`.
+const fn distinct(ids: &[u8]) -> bool {
+    let mut i = 0;
+    while i < ids.len() {
+        match position(ids, ids[i]) {
+            Some(first) if first != i => return false,
+            _ => i += 1,
+        }
+    }
+    true
+}
+
+/// Whether `to` has the same labels as `from`, assuming that both are distinct.
+const fn same_labels(from: &[u8], to: &[u8]) -> bool {
+    if from.len() != to.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < to.len() {
+        if position(from, to[i]).is_none() {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// The axis labelled `A` in layout `L`, which must contain it.
+const fn axis_of<L: Layout, A: AxisLabel>() -> usize {
+    match position(L::IDS, A::ID) {
+        Some(axis) => axis,
+        None => panic!("the layout must contain the axis"),
+    }
+}
+
+/// The dimension of everything at a pixel of an array with layout `L`, which has two axes less.
+type PixelDim<L> = <<<L as Layout>::Dim as Dimension>::Smaller as Dimension>::Smaller;
+
+/// An ndarray array together with the meaning of each of its axes.
+///
+/// The layout `L` is a tuple of [`AxisLabel`]s, such as `(Channel, Row, Col)` for the arrays that
+/// [`IntoNdarray3`](crate::IntoNdarray3) makes out of images. Operations that need a label check at compile
+/// time that the layout has it, so asking for the pixel at `(x, y)` of an array without a [`Row`] axis
+/// does not build.
+///
+/// ```
+/// use image::{Rgb, RgbImage};
+/// use nshare::{AsNdarrayLabeled, Channel, Col, Row};
+///
+/// let mut img = RgbImage::new(4, 2);
+/// img[(3, 1)] = Rgb([10, 20, 30]);
+/// let labeled = img.as_ndarray_labeled();
+/// // The view is in `(channel, row, col)`, but nobody has to remember that.
+/// assert_eq!(labeled.len_of::<Col>(), 4);
+/// assert_eq!(labeled.pixel((3, 1)).unwrap().to_vec(), [10, 20, 30]);
+/// let interleaved = labeled.permute_to::<(Row, Col, Channel)>();
+/// assert_eq!(interleaved.as_array().dim(), (2, 4, 3));
+/// ```
+pub struct Labeled<S: RawData, L: Layout> {
+    array: ArrayBase<S, L::Dim>,
+    layout: PhantomData<L>,
+}
+
+/// A [`Labeled`] array that owns its elements.
+pub type LabeledArray<A, L> = Labeled<OwnedRepr<A>, L>;
+
+/// A [`Labeled`] array that borrows its elements.
+pub type LabeledView<'a, A, L> = Labeled<ViewRepr<&'a A>, L>;
+
+/// A [`Labeled`] array that mutably borrows its elements.
+pub type LabeledViewMut<'a, A, L> = Labeled<ViewRepr<&'a mut A>, L>;
+
+impl<S: RawData, L: Layout> Labeled<S, L> {
+    /// Labels the axes of `array`, from the outermost to the innermost one.
+    ///
+    /// ```compile_fail
+    /// use nshare::{Labeled, Row};
+    ///
+    /// // A layout cannot have two rows.
+    /// let labeled = Labeled::<_, (Row, Row)>::new(ndarray::Array2::<u8>::zeros((2, 2)));
+    /// ```
+    pub fn new(array: ArrayBase<S, L::Dim>) -> Self {
+        const { assert!(distinct(L::IDS), "a layout must not repeat an axis") };
+        Self {
+            array,
+            layout: PhantomData,
+        }
+    }
+
+    /// The array without its labels.
+    pub fn as_array(&self) -> &ArrayBase<S, L::Dim> {
+        &self.array
+    }
+
+    /// Drops the labels.
+    pub fn into_array(self) -> ArrayBase<S, L::Dim> {
+        self.array
+    }
+
+    /// The position of the axis labelled `A`.
+    ///
+    /// This does not build if the layout has no such axis.
+    pub fn axis<A: AxisLabel>(&self) -> Axis {
+        Axis(const { axis_of::<L, A>() })
+    }
+
+    /// The length of the axis labelled `A`.
+    ///
+    /// This does not build if the layout has no such axis.
+    pub fn len_of<A: AxisLabel>(&self) -> usize {
+        self.array.len_of(self.axis::<A>())
+    }
+
+    /// Moves the axes into the order of layout `M`, without copying the elements.
+    ///
+    /// This does not build unless `M` has exactly the labels of `L`.
+    ///
+    /// ```
+    /// use nalgebra::Matrix2x3;
+    /// use nshare::{AsNdarrayLabeled, Col, Row};
+    ///
+    /// let m = Matrix2x3::new(1, 2, 3, 4, 5, 6);
+    /// let transposed = m.as_ndarray_labeled().permute_to::<(Col, Row)>();
+    /// assert_eq!(transposed.as_array().dim(), (3, 2));
+    /// assert_eq!(transposed.get((2, 0)), Some(&3));
+    /// ```
+    ///
+    /// ```compile_fail
+    /// use nalgebra::Matrix2x3;
+    /// use nshare::{AsNdarrayLabeled, Channel, Col};
+    ///
+    /// let m = Matrix2x3::new(1, 2, 3, 4, 5, 6);
+    /// // A matrix has no channel axis.
+    /// m.as_ndarray_labeled().permute_to::<(Col, Channel)>();
+    /// ```
+    pub fn permute_to<M: Layout<Dim = L::Dim>>(self) -> Labeled<S, M> {
+        const {
+            assert!(distinct(M::IDS), "a layout must not repeat an axis");
+            assert!(
+                same_labels(L::IDS, M::IDS),
+                "the layouts must have the same axes"
+            );
+        };
+        let mut axes = L::Dim::zeros(M::IDS.len());
+        for (axis, &id) in axes.slice_mut().iter_mut().zip(M::IDS) {
+            *axis = position(L::IDS, id).unwrap();
+        }
+        Labeled::new(self.array.permuted_axes(axes))
+    }
+
+    /// The positions of the row and column axes, and the index along them of `xy`, if it is in bounds.
+    fn locate(&self, xy: [usize; 2]) -> Option<[(usize, usize); 2]> {
+        let [x, y] = xy;
+        let row = const { axis_of::<L, Row>() };
+        let col = const { axis_of::<L, Col>() };
+        let shape = self.array.shape();
+        (x < shape[col] && y < shape[row]).then_some([(row, y), (col, x)])
+    }
+}
+
+impl<S: Data, L: Layout> Labeled<S, L> {
+    /// Borrows the array with the same labels.
+    pub fn view(&self) -> LabeledView<'_, S::Elem, L> {
+        Labeled::new(self.array.view())
+    }
+
+    /// Everything at pixel `(x, y)`, such as its channels, or `None` if it is out of bounds.
+    ///
+    /// This takes anything that converts to `[x, y]`, which includes tuples and nalgebra's `Point2<usize>`.
+    /// It does not build if the layout has no [`Row`] or [`Col`] axis.
+    ///
+    /// ```
+    /// use image::{GrayImage, Luma};
+    /// use nalgebra::Point2;
+    /// use nshare::AsNdarrayLabeled;
+    ///
+    /// let mut img = GrayImage::new(4, 2);
+    /// img[(3, 1)] = Luma([7]);
+    /// let labeled = img.as_ndarray_labeled();
+    /// assert_eq!(labeled.pixel(Point2::new(3, 1)).unwrap()[0], 7);
+    /// assert!(labeled.pixel((1, 3)).is_none());
+    /// ```
+    pub fn pixel(&self, xy: impl Into<[usize; 2]>) -> Option<ArrayView<'_, S::Elem, PixelDim<L>>>
+    where
+        L::Dim: RemoveAxis,
+        <L::Dim as Dimension>::Smaller: RemoveAxis,
+    {
+        let [outer, inner] = sorted(self.locate(xy.into())?);
+        let view = self.array.view().index_axis_move(Axis(inner.0), inner.1);
+        Some(view.index_axis_move(Axis(outer.0), outer.1))
+    }
+}
+
+impl<S: DataMut, L: Layout> Labeled<S, L> {
+    /// Mutably borrows the array with the same labels.
+    pub fn view_mut(&mut self) -> LabeledViewMut<'_, S::Elem, L> {
+        Labeled::new(self.array.view_mut())
+    }
+
+    /// Mutably borrows everything at pixel `(x, y)`, or returns `None` if it is out of bounds.
+    ///
+    /// This does not build if the layout has no [`Row`] or [`Col`] axis.
+    pub fn pixel_mut(
+        &mut self,
+        xy: impl Into<[usize; 2]>,
+    ) -> Option<ArrayViewMut<'_, S::Elem, PixelDim<L>>>
+    where
+        L::Dim: RemoveAxis,
+        <L::Dim as Dimension>::Smaller: RemoveAxis,
+    {
+        let [outer, inner] = sorted(self.locate(xy.into())?);
+        let view = self
+            .array
+            .view_mut()
+            .index_axis_move(Axis(inner.0), inner.1);
+        Some(view.index_axis_move(Axis(outer.0), outer.1))
+    }
+}
+
+impl<S: Data, L: Layout<Dim = Ix2>> Labeled<S, L> {
+    /// The element at `(x, y)`, or `None` if it is out of bounds.
+    ///
+    /// This does not build unless the layout is `(Row, Col)` or `(Col, Row)`.
+    pub fn get(&self, xy: impl Into<[usize; 2]>) -> Option<&S::Elem> {
+        self.array.get(index(self.locate(xy.into())?))
+    }
+}
+
+impl<S: DataMut, L: Layout<Dim = Ix2>> Labeled<S, L> {
+    /// Mutably borrows the element at `(x, y)`, or returns `None` if it is out of bounds.
+    ///
+    /// This does not build unless the layout is `(Row, Col)` or `(Col, Row)`.
+    pub fn get_mut(&mut self, xy: impl Into<[usize; 2]>) -> Option<&mut S::Elem> {
+        self.array.get_mut(index(self.locate(xy.into())?))
+    }
+}
+
+/// Orders the located axes so that removing the inner one first leaves the outer one in place.
+fn sorted(mut located: [(usize, usize); 2]) -> [(usize, usize); 2] {
+    located.sort_unstable();
+    located
+}
+
+/// The 2d index of the located axes.
+fn index(located: [(usize, usize); 2]) -> [usize; 2] {
+    let mut index = [0; 2];
+    for (axis, i) in located {
+        index[axis] = i;
+    }
+    index
+}
+
+impl<S: RawDataClone, L: Layout> Clone for Labeled<S, L> {
+    fn clone(&self) -> Self {
+        Labeled::new(self.array.clone())
+    }
+}
+
+impl<S: Data, L: Layout> fmt::Debug for Labeled<S, L>
+where
+    S::Elem: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Labeled")
+            .field("layout", &core::any::type_name::<L>())
+            .field("array", &self.array)
+            .finish()
+    }
+}
@@ -5,6 +5,19 @@ mod tonalgebra;
 #[cfg(feature = "nalgebra")]
 pub use tonalgebra::*;
 
+#[cfg(feature = "nalgebra")]
+mod points;
+
+#[cfg(feature = "nalgebra")]
+mod depth;
+#[cfg(feature = "nalgebra")]
+pub use depth::DepthCamera;
+
+#[cfg(feature = "nalgebra")]
+mod toarray;
+#[cfg(feature = "nalgebra")]
+pub use toarray::*;
+
 #[cfg(feature = "image")]
 mod toimage;
 #[cfg(feature = "image")]
@@ -14,3 +27,75 @@ pub use toimage::*;
 mod tondarray;
 #[cfg(feature = "ndarray")]
 pub use tondarray::*;
+
+#[cfg(feature = "ndarray")]
+mod labeled;
+#[cfg(feature = "ndarray")]
+pub use labeled::{
+    AxisLabel, Batch, Channel, Col, Labeled, LabeledArray, LabeledView, LabeledViewMut, Layout,
+    Row, Z,
+};
+
+#[cfg(feature = "imgref")]
+mod toimgref;
+#[cfg(feature = "imgref")]
+pub use toimgref::*;
+
+#[cfg(feature = "palette")]
+mod topalette;
+#[cfg(feature = "palette")]
+pub use topalette::*;
+
+#[cfg(feature = "rgb")]
+mod torgb;
+#[cfg(feature = "rgb")]
+pub use torgb::*;
+
+#[cfg(feature = "sprs")]
+mod tosprs;
+#[cfg(feature = "sprs")]
+pub use tosprs::*;
+
+#[cfg(feature = "tiny-skia")]
+mod totiny_skia;
+#[cfg(feature = "tiny-skia")]
+pub use totiny_skia::*;
+
+#[cfg(feature = "embedded-graphics")]
+mod toembedded_graphics;
+#[cfg(feature = "embedded-graphics")]
+pub use toembedded_graphics::*;
+
+#[cfg(feature = "nalgebra-sparse")]
+mod tonalgebra_sparse;
+#[cfg(feature = "nalgebra-sparse")]
+pub use tonalgebra_sparse::*;
+
+mod strided;
+pub use strided::{StridedError, StridedSlice, StridedSliceMut};
+
+mod bytes;
+pub use bytes::{ByteElement, ByteOrder, BytesError, TypedBytes};
+
+mod tobytes;
+pub use tobytes::*;
+
+mod bayer;
+pub use bayer::{BayerColor, BayerError, BayerImage, BayerPattern, BayerSample, Demosaic};
+
+mod packed;
+pub use packed::{PackedError, PackedFormat, PackedImage, PackedRawImage, RawPacking};
+
+#[cfg(feature = "alloc")]
+mod topacked;
+#[cfg(feature = "alloc")]
+pub use topacked::*;
+
+mod yuv;
+pub use yuv::{YuvColorSpace, YuvError, YuvFormat, YuvFrame, YuvMatrix, YuvPlane, YuvRange};
+
+#[cfg(all(
+    any(feature = "sprs", feature = "nalgebra-sparse"),
+    any(feature = "ndarray", feature = "nalgebra")
+))]
+mod sparse;
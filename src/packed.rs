@@ -0,0 +1,414 @@
+//! Pixel formats that pack several samples into the same bytes, as used by embedded displays and sensors.
+
+use core::fmt;
+
+/// A format that packs the channels of a pixel into a `u16`.
+///
+/// Channels are scaled between their bit depth and 8 bits by rounding to the nearest value, with halves
+/// rounded up, so unpacking and packing again gives back the same bits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PackedFormat {
+    /// 5 bits of red in the high bits, 6 bits of green and 5 bits of blue in the low bits.
+    Rgb565,
+    /// 5 bits of blue in the high bits, 6 bits of green and 5 bits of red in the low bits.
+    Bgr565,
+    /// 1 bit of alpha in the highest bit, followed by 5 bits each of red, green and blue.
+    Argb1555,
+}
+
+impl PackedFormat {
+    /// Whether the format stores alpha.
+    pub fn has_alpha(self) -> bool {
+        self == Self::Argb1555
+    }
+
+    /// Unpacks a pixel into its 8-bit red, green, blue and alpha channels.
+    ///
+    /// Formats without alpha are opaque, and the alpha bit is either fully transparent or opaque.
+    ///
+    /// ```
+    /// use nshare::PackedFormat;
+    ///
+    /// assert_eq!(PackedFormat::Rgb565.unpack(0xf800), [255, 0, 0, 255]);
+    /// assert_eq!(PackedFormat::Bgr565.unpack(0xf800), [0, 0, 255, 255]);
+    /// assert_eq!(PackedFormat::Argb1555.unpack(0x03e0), [0, 255, 0, 0]);
+    /// ```
+    pub fn unpack(self, pixel: u16) -> [u8; 4] {
+        let field = |shift: u16, bits: u16| {
+            let max = (1 << bits) - 1;
+            widen((pixel >> shift) & max, max)
+        };
+        match self {
+            Self::Rgb565 => [field(11, 5), field(5, 6), field(0, 5), 255],
+            Self::Bgr565 => [field(0, 5), field(5, 6), field(11, 5), 255],
+            Self::Argb1555 => [field(10, 5), field(5, 5), field(0, 5), field(15, 1)],
+        }
+    }
+
+    /// Packs 8-bit red, green, blue and alpha channels into a pixel.
+    ///
+    /// Alpha is ignored by formats without it, and becomes opaque from `128` on for the alpha bit.
+    ///
+    /// ```
+    /// use nshare::PackedFormat;
+    ///
+    /// assert_eq!(PackedFormat::Rgb565.pack([255, 0, 0, 0]), 0xf800);
+    /// // 4 of 255 is nearer to 0 of 31, and 5 of 255 is nearer to 1 of 31.
+    /// assert_eq!(PackedFormat::Argb1555.pack([4, 5, 0, 128]), 0x8020);
+    /// ```
+    pub fn pack(self, [r, g, b, a]: [u8; 4]) -> u16 {
+        let field = |value: u8, shift: u16, bits: u16| narrow(value, (1 << bits) - 1) << shift;
+        match self {
+            Self::Rgb565 => field(r, 11, 5) | field(g, 5, 6) | field(b, 0, 5),
+            Self::Bgr565 => field(b, 11, 5) | field(g, 5, 6) | field(r, 0, 5),
+            Self::Argb1555 => field(a, 15, 1) | field(r, 10, 5) | field(g, 5, 5) | field(b, 0, 5),
+        }
+    }
+}
+
+/// Scales `value` out of `max` to the nearest 8-bit value.
+fn widen(value: u16, max: u16) -> u8 {
+    ((u32::from(value) * 255 + u32::from(max) / 2) / u32::from(max)) as u8
+}
+
+/// Scales an 8-bit value to the nearest value out of `max`.
+fn narrow(value: u8, max: u16) -> u16 {
+    ((u32::from(value) * u32::from(max) + 127) / 255) as u16
+}
+
+/// The reason a layout was rejected by the constructors of [`PackedImage`] and [`PackedRawImage`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PackedError {
+    /// Computing the size of the image overflowed.
+    Overflow,
+    /// Rows need `min` elements, which is more than the `stride`.
+    Stride { stride: usize, min: usize },
+    /// The last row ends at `end`, past the end of a slice of `len` elements.
+    OutOfBounds { end: usize, len: usize },
+}
+
+impl fmt::Display for PackedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Overflow => write!(f, "the size of the image overflows"),
+            Self::Stride { stride, min } => {
+                write!(f, "rows need {min} elements, but the stride is {stride}")
+            }
+            Self::OutOfBounds { end, len } => write!(
+                f,
+                "the last row ends at {end}, but the slice has {len} elements"
+            ),
+        }
+    }
+}
+
+impl core::error::Error for PackedError {}
+
+/// Checks that `height` rows of `min` elements, `stride` elements apart, fit in `len` elements.
+fn check_rows(len: usize, min: usize, stride: usize, height: usize) -> Result<(), PackedError> {
+    if min > stride {
+        return Err(PackedError::Stride { stride, min });
+    }
+    if min == 0 || height == 0 {
+        return Ok(());
+    }
+    let end = stride
+        .checked_mul(height - 1)
+        .and_then(|last| last.checked_add(min))
+        .ok_or(PackedError::Overflow)?;
+    if end > len {
+        return Err(PackedError::OutOfBounds { end, len });
+    }
+    Ok(())
+}
+
+/// A borrowed image of pixels packed into a `u16` each.
+#[derive(Debug, Clone, Copy)]
+pub struct PackedImage<'a> {
+    data: &'a [u16],
+    width: usize,
+    height: usize,
+    stride: usize,
+    format: PackedFormat,
+}
+
+impl<'a> PackedImage<'a> {
+    /// Uses rows of `width` pixels without padding.
+    ///
+    /// ```
+    /// use nshare::{PackedError, PackedFormat, PackedImage};
+    ///
+    /// let data = [0u16; 6];
+    /// assert!(PackedImage::new(&data, 3, 2, PackedFormat::Rgb565).is_ok());
+    /// assert_eq!(
+    ///     PackedImage::new(&data, 3, 3, PackedFormat::Rgb565).unwrap_err(),
+    ///     PackedError::OutOfBounds { end: 9, len: 6 },
+    /// );
+    /// ```
+    pub fn new(
+        data: &'a [u16],
+        width: usize,
+        height: usize,
+        format: PackedFormat,
+    ) -> Result<Self, PackedError> {
+        Self::with_stride(data, width, height, width, format)
+    }
+
+    /// Uses rows that start `stride` pixels apart.
+    pub fn with_stride(
+        data: &'a [u16],
+        width: usize,
+        height: usize,
+        stride: usize,
+        format: PackedFormat,
+    ) -> Result<Self, PackedError> {
+        check_rows(data.len(), width, stride, height)?;
+        Ok(Self {
+            data,
+            width,
+            height,
+            stride,
+            format,
+        })
+    }
+
+    /// The width of the image in pixels.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// The height of the image in pixels.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The distance in pixels between the starts of neighboring rows.
+    pub fn stride(&self) -> usize {
+        self.stride
+    }
+
+    /// The format of the pixels.
+    pub fn format(&self) -> PackedFormat {
+        self.format
+    }
+
+    /// The pixels the image refers to.
+    pub fn data(&self) -> &'a [u16] {
+        self.data
+    }
+
+    /// The unpacked red, green, blue and alpha channels of the pixel at column `x` and row `y`.
+    ///
+    /// ```
+    /// use nshare::{PackedFormat, PackedImage};
+    ///
+    /// let data = [0x0000, 0xffff];
+    /// let img = PackedImage::new(&data, 2, 1, PackedFormat::Rgb565).unwrap();
+    /// assert_eq!(img.pixel(1, 0), [255; 4]);
+    /// ```
+    pub fn pixel(&self, x: usize, y: usize) -> [u8; 4] {
+        assert!(
+            x < self.width && y < self.height,
+            "the pixel must be in the image"
+        );
+        self.format.unpack(self.data[y * self.stride + x])
+    }
+}
+
+/// How the samples of a raw sensor image are packed into bytes, as defined by MIPI CSI-2.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RawPacking {
+    /// Groups of 4 10-bit samples in 5 bytes, with the high 8 bits of each sample in the first 4 bytes
+    /// and the low 2 bits of all of them in the last byte, starting with the first sample in the lowest bits.
+    Raw10,
+    /// Groups of 2 12-bit samples in 3 bytes, with the high 8 bits of each sample in the first 2 bytes
+    /// and the low 4 bits of both in the last byte, starting with the first sample in the lowest bits.
+    Raw12,
+}
+
+impl RawPacking {
+    /// The number of bits of each sample.
+    pub fn bits(self) -> u32 {
+        match self {
+            Self::Raw10 => 10,
+            Self::Raw12 => 12,
+        }
+    }
+
+    /// The number of samples in a group and the number of bytes they are packed into.
+    fn group(self) -> (usize, usize) {
+        match self {
+            Self::Raw10 => (4, 5),
+            Self::Raw12 => (2, 3),
+        }
+    }
+
+    /// The number of bytes a row of `width` samples needs, with the last group padded if it is incomplete.
+    /// It panics if that does not fit in `usize`.
+    ///
+    /// ```
+    /// use nshare::RawPacking;
+    ///
+    /// assert_eq!(RawPacking::Raw10.row_bytes(8), 10);
+    /// assert_eq!(RawPacking::Raw12.row_bytes(3), 6);
+    /// ```
+    pub fn row_bytes(self, width: usize) -> usize {
+        self.checked_row_bytes(width)
+            .expect("the row must fit in usize")
+    }
+
+    /// [`Self::row_bytes`], or [`PackedError::Overflow`] if it does not fit in `usize`.
+    fn checked_row_bytes(self, width: usize) -> Result<usize, PackedError> {
+        let (samples, bytes) = self.group();
+        width
+            .div_ceil(samples)
+            .checked_mul(bytes)
+            .ok_or(PackedError::Overflow)
+    }
+
+    /// Unpacks the sample at column `x` of a packed row.
+    fn unpack(self, row: &[u8], x: usize) -> u16 {
+        let (samples, bytes) = self.group();
+        let group = &row[x / samples * bytes..][..bytes];
+        let index = x % samples;
+        let low_bits = self.bits() - 8;
+        let low = (group[samples] >> (low_bits as usize * index)) & ((1 << low_bits) - 1);
+        (u16::from(group[index]) << low_bits) | u16::from(low)
+    }
+
+    /// Packs a row of samples into `row`, saturating samples that do not fit in the bit depth.
+    ///
+    /// The padding of an incomplete last group is zero.
+    ///
+    /// ```
+    /// use nshare::RawPacking;
+    ///
+    /// let mut row = [0u8; 5];
+    /// RawPacking::Raw10.pack_row(&[0x3ff, 0x001, 0x002, 0x403], &mut row);
+    /// assert_eq!(row, [0xff, 0x00, 0x00, 0xff, 0b11_10_01_11]);
+    /// ```
+    pub fn pack_row(self, samples: &[u16], row: &mut [u8]) {
+        assert_eq!(
+            row.len(),
+            self.row_bytes(samples.len()),
+            "the row must hold exactly the packed samples"
+        );
+        let (group_samples, group_bytes) = self.group();
+        let low_bits = self.bits() - 8;
+        let max = (1u16 << self.bits()) - 1;
+        for (values, group) in samples
+            .chunks(group_samples)
+            .zip(row.chunks_exact_mut(group_bytes))
+        {
+            group.fill(0);
+            for (index, &value) in values.iter().enumerate() {
+                let value = value.min(max);
+                group[index] = (value >> low_bits) as u8;
+                let low = (value & ((1 << low_bits) - 1)) as u8;
+                group[group_samples] |= low << (low_bits as usize * index);
+            }
+        }
+    }
+}
+
+/// A borrowed raw sensor image with samples packed as by [`RawPacking`].
+#[derive(Debug, Clone, Copy)]
+pub struct PackedRawImage<'a> {
+    data: &'a [u8],
+    width: usize,
+    height: usize,
+    stride: usize,
+    packing: RawPacking,
+}
+
+impl<'a> PackedRawImage<'a> {
+    /// Uses rows of [`RawPacking::row_bytes`] bytes without padding.
+    ///
+    /// ```
+    /// use nshare::{PackedRawImage, RawPacking};
+    ///
+    /// let data = [0u8; 10];
+    /// let img = PackedRawImage::new(&data, 4, 2, RawPacking::Raw10).unwrap();
+    /// assert_eq!(img.stride(), 5);
+    /// ```
+    pub fn new(
+        data: &'a [u8],
+        width: usize,
+        height: usize,
+        packing: RawPacking,
+    ) -> Result<Self, PackedError> {
+        let stride = packing.checked_row_bytes(width)?;
+        Self::with_stride(data, width, height, stride, packing)
+    }
+
+    /// Uses rows that start `stride` bytes apart.
+    ///
+    /// ```
+    /// use nshare::{PackedError, PackedRawImage, RawPacking};
+    ///
+    /// let data = [0u8; 16];
+    /// assert_eq!(
+    ///     PackedRawImage::with_stride(&data, 6, 2, 8, RawPacking::Raw12).unwrap_err(),
+    ///     PackedError::Stride { stride: 8, min: 9 },
+    /// );
+    /// ```
+    pub fn with_stride(
+        data: &'a [u8],
+        width: usize,
+        height: usize,
+        stride: usize,
+        packing: RawPacking,
+    ) -> Result<Self, PackedError> {
+        let min = packing.checked_row_bytes(width)?;
+        check_rows(data.len(), min, stride, height)?;
+        Ok(Self {
+            data,
+            width,
+            height,
+            stride,
+            packing,
+        })
+    }
+
+    /// The width of the image in pixels.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// The height of the image in pixels.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The distance in bytes between the starts of neighboring rows.
+    pub fn stride(&self) -> usize {
+        self.stride
+    }
+
+    /// How the samples are packed.
+    pub fn packing(&self) -> RawPacking {
+        self.packing
+    }
+
+    /// The bytes the image refers to.
+    pub fn data(&self) -> &'a [u8] {
+        self.data
+    }
+
+    /// The unpacked sample of the pixel at column `x` and row `y`.
+    ///
+    /// ```
+    /// use nshare::{PackedRawImage, RawPacking};
+    ///
+    /// let data = [0xab, 0xcd, 0x21];
+    /// let img = PackedRawImage::new(&data, 2, 1, RawPacking::Raw12).unwrap();
+    /// assert_eq!((img.sample(0, 0), img.sample(1, 0)), (0xab1, 0xcd2));
+    /// ```
+    pub fn sample(&self, x: usize, y: usize) -> u16 {
+        assert!(
+            x < self.width && y < self.height,
+            "the pixel must be in the image"
+        );
+        self.packing.unpack(&self.data[y * self.stride..], x)
+    }
+}
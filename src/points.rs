@@ -0,0 +1,104 @@
+//! Casts between slices of fixed-size nalgebra points, vectors or matrices and slices of their elements.
+//!
+//! nalgebra lays out `SMatrix<T, R, C>` as `[[T; R]; C]`, and `Point<T, D>` as the vector of its coordinates,
+//! so a slice of any of them has the same layout as a slice of their elements.
+
+use core::mem::{align_of, size_of};
+#[cfg(feature = "ndarray")]
+use nalgebra::SMatrix;
+use nalgebra::{Point, SVector, Scalar};
+
+/// Checks that `P` has the size and alignment of `len` elements of type `T`, which the casts below rely on.
+fn check_layout<P, T>(len: usize) {
+    assert_eq!(size_of::<P>(), len * size_of::<T>());
+    assert_eq!(align_of::<P>(), align_of::<T>());
+}
+
+pub(crate) fn point_coords<T: Scalar, const D: usize>(points: &[Point<T, D>]) -> &[[T; D]] {
+    check_layout::<Point<T, D>, T>(D);
+    // SAFETY: `Point<T, D>` is `repr(C)` around `SVector<T, D>`, which is `repr(C)` around `[[T; D]; 1]`
+    // and a zero-sized marker, and the layouts were checked to match.
+    unsafe { core::slice::from_raw_parts(points.as_ptr().cast(), points.len()) }
+}
+
+pub(crate) fn point_coords_mut<T: Scalar, const D: usize>(
+    points: &mut [Point<T, D>],
+) -> &mut [[T; D]] {
+    check_layout::<Point<T, D>, T>(D);
+    // SAFETY: See `point_coords`.
+    unsafe { core::slice::from_raw_parts_mut(points.as_mut_ptr().cast(), points.len()) }
+}
+
+pub(crate) fn vector_coords<T: Scalar, const D: usize>(vectors: &[SVector<T, D>]) -> &[[T; D]] {
+    check_layout::<SVector<T, D>, T>(D);
+    // SAFETY: `SVector<T, D>` is `repr(C)` around `[[T; D]; 1]` and a zero-sized marker,
+    // and the layouts were checked to match.
+    unsafe { core::slice::from_raw_parts(vectors.as_ptr().cast(), vectors.len()) }
+}
+
+pub(crate) fn vector_coords_mut<T: Scalar, const D: usize>(
+    vectors: &mut [SVector<T, D>],
+) -> &mut [[T; D]] {
+    check_layout::<SVector<T, D>, T>(D);
+    // SAFETY: See `vector_coords`.
+    unsafe { core::slice::from_raw_parts_mut(vectors.as_mut_ptr().cast(), vectors.len()) }
+}
+
+#[cfg(feature = "ndarray")]
+pub(crate) fn coords_points<T: Scalar, const D: usize>(coords: &[[T; D]]) -> &[Point<T, D>] {
+    check_layout::<Point<T, D>, T>(D);
+    // SAFETY: See `point_coords`.
+    unsafe { core::slice::from_raw_parts(coords.as_ptr().cast(), coords.len()) }
+}
+
+#[cfg(feature = "ndarray")]
+pub(crate) fn coords_points_mut<T: Scalar, const D: usize>(
+    coords: &mut [[T; D]],
+) -> &mut [Point<T, D>] {
+    check_layout::<Point<T, D>, T>(D);
+    // SAFETY: See `point_coords`.
+    unsafe { core::slice::from_raw_parts_mut(coords.as_mut_ptr().cast(), coords.len()) }
+}
+
+#[cfg(feature = "ndarray")]
+pub(crate) fn matrix_elements<T: Scalar, const R: usize, const C: usize>(
+    matrices: &[SMatrix<T, R, C>],
+) -> &[T] {
+    check_layout::<SMatrix<T, R, C>, T>(R * C);
+    // SAFETY: `SMatrix<T, R, C>` is `repr(C)` around `[[T; R]; C]` and a zero-sized marker,
+    // and the layouts were checked to match.
+    unsafe { core::slice::from_raw_parts(matrices.as_ptr().cast(), matrices.len() * R * C) }
+}
+
+#[cfg(feature = "ndarray")]
+pub(crate) fn matrix_elements_mut<T: Scalar, const R: usize, const C: usize>(
+    matrices: &mut [SMatrix<T, R, C>],
+) -> &mut [T] {
+    check_layout::<SMatrix<T, R, C>, T>(R * C);
+    // SAFETY: See `matrix_elements`.
+    unsafe { core::slice::from_raw_parts_mut(matrices.as_mut_ptr().cast(), matrices.len() * R * C) }
+}
+
+/// Casts the elements of `len` column-major matrices.
+#[cfg(feature = "ndarray")]
+pub(crate) fn elements_matrices<T: Scalar, const R: usize, const C: usize>(
+    elements: &[T],
+    len: usize,
+) -> &[SMatrix<T, R, C>] {
+    check_layout::<SMatrix<T, R, C>, T>(R * C);
+    assert_eq!(elements.len(), len * R * C);
+    // SAFETY: See `matrix_elements`.
+    unsafe { core::slice::from_raw_parts(elements.as_ptr().cast(), len) }
+}
+
+/// Casts the elements of `len` column-major matrices.
+#[cfg(feature = "ndarray")]
+pub(crate) fn elements_matrices_mut<T: Scalar, const R: usize, const C: usize>(
+    elements: &mut [T],
+    len: usize,
+) -> &mut [SMatrix<T, R, C>] {
+    check_layout::<SMatrix<T, R, C>, T>(R * C);
+    assert_eq!(elements.len(), len * R * C);
+    // SAFETY: See `matrix_elements`.
+    unsafe { core::slice::from_raw_parts_mut(elements.as_mut_ptr().cast(), len) }
+}
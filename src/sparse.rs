@@ -0,0 +1,40 @@
+//! Helpers shared by the conversions to sparse matrix types.
+
+extern crate alloc;
+
+use alloc::{vec, vec::Vec};
+
+/// Checks whether `value` is within `tolerance` of zero.
+///
+/// This is written so that NaN is never negligible.
+pub(crate) fn is_negligible<T>(value: &T, tolerance: &T) -> bool
+where
+    T: Clone + PartialOrd + core::ops::Neg<Output = T>,
+{
+    *value <= *tolerance && *value >= -tolerance.clone()
+}
+
+/// Compresses dense lanes (rows for CSR or columns for CSC) into offsets, indices, and values.
+pub(crate) fn compress_lanes<'a, T, L, I>(
+    lanes: L,
+    tolerance: &T,
+) -> (Vec<usize>, Vec<usize>, Vec<T>)
+where
+    T: Clone + PartialOrd + core::ops::Neg<Output = T> + 'a,
+    L: IntoIterator<Item = I>,
+    I: IntoIterator<Item = &'a T>,
+{
+    let mut offsets = vec![0];
+    let mut indices = Vec::new();
+    let mut values = Vec::new();
+    for lane in lanes {
+        for (index, value) in lane.into_iter().enumerate() {
+            if !is_negligible(value, tolerance) {
+                indices.push(index);
+                values.push(value.clone());
+            }
+        }
+        offsets.push(indices.len());
+    }
+    (offsets, indices, values)
+}
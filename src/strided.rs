@@ -0,0 +1,276 @@
+//! Slices described by a shape, strides and an offset, as they come from C libraries or file headers.
+
+use core::fmt;
+
+/// A shared slice together with a validated layout of `N` axes.
+///
+/// The element at index `[i0, i1, ...]` is `data[offset + i0 * strides[0] + i1 * strides[1] + ...]`,
+/// with strides counted in elements. Constructing one proves that every index is in bounds,
+/// so it converts to ndarray, nalgebra and image types without further checks.
+#[derive(Debug, Clone, Copy)]
+pub struct StridedSlice<'a, T, const N: usize> {
+    pub(crate) data: &'a [T],
+    pub(crate) shape: [usize; N],
+    pub(crate) strides: [isize; N],
+    pub(crate) offset: usize,
+}
+
+/// A mutable slice together with a validated layout of `N` axes.
+///
+/// This is the same as [`StridedSlice`], except that constructing one also proves that
+/// no two indices refer to the same element.
+#[derive(Debug)]
+pub struct StridedSliceMut<'a, T, const N: usize> {
+    pub(crate) data: &'a mut [T],
+    pub(crate) shape: [usize; N],
+    pub(crate) strides: [isize; N],
+    pub(crate) offset: usize,
+}
+
+/// The reason a layout was rejected by the constructors of [`StridedSlice`] and [`StridedSliceMut`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StridedError {
+    /// Computing the position of the last element overflowed.
+    Overflow,
+    /// Some index refers to an element before the start of the slice.
+    BeforeStart,
+    /// Some index refers to an element past the end of the slice, which would need `end` elements.
+    OutOfBounds { end: usize, len: usize },
+    /// Stepping along `axis` may reach an element that other indices also refer to.
+    Aliasing { axis: usize },
+}
+
+impl fmt::Display for StridedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Overflow => write!(f, "the layout overflows the address space"),
+            Self::BeforeStart => write!(f, "the layout reaches before the start of the slice"),
+            Self::OutOfBounds { end, len } => write!(
+                f,
+                "the layout needs {end} elements, but the slice only has {len}"
+            ),
+            Self::Aliasing { axis } => write!(
+                f,
+                "axis {axis} may step onto elements that other indices refer to"
+            ),
+        }
+    }
+}
+
+impl core::error::Error for StridedError {}
+
+impl<'a, T, const N: usize> StridedSlice<'a, T, N> {
+    /// Validates the layout against `data`.
+    ///
+    /// ```
+    /// use nshare::{StridedError, StridedSlice};
+    ///
+    /// let data = [0u8; 12];
+    /// // Every other column of a 3x4 row-major image.
+    /// assert!(StridedSlice::new(&data, [3, 2], [4, 2], 1).is_ok());
+    /// assert_eq!(
+    ///     StridedSlice::new(&data, [3, 2], [4, 2], 4).unwrap_err(),
+    ///     StridedError::OutOfBounds { end: 15, len: 12 },
+    /// );
+    /// ```
+    pub fn new(
+        data: &'a [T],
+        shape: [usize; N],
+        strides: [isize; N],
+        offset: usize,
+    ) -> Result<Self, StridedError> {
+        check_bounds(data.len(), &shape, &strides, offset)?;
+        Ok(Self {
+            data,
+            shape,
+            strides,
+            offset,
+        })
+    }
+
+    /// Lays out `data` in row-major order without padding.
+    ///
+    /// ```
+    /// use nshare::StridedSlice;
+    ///
+    /// let data = [0u8; 12];
+    /// let s = StridedSlice::contiguous(&data, [3, 4]).unwrap();
+    /// assert_eq!(s.strides(), [4, 1]);
+    /// ```
+    pub fn contiguous(data: &'a [T], shape: [usize; N]) -> Result<Self, StridedError> {
+        Self::new(data, shape, contiguous_strides(&shape)?, 0)
+    }
+
+    /// The length of each axis.
+    pub fn shape(&self) -> [usize; N] {
+        self.shape
+    }
+
+    /// The distance in elements between neighbors along each axis.
+    pub fn strides(&self) -> [isize; N] {
+        self.strides
+    }
+
+    /// The position in the slice of the element at index zero.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// The slice the layout refers to.
+    pub fn data(&self) -> &'a [T] {
+        self.data
+    }
+}
+
+impl<'a, T, const N: usize> StridedSliceMut<'a, T, N> {
+    /// Validates the layout against `data` and checks that the indices do not alias.
+    ///
+    /// Aliasing is ruled out by sorting the axes by their stride and checking that each one steps over
+    /// everything the axes with smaller strides can reach. Layouts that do not alias but fail this check,
+    /// such as some interleavings of axes, are rejected too.
+    ///
+    /// ```
+    /// use nshare::{StridedError, StridedSliceMut};
+    ///
+    /// let mut data = [0u8; 12];
+    /// assert!(StridedSliceMut::new(&mut data, [3, 4], [4, 1], 0).is_ok());
+    /// // Rows of 4 elements only 2 apart overlap.
+    /// assert_eq!(
+    ///     StridedSliceMut::new(&mut data, [3, 4], [2, 1], 0).unwrap_err(),
+    ///     StridedError::Aliasing { axis: 0 },
+    /// );
+    /// ```
+    pub fn new(
+        data: &'a mut [T],
+        shape: [usize; N],
+        strides: [isize; N],
+        offset: usize,
+    ) -> Result<Self, StridedError> {
+        check_bounds(data.len(), &shape, &strides, offset)?;
+        check_aliasing(&shape, &strides)?;
+        Ok(Self {
+            data,
+            shape,
+            strides,
+            offset,
+        })
+    }
+
+    /// Lays out `data` in row-major order without padding.
+    pub fn contiguous(data: &'a mut [T], shape: [usize; N]) -> Result<Self, StridedError> {
+        let strides = contiguous_strides(&shape)?;
+        Self::new(data, shape, strides, 0)
+    }
+
+    /// The length of each axis.
+    pub fn shape(&self) -> [usize; N] {
+        self.shape
+    }
+
+    /// The distance in elements between neighbors along each axis.
+    pub fn strides(&self) -> [isize; N] {
+        self.strides
+    }
+
+    /// The position in the slice of the element at index zero.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// The slice the layout refers to.
+    pub fn into_data(self) -> &'a mut [T] {
+        self.data
+    }
+}
+
+/// The lowest position in the slice that an index refers to, and the strides with their signs removed.
+///
+/// A view of the data from that position with these strides has the axes with negative strides inverted.
+/// The layout must have been checked with [`check_bounds`].
+#[cfg(feature = "ndarray")]
+pub(crate) fn unsigned_layout<const N: usize>(
+    shape: &[usize; N],
+    strides: &[isize; N],
+    offset: usize,
+) -> (usize, [usize; N]) {
+    let mut low = offset;
+    if shape.contains(&0) {
+        return (low, strides.map(isize::unsigned_abs));
+    }
+    for (&len, &stride) in shape.iter().zip(strides) {
+        if stride < 0 {
+            low -= (len - 1) * stride.unsigned_abs();
+        }
+    }
+    (low, strides.map(isize::unsigned_abs))
+}
+
+/// The row-major strides of `shape` without padding.
+fn contiguous_strides<const N: usize>(shape: &[usize; N]) -> Result<[isize; N], StridedError> {
+    let mut strides = [0; N];
+    let mut step: isize = 1;
+    for (stride, &len) in strides.iter_mut().zip(shape).rev() {
+        *stride = step;
+        let len = isize::try_from(len).map_err(|_| StridedError::Overflow)?;
+        step = step.checked_mul(len).ok_or(StridedError::Overflow)?;
+    }
+    Ok(strides)
+}
+
+/// Checks that every index of the layout refers to an element of a slice of length `len`.
+fn check_bounds<const N: usize>(
+    len: usize,
+    shape: &[usize; N],
+    strides: &[isize; N],
+    offset: usize,
+) -> Result<(), StridedError> {
+    if shape.contains(&0) {
+        // No index refers to an element, but the offset is still used to split the slice.
+        return match offset <= len {
+            true => Ok(()),
+            false => Err(StridedError::OutOfBounds { end: offset, len }),
+        };
+    }
+    let mut low = isize::try_from(offset).map_err(|_| StridedError::Overflow)?;
+    let mut high = low;
+    for (&axis_len, &stride) in shape.iter().zip(strides) {
+        let last = isize::try_from(axis_len - 1).map_err(|_| StridedError::Overflow)?;
+        let step = last.checked_mul(stride).ok_or(StridedError::Overflow)?;
+        let bound = if step < 0 { &mut low } else { &mut high };
+        *bound = bound.checked_add(step).ok_or(StridedError::Overflow)?;
+    }
+    if low < 0 {
+        return Err(StridedError::BeforeStart);
+    }
+    let end = high as usize + 1;
+    if end > len {
+        return Err(StridedError::OutOfBounds { end, len });
+    }
+    Ok(())
+}
+
+/// Checks that no two indices of the layout refer to the same element.
+fn check_aliasing<const N: usize>(
+    shape: &[usize; N],
+    strides: &[isize; N],
+) -> Result<(), StridedError> {
+    if shape.contains(&0) {
+        return Ok(());
+    }
+    let mut axes: [usize; N] = core::array::from_fn(|axis| axis);
+    axes.sort_unstable_by_key(|&axis| strides[axis].unsigned_abs());
+    // The furthest the axes with smaller strides can reach from any element.
+    let mut reach = 0usize;
+    for axis in axes {
+        if shape[axis] <= 1 {
+            continue;
+        }
+        let stride = strides[axis].unsigned_abs();
+        if stride <= reach {
+            return Err(StridedError::Aliasing { axis });
+        }
+        // This cannot overflow since the layout was already checked to be in bounds.
+        reach += stride * (shape[axis] - 1);
+    }
+    Ok(())
+}
@@ -0,0 +1,24 @@
+#[cfg(feature = "nalgebra")]
+mod nalgebra_impl;
+
+/// Converts a 1d type to a fixed-size array.
+///
+/// This uses an associated type to avoid ambiguity for the compiler.
+/// By calling this, the compiler always knows the returned type.
+pub trait IntoArray1 {
+    type Out;
+
+    fn into_array1(self) -> Self::Out;
+}
+
+/// Converts a 2d type to a fixed-size array of rows.
+///
+/// Coordinates are in (row, col), so the result is row-major.
+///
+/// This uses an associated type to avoid ambiguity for the compiler.
+/// By calling this, the compiler always knows the returned type.
+pub trait IntoArray2 {
+    type Out;
+
+    fn into_array2(self) -> Self::Out;
+}
@@ -0,0 +1,48 @@
+//! Implementations for nalgebra types being converted to fixed-size arrays.
+
+use super::*;
+use nalgebra::{Const, Matrix, RawStorage, Scalar, U1};
+
+/// ```
+/// use nshare::IntoArray1;
+///
+/// let v = nalgebra::Vector3::new(1, 2, 3);
+/// assert_eq!(v.into_array1(), [1, 2, 3]);
+/// // Views of columns work as well.
+/// let m = nalgebra::Matrix2::new(1, 2, 3, 4);
+/// assert_eq!(m.column(1).into_array1(), [2, 4]);
+/// ```
+impl<T, S, const N: usize> IntoArray1 for Matrix<T, Const<N>, U1, S>
+where
+    T: Scalar,
+    S: RawStorage<T, Const<N>, U1>,
+{
+    type Out = [T; N];
+
+    fn into_array1(self) -> Self::Out {
+        core::array::from_fn(|i| self[i].clone())
+    }
+}
+
+/// The column-major storage of nalgebra is copied into rows.
+///
+/// ```
+/// use nshare::IntoArray2;
+///
+/// let m = nalgebra::Matrix2x3::new(
+///     1, 2, 3,
+///     4, 5, 6,
+/// );
+/// assert_eq!(m.into_array2(), [[1, 2, 3], [4, 5, 6]]);
+/// ```
+impl<T, S, const R: usize, const C: usize> IntoArray2 for Matrix<T, Const<R>, Const<C>, S>
+where
+    T: Scalar,
+    S: RawStorage<T, Const<R>, Const<C>>,
+{
+    type Out = [[T; C]; R];
+
+    fn into_array2(self) -> Self::Out {
+        core::array::from_fn(|row| core::array::from_fn(|col| self[(row, col)].clone()))
+    }
+}
@@ -0,0 +1,22 @@
+#[cfg(feature = "image")]
+mod image_impl;
+#[cfg(feature = "nalgebra")]
+mod nalgebra_impl;
+#[cfg(feature = "ndarray")]
+mod ndarray_impl;
+mod slice_impl;
+
+/// Borrows a contiguous type as the bytes of its elements, for writing it to a file or the network.
+///
+/// The elements are in the byte order of the target, [`ByteOrder::NATIVE`](crate::ByteOrder::NATIVE),
+/// and in the order they are laid out in memory.
+///
+/// This uses an associated type so that types which are not always contiguous, such as ndarray arrays,
+/// can return `None` instead of panicking.
+pub trait AsBytes {
+    type Out<'a>
+    where
+        Self: 'a;
+
+    fn as_bytes(&self) -> Self::Out<'_>;
+}
@@ -0,0 +1,30 @@
+//! Implementations for image types being converted to bytes.
+
+use super::*;
+use crate::ByteElement;
+use core::ops::Deref;
+use image::{ImageBuffer, Pixel};
+
+/// The subpixels are interleaved in row-major order.
+///
+/// ```
+/// use image::{Rgb, ImageBuffer};
+/// use nshare::AsBytes;
+///
+/// let img: ImageBuffer<Rgb<u16>, Vec<u16>> = ImageBuffer::from_pixel(2, 2, Rgb([1, 2, 3]));
+/// assert_eq!(img.as_bytes().len(), 2 * 2 * 3 * 2);
+/// ```
+impl<P, Container> AsBytes for ImageBuffer<P, Container>
+where
+    P: Pixel,
+    P::Subpixel: ByteElement,
+    Container: Deref<Target = [P::Subpixel]>,
+{
+    type Out<'a> = &'a [u8]
+    where
+        Self: 'a;
+
+    fn as_bytes(&self) -> Self::Out<'_> {
+        self.as_raw().as_bytes()
+    }
+}
@@ -0,0 +1,30 @@
+//! Implementations for nalgebra types being converted to bytes.
+
+use super::*;
+use crate::ByteElement;
+use nalgebra::{storage::IsContiguous, Dim, Matrix, RawStorage};
+
+/// The elements are in column-major order.
+///
+/// ```
+/// use nshare::AsBytes;
+///
+/// let m = nalgebra::Matrix2::new(1.0f32, 2.0, 3.0, 4.0);
+/// let expected: Vec<u8> = [1.0f32, 3.0, 2.0, 4.0].iter().flat_map(|x| x.to_ne_bytes()).collect();
+/// assert_eq!(m.as_bytes(), expected.as_slice());
+/// ```
+impl<T, R, C, S> AsBytes for Matrix<T, R, C, S>
+where
+    T: ByteElement,
+    R: Dim,
+    C: Dim,
+    S: RawStorage<T, R, C> + IsContiguous,
+{
+    type Out<'a> = &'a [u8]
+    where
+        Self: 'a;
+
+    fn as_bytes(&self) -> Self::Out<'_> {
+        self.as_slice().as_bytes()
+    }
+}
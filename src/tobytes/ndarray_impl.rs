@@ -0,0 +1,31 @@
+//! Implementations for ndarray types being converted to bytes.
+
+use super::*;
+use crate::ByteElement;
+use ndarray::{ArrayBase, Data, Dimension};
+
+/// Contiguous arrays may be in any memory order, which is the order of the bytes. Arrays that are not
+/// contiguous, such as views of every other column, give `None`.
+///
+/// ```
+/// use nshare::AsBytes;
+///
+/// let arr = ndarray::array![[1u16, 2], [3, 4]];
+/// assert_eq!(arr.as_bytes().unwrap().len(), 8);
+/// assert_eq!(arr.t().as_bytes(), arr.as_bytes());
+/// assert_eq!(arr.column(0).as_bytes(), None);
+/// ```
+impl<S, D> AsBytes for ArrayBase<S, D>
+where
+    S: Data,
+    S::Elem: ByteElement,
+    D: Dimension,
+{
+    type Out<'a> = Option<&'a [u8]>
+    where
+        Self: 'a;
+
+    fn as_bytes(&self) -> Self::Out<'_> {
+        self.as_slice_memory_order().map(AsBytes::as_bytes)
+    }
+}
@@ -0,0 +1,25 @@
+//! Implementations for slices being converted to bytes.
+
+use super::*;
+use crate::ByteElement;
+
+/// ```
+/// use nshare::AsBytes;
+///
+/// let values = vec![1u16, 2];
+/// let expected: Vec<u8> = values.iter().flat_map(|x| x.to_ne_bytes()).collect();
+/// assert_eq!(values.as_bytes(), expected.as_slice());
+/// ```
+impl<T> AsBytes for [T]
+where
+    T: ByteElement,
+{
+    type Out<'a> = &'a [u8]
+    where
+        T: 'a;
+
+    fn as_bytes(&self) -> Self::Out<'_> {
+        // SAFETY: `ByteElement` is only implemented for primitive numbers, which have no padding.
+        unsafe { core::slice::from_raw_parts(self.as_ptr().cast(), core::mem::size_of_val(self)) }
+    }
+}
@@ -0,0 +1,30 @@
+#[cfg(feature = "nalgebra")]
+mod nalgebra_impl;
+mod slice_impl;
+
+#[cfg(feature = "nalgebra")]
+pub use nalgebra_impl::MatrixImage;
+
+use embedded_graphics::pixelcolor::raw::BigEndian;
+
+/// Converts raw pixel data into an embedded-graphics `ImageRaw` of pixels of color `C`, given the width of the image.
+///
+/// The data is stored in the format of `C::Raw` with the byte order `BO`, as with `ImageRaw` itself.
+///
+/// The color type is a type parameter of the trait since the same data may be drawn with several of them.
+pub trait IntoImageRaw<C, BO = BigEndian> {
+    type Out;
+
+    fn into_image_raw(self, width: u32) -> Self::Out;
+}
+
+/// Converts a 2d type into an embedded-graphics `ImageDrawable` with pixels of color `C`.
+///
+/// Each element is converted to the raw data of `C` and then to `C`, and row `r` is drawn at `y = r`.
+///
+/// The color type is a type parameter of the trait since the same data may be drawn with several of them.
+pub trait IntoImageDrawable<C> {
+    type Out;
+
+    fn into_image_drawable(self) -> Self::Out;
+}
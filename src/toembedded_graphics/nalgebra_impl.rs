@@ -0,0 +1,143 @@
+//! Implementations for nalgebra types being converted to embedded-graphics types.
+
+use super::*;
+use core::marker::PhantomData;
+use embedded_graphics::draw_target::DrawTarget;
+use embedded_graphics::geometry::{Dimensions, OriginDimensions, Point, Size};
+use embedded_graphics::image::ImageDrawable;
+use embedded_graphics::pixelcolor::PixelColor;
+use embedded_graphics::primitives::Rectangle;
+use nalgebra::{Dim, Matrix, RawStorage, Scalar};
+
+/// A borrowed nalgebra matrix that can be drawn as an image with pixels of color `C`.
+///
+/// This is created by [`IntoImageDrawable`] and drawn with an embedded-graphics `Image`.
+pub struct MatrixImage<'a, C, T, R, Cols, S> {
+    matrix: &'a Matrix<T, R, Cols, S>,
+    color: PhantomData<C>,
+}
+
+impl<C, T, R, Cols, S> Clone for MatrixImage<'_, C, T, R, Cols, S> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<C, T, R, Cols, S> Copy for MatrixImage<'_, C, T, R, Cols, S> {}
+
+impl<C, T, R, Cols, S> MatrixImage<'_, C, T, R, Cols, S>
+where
+    C: PixelColor + From<C::Raw>,
+    C::Raw: From<T>,
+    T: Scalar + Copy,
+    R: Dim,
+    Cols: Dim,
+    S: RawStorage<T, R, Cols>,
+{
+    /// The colors of the pixels in `area`, which must lie within the matrix, in row-major order.
+    fn colors(&self, area: Rectangle) -> impl Iterator<Item = C> + '_ {
+        let rows = area.rows().map(|y| y as usize);
+        let cols = area.columns().map(|x| x as usize);
+        rows.flat_map(move |row| {
+            cols.clone()
+                .map(move |col| C::from(C::Raw::from(self.matrix[(row, col)])))
+        })
+    }
+}
+
+impl<C, T, R, Cols, S> OriginDimensions for MatrixImage<'_, C, T, R, Cols, S>
+where
+    R: Dim,
+    Cols: Dim,
+    S: RawStorage<T, R, Cols>,
+{
+    fn size(&self) -> Size {
+        let (rows, cols) = self.matrix.shape();
+        Size::new(cols as u32, rows as u32)
+    }
+}
+
+impl<C, T, R, Cols, S> ImageDrawable for MatrixImage<'_, C, T, R, Cols, S>
+where
+    C: PixelColor + From<C::Raw>,
+    C::Raw: From<T>,
+    T: Scalar + Copy,
+    R: Dim,
+    Cols: Dim,
+    S: RawStorage<T, R, Cols>,
+{
+    type Color = C;
+
+    fn draw<D>(&self, target: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let area = self.bounding_box();
+        target.fill_contiguous(&area, self.colors(area))
+    }
+
+    fn draw_sub_image<D>(&self, target: &mut D, area: &Rectangle) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        // Like `ImageRaw`, nothing is drawn when `area` is not completely inside the image.
+        if area.is_zero_sized() || self.bounding_box().intersection(area) != *area {
+            return Ok(());
+        }
+        target.fill_contiguous(
+            &Rectangle::new(Point::zero(), area.size),
+            self.colors(*area),
+        )
+    }
+}
+
+/// ```
+/// use embedded_graphics::image::Image;
+/// use embedded_graphics::mock_display::MockDisplay;
+/// use embedded_graphics::pixelcolor::BinaryColor;
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::primitives::Rectangle;
+/// use nshare::IntoImageDrawable;
+///
+/// let m = nalgebra::SMatrix::<u8, 2, 3>::new(
+///     1, 0, 0,
+///     0, 0, 1,
+/// );
+/// let image = IntoImageDrawable::<BinaryColor>::into_image_drawable(&m);
+/// // The columns of the matrix are along x and the rows along y.
+/// assert_eq!(image.size(), Size::new(3, 2));
+///
+/// let mut display = MockDisplay::new();
+/// Image::new(&image, Point::zero()).draw(&mut display).unwrap();
+/// display.assert_pattern(&[
+///     "#..",
+///     "..#",
+/// ]);
+///
+/// // Parts of the matrix are drawn as sub images.
+/// let mut display = MockDisplay::new();
+/// let area = Rectangle::new(Point::new(1, 0), Size::new(2, 2));
+/// Image::new(&image.sub_image(&area), Point::zero()).draw(&mut display).unwrap();
+/// display.assert_pattern(&[
+///     "..",
+///     ".#",
+/// ]);
+/// ```
+impl<'a, C, T, R, Cols, S> IntoImageDrawable<C> for &'a Matrix<T, R, Cols, S>
+where
+    C: PixelColor + From<C::Raw>,
+    C::Raw: From<T>,
+    T: Scalar + Copy,
+    R: Dim,
+    Cols: Dim,
+    S: RawStorage<T, R, Cols>,
+{
+    type Out = MatrixImage<'a, C, T, R, Cols, S>;
+
+    fn into_image_drawable(self) -> Self::Out {
+        MatrixImage {
+            matrix: self,
+            color: PhantomData,
+        }
+    }
+}
@@ -0,0 +1,35 @@
+//! Implementations for slices of raw pixel data being converted to embedded-graphics types.
+
+use super::*;
+use embedded_graphics::image::ImageRaw;
+use embedded_graphics::iterator::raw::RawDataSlice;
+use embedded_graphics::pixelcolor::raw::ByteOrder;
+use embedded_graphics::pixelcolor::PixelColor;
+
+/// ```
+/// use embedded_graphics::image::Image;
+/// use embedded_graphics::mock_display::MockDisplay;
+/// use embedded_graphics::pixelcolor::Gray8;
+/// use embedded_graphics::prelude::*;
+/// use nshare::IntoImageRaw;
+///
+/// let data = [0u8, 255, 255, 0];
+/// let raw = IntoImageRaw::<Gray8>::into_image_raw(&data[..], 2);
+/// assert_eq!(raw.size(), Size::new(2, 2));
+///
+/// let mut display = MockDisplay::<Gray8>::new();
+/// Image::new(&raw, Point::zero()).draw(&mut display).unwrap();
+/// assert_eq!(display.get_pixel(Point::new(1, 0)), Some(Gray8::WHITE));
+/// ```
+impl<'a, C, BO> IntoImageRaw<C, BO> for &'a [u8]
+where
+    C: PixelColor + From<C::Raw>,
+    BO: ByteOrder,
+    RawDataSlice<'a, C::Raw, BO>: IntoIterator<Item = C::Raw>,
+{
+    type Out = ImageRaw<'a, C, BO>;
+
+    fn into_image_raw(self, width: u32) -> Self::Out {
+        ImageRaw::new(self, width)
+    }
+}
@@ -1,3 +1,19 @@
+mod bayer_impl;
+#[cfg(feature = "ndarray")]
+mod ndarray_impl;
+mod packed_impl;
+#[cfg(all(feature = "ndarray", feature = "palette"))]
+mod palette_impl;
+mod strided_impl;
+#[cfg(feature = "tiny-skia")]
+mod tiny_skia_impl;
+mod yuv_impl;
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use image::{ImageBuffer, Pixel};
+
 /// Converts a 2d type to a luma image type.
 ///
 /// This uses an associated type to avoid ambiguity for the compiler.
@@ -7,3 +23,288 @@ pub trait IntoImageLuma {
 
     fn into_image_luma(self) -> Self::Out;
 }
+
+/// How the range of the input is found before it is mapped onto the range of the subpixel type.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Scaling {
+    /// Maps `min` to the minimum and `max` to the maximum subpixel value.
+    Fixed { min: f64, max: f64 },
+    /// Maps the smallest and largest finite values in the whole image to the subpixel range.
+    AutoImage,
+    /// Maps the smallest and largest finite values of each channel separately to the subpixel range.
+    AutoChannel,
+}
+
+/// How scaled values are rounded when the subpixel type is an integer.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Default)]
+pub enum Rounding {
+    /// Rounds to the nearest integer, with halfway values rounded up.
+    #[default]
+    Nearest,
+    /// Rounds towards negative infinity.
+    Floor,
+    /// Rounds towards positive infinity.
+    Ceil,
+}
+
+/// What a NaN in the input becomes in the output.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum NanHandling {
+    /// The minimum subpixel value.
+    Min,
+    /// The maximum subpixel value.
+    Max,
+    /// The given input value, which is then scaled like any other.
+    Value(f64),
+}
+
+/// Describes how float values are mapped onto subpixels by [`IntoImageQuantized`].
+///
+/// Values are scaled linearly, clamped to the `DEFAULT_MIN_VALUE` and `DEFAULT_MAX_VALUE`
+/// of the subpixel type, and then rounded.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Quantization {
+    pub scaling: Scaling,
+    pub rounding: Rounding,
+    pub nan: NanHandling,
+}
+
+impl Quantization {
+    /// Maps `[0, 1]` onto the full subpixel range, rounding to nearest and turning NaN into the minimum.
+    pub const UNIT: Self = Self {
+        scaling: Scaling::Fixed { min: 0.0, max: 1.0 },
+        rounding: Rounding::Nearest,
+        nan: NanHandling::Min,
+    };
+}
+
+impl Default for Quantization {
+    fn default() -> Self {
+        Self::UNIT
+    }
+}
+
+/// Converts a float 2d or 3d type into an image, quantizing the values to the subpixel type.
+///
+/// 3d inputs are in `(channel, row, col)`, and the number of channels must match the pixel type.
+///
+/// The pixel type is a type parameter of the method since the same data may be quantized to any of them.
+pub trait IntoImageQuantized {
+    fn into_image_quantized<P>(
+        self,
+        quantization: &Quantization,
+    ) -> ImageBuffer<P, Vec<P::Subpixel>>
+    where
+        P: Pixel;
+}
+
+/// Converts a float 2d or 3d type into an image like [`IntoImageQuantized`], splitting the rows across the
+/// rayon thread pool.
+///
+/// The result is identical to [`IntoImageQuantized`].
+///
+/// The pixel type is a type parameter of the method since the same data may be quantized to any of them.
+#[cfg(feature = "rayon")]
+pub trait IntoImageQuantizedPar {
+    fn into_image_quantized_par<P>(
+        self,
+        quantization: &Quantization,
+    ) -> ImageBuffer<P, Vec<P::Subpixel>>
+    where
+        P: Pixel,
+        P::Subpixel: Send + Sync;
+}
+
+/// A window of values shown on a display, as used for CT and other medical volumes.
+///
+/// Values from `level - width / 2` to `level + width / 2` are spread over the range of the subpixel type,
+/// and values outside the window are clamped to its ends.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Window {
+    /// The value in the center of the window.
+    pub level: f64,
+    /// The distance between the smallest and the largest value in the window.
+    pub width: f64,
+}
+
+impl From<Window> for Scaling {
+    fn from(window: Window) -> Self {
+        let half = window.width / 2.0;
+        Self::Fixed {
+            min: window.level - half,
+            max: window.level + half,
+        }
+    }
+}
+
+/// Converts a 2d slice of a 3d type into a luma image.
+///
+/// Inputs are in `(z, y, x)`, and the slice is the plane at `index` along `axis`. The remaining axes
+/// keep their order and become `(row, col)`, so `Axis(0)` gives an axial slice in `(y, x)`, `Axis(1)`
+/// a coronal slice in `(z, x)` and `Axis(2)` a sagittal slice in `(z, y)`.
+///
+/// This uses an associated type to avoid ambiguity for the compiler.
+/// By calling this, the compiler always knows the returned type.
+#[cfg(feature = "ndarray")]
+pub trait IntoImageSlice {
+    type Out;
+
+    fn into_image_slice(self, axis: ndarray::Axis, index: usize) -> Self::Out;
+}
+
+/// Converts a 2d slice of a 3d type into an 8-bit luma image, mapping the values in `window` onto it.
+///
+/// The slice is taken the same way as with [`IntoImageSlice`].
+///
+/// This uses an associated type to avoid ambiguity for the compiler.
+/// By calling this, the compiler always knows the returned type.
+#[cfg(feature = "ndarray")]
+pub trait IntoImageSliceWindowed {
+    type Out;
+
+    fn into_image_slice_windowed(
+        self,
+        axis: ndarray::Axis,
+        index: usize,
+        window: Window,
+    ) -> Self::Out;
+}
+
+/// Converts a 3d type into an image, interleaving the channels into pixels.
+///
+/// Inputs are in `(channel, row, col)`, and the number of channels must match the pixel type.
+///
+/// The pixel type is a type parameter of the method since the same data may be used by several of them.
+pub trait IntoImage {
+    type Subpixel;
+
+    fn into_image<P>(self) -> ImageBuffer<P, Vec<Self::Subpixel>>
+    where
+        P: Pixel<Subpixel = Self::Subpixel>;
+}
+
+/// Converts a 3d type into an image like [`IntoImage`], splitting the rows across the rayon thread pool.
+///
+/// The result is identical to [`IntoImage`].
+///
+/// The pixel type is a type parameter of the method since the same data may be used by several of them.
+#[cfg(feature = "rayon")]
+pub trait IntoImagePar {
+    type Subpixel;
+
+    fn into_image_par<P>(self) -> ImageBuffer<P, Vec<Self::Subpixel>>
+    where
+        P: Pixel<Subpixel = Self::Subpixel>;
+}
+
+/// Combines a 3d type of colors and a 2d type of alpha into an image with an alpha channel.
+///
+/// The colors are in `(channel, row, col)` and the alpha is in `(row, col)`. The pixel type must have an alpha
+/// channel after the colors, as [`Rgba`](image::Rgba) and [`LumaA`](image::LumaA) do, and one more channel than
+/// the colors.
+///
+/// The pixel type is a type parameter of the method since the same data may be used by several of them.
+#[cfg(feature = "ndarray")]
+pub trait IntoImageWithAlpha {
+    type Subpixel;
+
+    fn into_image_with_alpha<P>(self) -> ImageBuffer<P, Vec<Self::Subpixel>>
+    where
+        P: Pixel<Subpixel = Self::Subpixel>;
+}
+
+/// Converts a 2d type of colors into an `f32` RGB image.
+///
+/// Inputs are in (row, col), and the colors are converted to non-linear sRGB.
+///
+/// This uses an associated type to avoid ambiguity for the compiler.
+/// By calling this, the compiler always knows the returned type.
+pub trait IntoImageColor {
+    type Out;
+
+    fn into_image_color(self) -> Self::Out;
+}
+
+/// Converts a type to an 8-bit RGB image.
+///
+/// This uses an associated type to avoid ambiguity for the compiler.
+/// By calling this, the compiler always knows the returned type.
+pub trait IntoImageRgb {
+    type Out;
+
+    fn into_image_rgb(self) -> Self::Out;
+}
+
+/// Converts a type to an 8-bit RGBA image.
+///
+/// This uses an associated type to avoid ambiguity for the compiler.
+/// By calling this, the compiler always knows the returned type.
+pub trait IntoImageRgba {
+    type Out;
+
+    fn into_image_rgba(self) -> Self::Out;
+}
+
+/// Demosaics a raw image behind a color filter array into an RGB image.
+///
+/// This uses an associated type to avoid ambiguity for the compiler.
+/// By calling this, the compiler always knows the returned type.
+pub trait IntoImageDemosaiced {
+    type Out;
+
+    fn into_image_demosaiced(self, algorithm: crate::Demosaic) -> Self::Out;
+}
+
+/// Converts a 4d type into the frames of an animation, for encoding.
+///
+/// Inputs are in `(time, channel, row, col)` or `(time, row, col, channel)` depending on `order`, with
+/// RGB or RGBA channels. Every frame covers the whole canvas and gets the delay at the same index.
+///
+/// This uses an associated type to avoid ambiguity for the compiler.
+/// By calling this, the compiler always knows the returned type.
+#[cfg(feature = "ndarray")]
+pub trait IntoImageFrames {
+    type Out;
+
+    fn into_image_frames(self, delays: &[image::Delay], order: crate::ChannelOrder) -> Self::Out;
+}
+
+/// Converts a type with premultiplied alpha into an image with straight alpha.
+///
+/// This uses an associated type to avoid ambiguity for the compiler.
+/// By calling this, the compiler always knows the returned type.
+pub trait IntoImageDemultiplied {
+    type Out;
+
+    fn into_image_demultiplied(self) -> Self::Out;
+}
+
+/// Projects points in `(point, axis)` into a 16-bit depth image of the given `(width, height)`.
+///
+/// Depths are rounded to the nearest raw unit of the camera, so the result back-projects through
+/// [`IntoNdarray2Points`](crate::IntoNdarray2Points) like a depth map read from a sensor. Pixels without a
+/// point are zero, and the nearest point wins where several land on one pixel.
+///
+/// This uses an associated type to avoid ambiguity for the compiler.
+/// By calling this, the compiler always knows the returned type.
+#[cfg(all(feature = "nalgebra", feature = "ndarray"))]
+pub trait IntoImageDepth {
+    type Out;
+
+    fn into_image_depth(self, camera: &crate::DepthCamera, dimensions: (u32, u32)) -> Self::Out;
+}
+
+/// Converts a 3d type into an image that borrows its data, without copying.
+///
+/// Inputs are in `(row, col, channel)` and must be interleaved in row-major order without padding.
+/// The number of channels must match the pixel type.
+///
+/// The pixel type is a type parameter of the method since the same data may be used by several of them.
+pub trait IntoImageView {
+    type Subpixel;
+    type Container;
+
+    fn into_image_view<P>(self) -> ImageBuffer<P, Self::Container>
+    where
+        P: Pixel<Subpixel = Self::Subpixel>;
+}
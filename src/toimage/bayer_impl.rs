@@ -0,0 +1,60 @@
+//! Implementations for Bayer images being converted to and from image types.
+
+use super::*;
+use crate::{BayerError, BayerImage, BayerPattern, BayerSample, Demosaic};
+use core::ops::Deref;
+use image::{Luma, Rgb};
+
+/// Raw images loaded as grayscale images.
+impl<'a, T> BayerImage<'a, T>
+where
+    T: BayerSample,
+    Luma<T>: Pixel<Subpixel = T>,
+{
+    /// Borrows a grayscale image holding the raw samples, recording the pattern of the filter colors.
+    ///
+    /// ```
+    /// use image::GrayImage;
+    /// use nshare::{BayerImage, BayerPattern};
+    ///
+    /// let img = GrayImage::new(4, 2);
+    /// let bayer = BayerImage::from_image(&img, BayerPattern::Grbg).unwrap();
+    /// assert_eq!((bayer.width(), bayer.height()), (4, 2));
+    /// ```
+    pub fn from_image<Container>(
+        img: &'a ImageBuffer<Luma<T>, Container>,
+        pattern: BayerPattern,
+    ) -> Result<Self, BayerError>
+    where
+        Container: Deref<Target = [T]>,
+    {
+        let (width, height) = img.dimensions();
+        Self::new(img.as_raw(), width as usize, height as usize, pattern)
+    }
+}
+
+/// ```
+/// use image::{ImageBuffer, Luma, Rgb};
+/// use nshare::{BayerImage, BayerPattern, Demosaic, IntoImageDemosaiced};
+///
+/// // A 12-bit flat field.
+/// let raw: ImageBuffer<Luma<u16>, Vec<u16>> = ImageBuffer::from_pixel(4, 4, Luma([4095]));
+/// let bayer = BayerImage::from_image(&raw, BayerPattern::Rggb).unwrap();
+/// let img = bayer.into_image_demosaiced(Demosaic::EdgeAware);
+/// assert_eq!(img.get_pixel(2, 1), &Rgb([4095, 4095, 4095]));
+/// ```
+impl<T> IntoImageDemosaiced for BayerImage<'_, T>
+where
+    T: BayerSample,
+    Rgb<T>: Pixel<Subpixel = T>,
+{
+    type Out = ImageBuffer<Rgb<T>, Vec<T>>;
+
+    fn into_image_demosaiced(self, algorithm: Demosaic) -> Self::Out {
+        let mut rgb = alloc::vec![T::default(); self.width() * self.height() * 3];
+        self.demosaic_into(algorithm, &mut rgb);
+        let width = u32::try_from(self.width()).expect("the width must fit in a u32");
+        let height = u32::try_from(self.height()).expect("the height must fit in a u32");
+        ImageBuffer::from_raw(width, height, rgb).unwrap()
+    }
+}
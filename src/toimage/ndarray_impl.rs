@@ -0,0 +1,602 @@
+//! Implementations for conversions from ndarray types to image types.
+
+use super::*;
+use crate::ChannelOrder;
+use image::Primitive;
+use image::{Delay, Frame, GrayImage, Luma, Rgba, RgbaImage};
+use ndarray::{s, ArrayView2, ArrayView3, ArrayView4, ArrayViewMut3, Axis};
+
+/// ```
+/// use image::{GrayImage, Luma};
+/// use nshare::{IntoImageQuantized, Quantization, Scaling};
+///
+/// let arr = ndarray::array![
+///     [-1.0, 0.0, 3.0f32],
+///     [1.0, f32::NAN, 2.0],
+/// ];
+/// let quantization = Quantization {
+///     scaling: Scaling::AutoImage,
+///     ..Quantization::UNIT
+/// };
+/// let img: GrayImage = arr.view().into_image_quantized(&quantization);
+/// // ndarray uses (row, col), so the dims get flipped.
+/// assert_eq!(img.dimensions(), (3, 2));
+/// assert_eq!(img[(0, 0)], Luma([0]));
+/// assert_eq!(img[(1, 0)], Luma([64]));
+/// assert_eq!(img[(2, 0)], Luma([255]));
+/// assert_eq!(img[(1, 1)], Luma([0]));
+/// ```
+impl<A> IntoImageQuantized for ArrayView2<'_, A>
+where
+    A: Copy + Into<f64>,
+{
+    fn into_image_quantized<P>(
+        self,
+        quantization: &Quantization,
+    ) -> ImageBuffer<P, Vec<P::Subpixel>>
+    where
+        P: Pixel,
+    {
+        check_single_channel::<P>();
+        self.insert_axis(Axis(0)).into_image_quantized(quantization)
+    }
+}
+
+/// ```
+/// use image::GrayImage;
+/// use nshare::{IntoImageQuantized, IntoImageQuantizedPar, Quantization};
+///
+/// let arr = ndarray::Array2::from_shape_fn((64, 48), |(row, col)| (row * col) as f32 / 3000.0);
+/// let img: GrayImage = arr.view().into_image_quantized_par(&Quantization::UNIT);
+/// assert_eq!(img, arr.view().into_image_quantized::<image::Luma<u8>>(&Quantization::UNIT));
+/// ```
+#[cfg(feature = "rayon")]
+impl<A> IntoImageQuantizedPar for ArrayView2<'_, A>
+where
+    A: Copy + Into<f64> + Send + Sync,
+{
+    fn into_image_quantized_par<P>(
+        self,
+        quantization: &Quantization,
+    ) -> ImageBuffer<P, Vec<P::Subpixel>>
+    where
+        P: Pixel,
+        P::Subpixel: Send + Sync,
+    {
+        check_single_channel::<P>();
+        self.insert_axis(Axis(0))
+            .into_image_quantized_par(quantization)
+    }
+}
+
+/// Checks that a 2d array can fill every channel of `P`.
+fn check_single_channel<P: Pixel>() {
+    assert_eq!(
+        P::CHANNEL_COUNT,
+        1,
+        "a 2d array can only be quantized to a single channel pixel"
+    );
+}
+
+/// ```
+/// use image::{Rgb, Rgb32FImage, RgbImage};
+/// use nshare::{IntoImageQuantized, Quantization, Rounding, Scaling};
+///
+/// let mut arr = ndarray::Array3::<f64>::zeros((3, 4, 2));
+/// arr[(0, 0, 1)] = 2.0;
+/// arr[(1, 0, 1)] = 0.5;
+/// arr[(2, 0, 1)] = -1.0;
+/// let img: RgbImage = arr.view().into_image_quantized(&Quantization::UNIT);
+/// // ndarray uses (channel, row, col), so the dims get flipped.
+/// assert_eq!(img.dimensions(), (2, 4));
+/// assert_eq!(img[(1, 0)], Rgb([255, 128, 0]));
+///
+/// let quantization = Quantization {
+///     scaling: Scaling::AutoChannel,
+///     rounding: Rounding::Floor,
+///     ..Quantization::UNIT
+/// };
+/// let img: RgbImage = arr.view().into_image_quantized(&quantization);
+/// assert_eq!(img[(0, 0)], Rgb([0, 0, 255]));
+/// assert_eq!(img[(1, 0)], Rgb([255, 255, 0]));
+///
+/// // Float subpixels are scaled into [0, 1] and not rounded.
+/// let img: Rgb32FImage = arr.view().into_image_quantized(&quantization);
+/// assert_eq!(img[(1, 0)], Rgb([1.0, 1.0, 0.0]));
+/// ```
+impl<A> IntoImageQuantized for ArrayView3<'_, A>
+where
+    A: Copy + Into<f64>,
+{
+    fn into_image_quantized<P>(
+        self,
+        quantization: &Quantization,
+    ) -> ImageBuffer<P, Vec<P::Subpixel>>
+    where
+        P: Pixel,
+    {
+        let (_, height, width) = self.dim();
+        check_channels::<P>(self.len_of(Axis(0)));
+        let ranges = input_ranges(self, quantization.scaling);
+        let quantizer = Quantizer::<P::Subpixel>::new(quantization);
+        let data = quantizer.quantize_rows(self, &ranges);
+        ImageBuffer::from_raw(width as u32, height as u32, data).unwrap()
+    }
+}
+
+/// ```
+/// use image::RgbImage;
+/// use nshare::{IntoImageQuantized, IntoImageQuantizedPar, Quantization, Scaling};
+///
+/// let arr = ndarray::Array3::from_shape_fn((3, 64, 48), |(c, row, col)| (c * row * col) as f64);
+/// let quantization = Quantization {
+///     scaling: Scaling::AutoChannel,
+///     ..Quantization::UNIT
+/// };
+/// let img: RgbImage = arr.view().into_image_quantized_par(&quantization);
+/// assert_eq!(img, arr.view().into_image_quantized::<image::Rgb<u8>>(&quantization));
+/// ```
+#[cfg(feature = "rayon")]
+impl<A> IntoImageQuantizedPar for ArrayView3<'_, A>
+where
+    A: Copy + Into<f64> + Send + Sync,
+{
+    fn into_image_quantized_par<P>(
+        self,
+        quantization: &Quantization,
+    ) -> ImageBuffer<P, Vec<P::Subpixel>>
+    where
+        P: Pixel,
+        P::Subpixel: Send + Sync,
+    {
+        let (_, height, width) = self.dim();
+        check_channels::<P>(self.len_of(Axis(0)));
+        let ranges = input_ranges(self, quantization.scaling);
+        let quantizer = Quantizer::<P::Subpixel>::new(quantization);
+        let data = quantizer.quantize_rows_par(self, &ranges);
+        ImageBuffer::from_raw(width as u32, height as u32, data).unwrap()
+    }
+}
+
+/// Checks that the channel axis of a 3d array fills every channel of `P`.
+fn check_channels<P: Pixel>(channels: usize) {
+    assert_eq!(
+        channels,
+        P::CHANNEL_COUNT as usize,
+        "the channel axis must match the number of channels in the pixel"
+    );
+}
+
+/// Finds the input `(min, max)` of every channel.
+fn input_ranges<A>(view: ArrayView3<'_, A>, scaling: Scaling) -> Vec<(f64, f64)>
+where
+    A: Copy + Into<f64>,
+{
+    let finite_range = |values: &mut dyn Iterator<Item = f64>| {
+        values
+            .filter(|value| value.is_finite())
+            .fold(None, |range, value| match range {
+                None => Some((value, value)),
+                Some((min, max)) => Some((f64::min(min, value), f64::max(max, value))),
+            })
+            .unwrap_or((0.0, 0.0))
+    };
+    let channels = view.len_of(Axis(0));
+    match scaling {
+        Scaling::Fixed { min, max } => alloc::vec![(min, max); channels],
+        Scaling::AutoImage => {
+            alloc::vec![finite_range(&mut view.iter().map(|&value| value.into())); channels]
+        }
+        Scaling::AutoChannel => view
+            .outer_iter()
+            .map(|channel| finite_range(&mut channel.iter().map(|&value| value.into())))
+            .collect(),
+    }
+}
+
+/// Maps scaled values onto a subpixel type.
+struct Quantizer<T> {
+    min: f64,
+    max: f64,
+    rounding: Option<Rounding>,
+    nan: NanHandling,
+    _subpixel: core::marker::PhantomData<T>,
+}
+
+impl<T> Quantizer<T>
+where
+    T: Primitive,
+{
+    fn new(quantization: &Quantization) -> Self {
+        // Only integer subpixels truncate a half.
+        let is_float = T::from(0.5).and_then(|half| half.to_f64()) == Some(0.5);
+        Self {
+            min: T::DEFAULT_MIN_VALUE.to_f64().unwrap(),
+            max: T::DEFAULT_MAX_VALUE.to_f64().unwrap(),
+            rounding: (!is_float).then_some(quantization.rounding),
+            nan: quantization.nan,
+            _subpixel: core::marker::PhantomData,
+        }
+    }
+
+    /// Quantizes `(channel, row, col)` values into interleaved subpixels.
+    fn quantize_rows<A>(&self, view: ArrayView3<'_, A>, ranges: &[(f64, f64)]) -> Vec<T>
+    where
+        A: Copy + Into<f64>,
+    {
+        let (channels, height, width) = view.dim();
+        let row_len = channels * width;
+        let mut data = alloc::vec![T::DEFAULT_MIN_VALUE; row_len * height];
+        if row_len != 0 {
+            data.chunks_exact_mut(row_len)
+                .zip(view.axis_iter(Axis(1)))
+                .for_each(|(out_row, row)| self.quantize_row(row, out_row, ranges));
+        }
+        data
+    }
+
+    /// Quantizes like [`Self::quantize_rows`], one row per task.
+    #[cfg(feature = "rayon")]
+    fn quantize_rows_par<A>(&self, view: ArrayView3<'_, A>, ranges: &[(f64, f64)]) -> Vec<T>
+    where
+        A: Copy + Into<f64> + Send + Sync,
+        T: Send + Sync,
+    {
+        use rayon::prelude::*;
+        let (channels, height, width) = view.dim();
+        let row_len = channels * width;
+        let mut data = alloc::vec![T::DEFAULT_MIN_VALUE; row_len * height];
+        if row_len != 0 {
+            data.par_chunks_exact_mut(row_len)
+                .zip(view.axis_iter(Axis(1)).into_par_iter())
+                .for_each(|(out_row, row)| self.quantize_row(row, out_row, ranges));
+        }
+        data
+    }
+
+    /// Quantizes a `(channel, col)` view into a row of interleaved subpixels.
+    fn quantize_row<A>(&self, row: ArrayView2<'_, A>, out: &mut [T], ranges: &[(f64, f64)])
+    where
+        A: Copy + Into<f64>,
+    {
+        let channels = row.nrows();
+        for (out_pixel, pixel) in out.chunks_exact_mut(channels).zip(row.columns()) {
+            for ((out, &value), &range) in out_pixel.iter_mut().zip(pixel).zip(ranges) {
+                *out = self.quantize(value.into(), range);
+            }
+        }
+    }
+
+    fn quantize(&self, value: f64, (low, high): (f64, f64)) -> T {
+        let value = match (value.is_nan(), self.nan) {
+            (false, _) => value,
+            (true, NanHandling::Min) => return T::DEFAULT_MIN_VALUE,
+            (true, NanHandling::Max) => return T::DEFAULT_MAX_VALUE,
+            (true, NanHandling::Value(value)) => value,
+        };
+        let t = if high > low {
+            (value - low) / (high - low)
+        } else {
+            0.0
+        };
+        let scaled = (self.min + t * (self.max - self.min)).clamp(self.min, self.max);
+        let rounded = match self.rounding {
+            None => scaled,
+            Some(Rounding::Nearest) => floor(scaled + 0.5),
+            Some(Rounding::Floor) => floor(scaled),
+            Some(Rounding::Ceil) => -floor(-scaled),
+        };
+        // The clamped value can still be just out of range when the maximum is not exactly representable.
+        T::from(rounded).unwrap_or(if rounded > 0.0 {
+            T::DEFAULT_MAX_VALUE
+        } else {
+            T::DEFAULT_MIN_VALUE
+        })
+    }
+}
+
+/// `f64::floor` is not available without `std`.
+fn floor(value: f64) -> f64 {
+    // Every float of this magnitude is already an integer.
+    if value.abs() >= (1u64 << f64::MANTISSA_DIGITS) as f64 || value.is_nan() {
+        return value;
+    }
+    let truncated = value as i64 as f64;
+    if truncated > value {
+        truncated - 1.0
+    } else {
+        truncated
+    }
+}
+
+/// ```
+/// use image::Luma;
+/// use ndarray::Axis;
+/// use nshare::IntoImageSlice;
+///
+/// let volume = ndarray::Array3::from_shape_fn((4, 3, 2), |(z, y, x)| (z * 100 + y * 10 + x) as u16);
+/// // A coronal slice keeps z as the rows and x as the columns.
+/// let img = volume.view().into_image_slice(Axis(1), 2);
+/// assert_eq!(img.dimensions(), (2, 4));
+/// assert_eq!(img[(1, 3)], Luma([321]));
+/// ```
+impl<A> IntoImageSlice for ArrayView3<'_, A>
+where
+    A: Primitive,
+{
+    type Out = ImageBuffer<Luma<A>, Vec<A>>;
+
+    fn into_image_slice(self, axis: Axis, index: usize) -> Self::Out {
+        assert!(
+            index < self.len_of(axis),
+            "the index must be within the length of the axis"
+        );
+        let slice = self.index_axis_move(axis, index);
+        let (height, width) = slice.dim();
+        let data = slice.iter().copied().collect();
+        ImageBuffer::from_raw(width as u32, height as u32, data).unwrap()
+    }
+}
+
+/// ```
+/// use image::Luma;
+/// use ndarray::Axis;
+/// use nshare::{IntoImageSliceWindowed, Window};
+///
+/// // Hounsfield units of air, water, soft tissue and bone.
+/// let volume = ndarray::Array3::from_shape_vec((1, 1, 4), vec![-1000i16, 0, 40, 1000]).unwrap();
+/// let window = Window { level: 40.0, width: 400.0 };
+/// let img = volume.view().into_image_slice_windowed(Axis(0), 0, window);
+/// assert_eq!(img.as_raw(), &[0, 102, 128, 255]);
+/// ```
+impl<A> IntoImageSliceWindowed for ArrayView3<'_, A>
+where
+    A: Copy + Into<f64>,
+{
+    type Out = GrayImage;
+
+    fn into_image_slice_windowed(self, axis: Axis, index: usize, window: Window) -> Self::Out {
+        assert!(
+            index < self.len_of(axis),
+            "the index must be within the length of the axis"
+        );
+        let quantization = Quantization {
+            scaling: window.into(),
+            ..Quantization::UNIT
+        };
+        self.index_axis_move(axis, index)
+            .into_image_quantized(&quantization)
+    }
+}
+
+/// ```
+/// use image::{Rgb, RgbImage};
+/// use nshare::{IntoImage, IntoNdarray3Planar};
+///
+/// let mut arr = ndarray::Array3::<u8>::zeros((3, 4, 2));
+/// arr[(1, 0, 1)] = 255;
+/// let img: RgbImage = arr.view().into_image();
+/// // ndarray uses (channel, row, col), so the dims get flipped.
+/// assert_eq!(img.dimensions(), (2, 4));
+/// assert_eq!(img[(1, 0)], Rgb([0, 255, 0]));
+/// // The planar and interleaved conversions are inverses.
+/// assert_eq!(img.into_ndarray3_planar(), arr);
+/// ```
+impl<A> IntoImage for ArrayView3<'_, A>
+where
+    A: Primitive,
+{
+    type Subpixel = A;
+
+    fn into_image<P>(self) -> ImageBuffer<P, Vec<A>>
+    where
+        P: Pixel<Subpixel = A>,
+    {
+        let (channels, height, width) = self.dim();
+        check_channels::<P>(channels);
+        let row_len = channels * width;
+        let mut data = alloc::vec![A::DEFAULT_MIN_VALUE; row_len * height];
+        if row_len != 0 {
+            // Rows are interleaved one at a time so that they stay in cache while every plane is read.
+            data.chunks_exact_mut(row_len)
+                .zip(self.axis_iter(Axis(1)))
+                .for_each(|(out_row, row)| interleave_row(row, out_row));
+        }
+        ImageBuffer::from_raw(width as u32, height as u32, data).unwrap()
+    }
+}
+
+/// ```
+/// use image::RgbImage;
+/// use nshare::{IntoImage, IntoImagePar};
+///
+/// let arr = ndarray::Array3::from_shape_fn((3, 64, 48), |(c, row, col)| (c + row + col) as u8);
+/// let img: RgbImage = arr.view().into_image_par();
+/// assert_eq!(img, arr.view().into_image::<image::Rgb<u8>>());
+/// ```
+#[cfg(feature = "rayon")]
+impl<A> IntoImagePar for ArrayView3<'_, A>
+where
+    A: Primitive + Send + Sync,
+{
+    type Subpixel = A;
+
+    fn into_image_par<P>(self) -> ImageBuffer<P, Vec<A>>
+    where
+        P: Pixel<Subpixel = A>,
+    {
+        use rayon::prelude::*;
+        let (channels, height, width) = self.dim();
+        check_channels::<P>(channels);
+        let row_len = channels * width;
+        let mut data = alloc::vec![A::DEFAULT_MIN_VALUE; row_len * height];
+        if row_len != 0 {
+            data.par_chunks_exact_mut(row_len)
+                .zip(self.axis_iter(Axis(1)).into_par_iter())
+                .for_each(|(out_row, row)| interleave_row(row, out_row));
+        }
+        ImageBuffer::from_raw(width as u32, height as u32, data).unwrap()
+    }
+}
+
+/// ```
+/// use image::{LumaA, Rgba, RgbaImage};
+/// use nshare::{AsNdarray3Alpha, IntoImageWithAlpha};
+///
+/// let color = ndarray::Array3::from_shape_fn((3, 4, 2), |(c, _, x)| (c * 10 + x) as u8);
+/// let alpha = ndarray::Array2::from_elem((4, 2), 255u8);
+/// let img: RgbaImage = (color.view(), alpha.view()).into_image_with_alpha();
+/// assert_eq!(img.dimensions(), (2, 4));
+/// assert_eq!(img[(1, 3)], Rgba([1, 11, 21, 255]));
+/// // Splitting the alpha back off gives the same arrays.
+/// assert_eq!(img.as_ndarray3_alpha(), (color.view(), alpha.view()));
+///
+/// let gray: image::GrayAlphaImage = (color.slice(ndarray::s![..1, .., ..]), alpha.view()).into_image_with_alpha();
+/// assert_eq!(gray[(1, 3)], LumaA([1, 255]));
+/// ```
+impl<A> IntoImageWithAlpha for (ArrayView3<'_, A>, ArrayView2<'_, A>)
+where
+    A: Primitive,
+{
+    type Subpixel = A;
+
+    fn into_image_with_alpha<P>(self) -> ImageBuffer<P, Vec<A>>
+    where
+        P: Pixel<Subpixel = A>,
+    {
+        let (color, alpha) = self;
+        let (channels, height, width) = color.dim();
+        assert!(P::HAS_ALPHA, "the pixel must have an alpha channel");
+        assert_eq!(
+            channels + 1,
+            P::CHANNEL_COUNT as usize,
+            "the channel axis must match the number of color channels in the pixel"
+        );
+        assert_eq!(
+            alpha.dim(),
+            (height, width),
+            "the alpha must have the dimensions of the colors"
+        );
+        let mut data = alloc::vec![A::DEFAULT_MIN_VALUE; (channels + 1) * height * width];
+        let mut pixels =
+            ArrayViewMut3::from_shape((height, width, channels + 1), &mut data).unwrap();
+        pixels
+            .slice_mut(s![.., .., ..channels])
+            .assign(&color.permuted_axes([1, 2, 0]));
+        pixels.index_axis_mut(Axis(2), channels).assign(&alpha);
+        ImageBuffer::from_raw(width as u32, height as u32, data).unwrap()
+    }
+}
+
+/// Merges a `(channel, col)` view into a row of interleaved pixels.
+fn interleave_row<T: Copy>(row: ArrayView2<'_, T>, out: &mut [T]) {
+    let channels = row.nrows();
+    for (c, lane) in row.outer_iter().enumerate() {
+        match (lane.as_slice(), channels) {
+            // Contiguous planes with a known number of channels let the compiler unroll and vectorize.
+            (Some(lane), 1) => interleave_lane::<T, 1>(lane, c, out),
+            (Some(lane), 2) => interleave_lane::<T, 2>(lane, c, out),
+            (Some(lane), 3) => interleave_lane::<T, 3>(lane, c, out),
+            (Some(lane), 4) => interleave_lane::<T, 4>(lane, c, out),
+            _ => {
+                for (pixel, &value) in out.chunks_exact_mut(channels).zip(lane) {
+                    pixel[c] = value;
+                }
+            }
+        }
+    }
+}
+
+fn interleave_lane<T: Copy, const C: usize>(lane: &[T], c: usize, out: &mut [T]) {
+    for (pixel, &value) in out.chunks_exact_mut(C).zip(lane) {
+        pixel[c] = value;
+    }
+}
+
+/// RGB inputs give opaque frames.
+///
+/// ```
+/// use image::{Delay, Rgba};
+/// use nshare::{ChannelOrder, IntoImageFrames};
+///
+/// // Two RGB frames of 2x1 pixels.
+/// let arr = ndarray::Array4::from_shape_fn((2, 3, 1, 2), |(t, c, _, x)| (t * 100 + c * 10 + x) as u8);
+/// let delays = [Delay::from_numer_denom_ms(40, 1); 2];
+/// let frames = arr.view().into_image_frames(&delays, ChannelOrder::Chw);
+/// assert_eq!(frames.len(), 2);
+/// assert_eq!(frames[1].buffer().get_pixel(1, 0), &Rgba([101, 111, 121, 255]));
+/// assert_eq!(frames[1].delay(), delays[1]);
+/// ```
+impl IntoImageFrames for ArrayView4<'_, u8> {
+    type Out = Vec<Frame>;
+
+    fn into_image_frames(self, delays: &[Delay], order: ChannelOrder) -> Self::Out {
+        // Put the channels last, so each frame is indexed in (row, col, channel).
+        let frames = match order {
+            ChannelOrder::Chw => self.permuted_axes([0, 2, 3, 1]),
+            ChannelOrder::Hwc => self,
+        };
+        let (len, height, width, channels) = frames.dim();
+        assert_eq!(len, delays.len(), "every frame must have a delay");
+        assert!(
+            channels == 3 || channels == 4,
+            "the array must have 3 or 4 channels"
+        );
+        let width = u32::try_from(width).expect("the width must fit in a u32");
+        let height = u32::try_from(height).expect("the height must fit in a u32");
+        frames
+            .outer_iter()
+            .zip(delays)
+            .map(|(frame, &delay)| {
+                // Channel-first frames are made interleaved once, so the pixels are copied in a single pass.
+                let frame = frame.as_standard_layout();
+                let samples = frame.as_slice().unwrap();
+                let mut buffer = RgbaImage::from_pixel(width, height, Rgba([0, 0, 0, 255]));
+                for (out, pixel) in buffer
+                    .chunks_exact_mut(4)
+                    .zip(samples.chunks_exact(channels))
+                {
+                    out[..channels].copy_from_slice(pixel);
+                }
+                Frame::from_parts(buffer, 0, 0, delay)
+            })
+            .collect()
+    }
+}
+
+/// ```
+/// use nalgebra::Matrix3;
+/// use nshare::{DepthCamera, IntoImageDepth, IntoNdarray2Points};
+///
+/// let intrinsics = Matrix3::new(
+///     4.0, 0.0, 2.0,
+///     0.0, 4.0, 2.0,
+///     0.0, 0.0, 1.0,
+/// );
+/// let camera = DepthCamera::new(intrinsics, 0.001);
+/// let points = ndarray::array![[0.0, 0.0, 1.0], [0.25, -0.5, 1.2344]];
+/// let depth = points.view().into_image_depth(&camera, (5, 5));
+/// assert_eq!(depth[(2, 2)].0, [1000]);
+/// assert_eq!(depth[(3, 0)].0, [1234]);
+/// assert_eq!(depth.pixels().filter(|p| p.0 != [0]).count(), 2);
+///
+/// // The depth image back-projects like one read from a sensor.
+/// let back = depth.into_ndarray2_points(&camera);
+/// assert_eq!(back.nrows(), 2);
+/// ```
+#[cfg(feature = "nalgebra")]
+impl IntoImageDepth for ArrayView2<'_, f32> {
+    type Out = ImageBuffer<Luma<u16>, Vec<u16>>;
+
+    fn into_image_depth(self, camera: &crate::DepthCamera, dimensions: (u32, u32)) -> Self::Out {
+        use crate::IntoNdarray2Depth;
+
+        let (width, height) = dimensions;
+        let depth = self.into_ndarray2_depth(camera, (height as usize, width as usize));
+        let data = depth
+            .iter()
+            .map(|&raw| (raw.min(f32::from(u16::MAX)) + 0.5) as u16)
+            .collect();
+        ImageBuffer::from_raw(width, height, data).unwrap()
+    }
+}
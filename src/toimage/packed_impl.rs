@@ -0,0 +1,77 @@
+//! Implementations for packed pixel formats being converted to image types.
+
+use super::*;
+use crate::{PackedImage, PackedRawImage};
+use image::{Luma, Rgb, RgbImage, Rgba, RgbaImage};
+
+/// Alpha is dropped if the format stores it.
+///
+/// ```
+/// use image::Rgb;
+/// use nshare::{IntoImageRgb, PackedFormat, PackedImage};
+///
+/// let data = [0x001fu16, 0xffff];
+/// let img = PackedImage::new(&data, 1, 2, PackedFormat::Bgr565).unwrap().into_image_rgb();
+/// assert_eq!(img.get_pixel(0, 0), &Rgb([255, 0, 0]));
+/// assert_eq!(img.get_pixel(0, 1), &Rgb([255, 255, 255]));
+/// ```
+impl IntoImageRgb for PackedImage<'_> {
+    type Out = RgbImage;
+
+    fn into_image_rgb(self) -> Self::Out {
+        let (width, height) = dimensions(self.width(), self.height());
+        ImageBuffer::from_fn(width, height, |x, y| {
+            let [r, g, b, _] = self.pixel(x as usize, y as usize);
+            Rgb([r, g, b])
+        })
+    }
+}
+
+/// Formats without alpha are opaque.
+///
+/// ```
+/// use image::Rgba;
+/// use nshare::{IntoImageRgba, PackedFormat, PackedImage};
+///
+/// let data = [0x7c00u16, 0x801f];
+/// let img = PackedImage::new(&data, 2, 1, PackedFormat::Argb1555).unwrap().into_image_rgba();
+/// assert_eq!(img.get_pixel(0, 0), &Rgba([255, 0, 0, 0]));
+/// assert_eq!(img.get_pixel(1, 0), &Rgba([0, 0, 255, 255]));
+/// ```
+impl IntoImageRgba for PackedImage<'_> {
+    type Out = RgbaImage;
+
+    fn into_image_rgba(self) -> Self::Out {
+        let (width, height) = dimensions(self.width(), self.height());
+        ImageBuffer::from_fn(width, height, |x, y| {
+            Rgba(self.pixel(x as usize, y as usize))
+        })
+    }
+}
+
+/// The samples keep their bit depth, so they are not scaled to the range of `u16`.
+///
+/// ```
+/// use image::Luma;
+/// use nshare::{IntoImageLuma, PackedRawImage, RawPacking};
+///
+/// let data = [0xff, 0xff, 0xff];
+/// let img = PackedRawImage::new(&data, 2, 1, RawPacking::Raw12).unwrap().into_image_luma();
+/// assert_eq!(img.get_pixel(1, 0), &Luma([0xfff]));
+/// ```
+impl IntoImageLuma for PackedRawImage<'_> {
+    type Out = ImageBuffer<Luma<u16>, Vec<u16>>;
+
+    fn into_image_luma(self) -> Self::Out {
+        let (width, height) = dimensions(self.width(), self.height());
+        ImageBuffer::from_fn(width, height, |x, y| {
+            Luma([self.sample(x as usize, y as usize)])
+        })
+    }
+}
+
+fn dimensions(width: usize, height: usize) -> (u32, u32) {
+    let width = u32::try_from(width).expect("the width must fit in a u32");
+    let height = u32::try_from(height).expect("the height must fit in a u32");
+    (width, height)
+}
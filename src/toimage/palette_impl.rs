@@ -0,0 +1,36 @@
+//! Implementations for ndarray arrays of palette colors being converted to image types.
+
+use super::*;
+use image::{Rgb, Rgb32FImage};
+use ndarray::{ArrayBase, Data, Ix2};
+use palette::{FromColor, Srgb};
+
+/// ```
+/// use nshare::IntoImageColor;
+/// use ndarray::Array2;
+/// use palette::{LinSrgb, Srgb};
+///
+/// let linear = Array2::from_elem((1, 2), LinSrgb::new(0.5f32, 0.0, 1.0));
+/// let img = linear.view().into_image_color();
+/// assert_eq!(img.dimensions(), (2, 1));
+/// let encoded: Srgb<f32> = Srgb::from_linear(LinSrgb::new(0.5, 0.0, 1.0));
+/// assert_eq!(img.get_pixel(1, 0).0, [encoded.red, encoded.green, encoded.blue]);
+/// ```
+impl<C, S> IntoImageColor for ArrayBase<S, Ix2>
+where
+    C: Clone,
+    Srgb<f32>: FromColor<C>,
+    S: Data<Elem = C>,
+{
+    type Out = Rgb32FImage;
+
+    fn into_image_color(self) -> Self::Out {
+        let (height, width) = self.dim();
+        let mut subpixels = Vec::with_capacity(self.len() * 3);
+        for color in self.iter() {
+            let srgb = Srgb::from_color(color.clone());
+            subpixels.extend_from_slice(&[srgb.red, srgb.green, srgb.blue]);
+        }
+        ImageBuffer::<Rgb<f32>, _>::from_raw(width as u32, height as u32, subpixels).unwrap()
+    }
+}
@@ -0,0 +1,77 @@
+//! Implementations for strided slices being converted to image types.
+
+use super::*;
+use crate::{StridedSlice, StridedSliceMut};
+
+/// ```
+/// use image::Rgb;
+/// use nshare::{IntoImageView, StridedSlice};
+///
+/// // Two rows of two RGB pixels, after a header of 4 elements.
+/// let data: Vec<u8> = (0..16).collect();
+/// let s = StridedSlice::new(&data, [2, 2, 3], [6, 3, 1], 4).unwrap();
+/// let img = s.into_image_view::<Rgb<u8>>();
+/// assert_eq!(img.dimensions(), (2, 2));
+/// assert_eq!(img.get_pixel(1, 0), &Rgb([7, 8, 9]));
+/// ```
+impl<'a, T> IntoImageView for StridedSlice<'a, T, 3>
+where
+    T: image::Primitive,
+{
+    type Subpixel = T;
+    type Container = &'a [T];
+
+    fn into_image_view<P>(self) -> ImageBuffer<P, Self::Container>
+    where
+        P: Pixel<Subpixel = Self::Subpixel>,
+    {
+        let (width, height, len) = interleaved::<P>(self.shape, self.strides);
+        let data = &self.data[self.offset..self.offset + len];
+        ImageBuffer::from_raw(width, height, data).unwrap()
+    }
+}
+
+/// ```
+/// use image::Luma;
+/// use nshare::{IntoImageView, StridedSliceMut};
+///
+/// let mut data = [0u8; 6];
+/// let s = StridedSliceMut::contiguous(&mut data, [2, 3, 1]).unwrap();
+/// s.into_image_view::<Luma<u8>>().put_pixel(2, 1, Luma([255]));
+/// assert_eq!(data, [0, 0, 0, 0, 0, 255]);
+/// ```
+impl<'a, T> IntoImageView for StridedSliceMut<'a, T, 3>
+where
+    T: image::Primitive,
+{
+    type Subpixel = T;
+    type Container = &'a mut [T];
+
+    fn into_image_view<P>(self) -> ImageBuffer<P, Self::Container>
+    where
+        P: Pixel<Subpixel = Self::Subpixel>,
+    {
+        let (width, height, len) = interleaved::<P>(self.shape, self.strides);
+        let data = &mut self.data[self.offset..self.offset + len];
+        ImageBuffer::from_raw(width, height, data).unwrap()
+    }
+}
+
+/// The width, height and number of subpixels of an image with the layout, which must be interleaved.
+fn interleaved<P: Pixel>(shape: [usize; 3], strides: [isize; 3]) -> (u32, u32, usize) {
+    let [height, width, channels] = shape;
+    assert_eq!(
+        channels,
+        P::CHANNEL_COUNT as usize,
+        "the channel axis must match the pixel type"
+    );
+    let row_stride = (width * channels) as isize;
+    assert_eq!(
+        strides,
+        [row_stride, channels as isize, 1],
+        "the layout must be interleaved in row-major order without padding"
+    );
+    let width = u32::try_from(width).expect("the width must fit in a u32");
+    let height = u32::try_from(height).expect("the height must fit in a u32");
+    (width, height, shape.iter().product())
+}
@@ -0,0 +1,49 @@
+//! Implementations for tiny-skia types being converted to image types.
+
+use super::*;
+use image::{Rgba, RgbaImage};
+use tiny_skia::{Pixmap, PixmapRef};
+
+/// ```
+/// use nshare::IntoImageDemultiplied;
+/// use tiny_skia::{ColorU8, Pixmap};
+///
+/// let mut pixmap = Pixmap::new(2, 1).unwrap();
+/// pixmap.pixels_mut()[1] = ColorU8::from_rgba(255, 100, 0, 128).premultiply();
+/// let img = pixmap.as_ref().into_image_demultiplied();
+/// assert_eq!(img.get_pixel(1, 0).0, [255, 100, 0, 128]);
+/// assert_eq!(img.get_pixel(0, 0).0, [0, 0, 0, 0]);
+/// ```
+impl IntoImageDemultiplied for PixmapRef<'_> {
+    type Out = RgbaImage;
+
+    fn into_image_demultiplied(self) -> Self::Out {
+        let subpixels = self
+            .pixels()
+            .iter()
+            .flat_map(|pixel| {
+                let color = pixel.demultiply();
+                [color.red(), color.green(), color.blue(), color.alpha()]
+            })
+            .collect();
+        ImageBuffer::<Rgba<u8>, _>::from_raw(self.width(), self.height(), subpixels).unwrap()
+    }
+}
+
+/// ```
+/// use nshare::IntoImageDemultiplied;
+/// use tiny_skia::{Color, Pixmap};
+///
+/// let mut pixmap = Pixmap::new(3, 2).unwrap();
+/// pixmap.fill(Color::from_rgba8(10, 20, 30, 255));
+/// let img = (&pixmap).into_image_demultiplied();
+/// assert_eq!(img.dimensions(), (3, 2));
+/// assert_eq!(img.get_pixel(2, 1).0, [10, 20, 30, 255]);
+/// ```
+impl IntoImageDemultiplied for &Pixmap {
+    type Out = RgbaImage;
+
+    fn into_image_demultiplied(self) -> Self::Out {
+        self.as_ref().into_image_demultiplied()
+    }
+}
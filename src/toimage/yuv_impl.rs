@@ -0,0 +1,32 @@
+//! Implementations for YUV frames being converted to image types.
+
+use super::*;
+use crate::YuvFrame;
+use image::RgbImage;
+
+/// ```
+/// use image::Rgb;
+/// use nshare::{IntoImageRgb, YuvColorSpace, YuvFormat, YuvFrame, YuvRange};
+///
+/// let full_range = YuvColorSpace { range: YuvRange::Full, ..YuvColorSpace::BT601 };
+/// let frame = YuvFrame::new([0u8, 255, 255, 255, 128, 128], YuvFormat::Nv12, 2, 2)
+///     .unwrap()
+///     .with_color_space(full_range);
+/// let img = (&frame).into_image_rgb();
+/// assert_eq!(img.get_pixel(0, 0), &Rgb([0, 0, 0]));
+/// assert_eq!(img.get_pixel(1, 1), &Rgb([255, 255, 255]));
+/// ```
+impl<B> IntoImageRgb for &YuvFrame<B>
+where
+    B: AsRef<[u8]>,
+{
+    type Out = RgbImage;
+
+    fn into_image_rgb(self) -> Self::Out {
+        let mut rgb = alloc::vec![0; self.width() * self.height() * 3];
+        self.to_rgb(&mut rgb);
+        let width = u32::try_from(self.width()).expect("the width must fit in a u32");
+        let height = u32::try_from(self.height()).expect("the height must fit in a u32");
+        RgbImage::from_raw(width, height, rgb).unwrap()
+    }
+}
@@ -0,0 +1,16 @@
+#[cfg(feature = "ndarray")]
+mod ndarray_impl;
+
+/// Converts a 2d type to an imgref image type.
+///
+/// Coordinates of the source are in (row, col), and each row becomes a row of the image.
+/// Views only convert when they are contiguous in row-major order, so the image never covers elements
+/// outside of the view.
+///
+/// This uses an associated type to avoid ambiguity for the compiler.
+/// By calling this, the compiler always knows the returned type.
+pub trait IntoImgref {
+    type Out;
+
+    fn into_imgref(self) -> Self::Out;
+}
@@ -0,0 +1,56 @@
+//! Implementations for ndarray types being converted to imgref types.
+
+use super::*;
+use imgref::{Img, ImgRef, ImgRefMut};
+use ndarray::{ArrayView2, ArrayViewMut2};
+
+/// Returns `None` unless the view is contiguous in row-major order, since the buffer of an imgref image
+/// would otherwise cover elements between the rows that the view does not own.
+///
+/// ```
+/// use nshare::IntoImgref;
+/// use ndarray::s;
+///
+/// let arr = ndarray::array![
+///     [0.1, 0.2, 0.3, 0.4],
+///     [0.5, 0.6, 0.7, 0.8],
+///     [1.1, 1.2, 1.3, 1.4],
+/// ];
+/// let img = arr.slice(s![1.., ..]).into_imgref().unwrap();
+/// assert_eq!((img.width(), img.height(), img.stride()), (4, 2, 4));
+/// assert_eq!(img[(1usize, 0usize)], 0.6);
+/// assert_eq!(img[(2usize, 1usize)], 1.3);
+///
+/// // The columns of a slice are not contiguous.
+/// assert!(arr.slice(s![1.., 1..3]).into_imgref().is_none());
+/// ```
+impl<'a, T> IntoImgref for ArrayView2<'a, T> {
+    type Out = Option<ImgRef<'a, T>>;
+
+    fn into_imgref(self) -> Self::Out {
+        let (height, width) = self.dim();
+        let buf = self.to_slice()?;
+        Some(Img::new_stride(buf, width, height, width.max(1)))
+    }
+}
+
+/// Returns `None` unless the view is contiguous in row-major order, since the buffer of an imgref image
+/// would otherwise cover elements between the rows that the view does not own.
+///
+/// ```
+/// use nshare::IntoImgref;
+///
+/// let mut arr = ndarray::Array2::<u8>::zeros((3, 4));
+/// let mut img = arr.view_mut().into_imgref().unwrap();
+/// img[(3usize, 1usize)] = 255;
+/// assert_eq!(arr[(1, 3)], 255);
+/// ```
+impl<'a, T> IntoImgref for ArrayViewMut2<'a, T> {
+    type Out = Option<ImgRefMut<'a, T>>;
+
+    fn into_imgref(self) -> Self::Out {
+        let (height, width) = self.dim();
+        let buf = self.into_slice()?;
+        Some(Img::new_stride(buf, width, height, width.max(1)))
+    }
+}
@@ -1,5 +1,22 @@
+mod array_impl;
+#[cfg(feature = "alloc")]
+mod bytes_impl;
+#[cfg(feature = "embedded-graphics")]
+mod embedded_graphics_impl;
+#[cfg(feature = "ndarray")]
+mod geometry_impl;
+#[cfg(feature = "imgref")]
+mod imgref_impl;
+#[cfg(feature = "nalgebra-sparse")]
+mod nalgebra_sparse_impl;
 #[cfg(feature = "ndarray")]
 mod ndarray_impl;
+mod point_impl;
+#[cfg(feature = "sprs")]
+mod sprs_impl;
+mod strided_impl;
+
+use core::fmt;
 
 /// Converts a 1 or 2 dimensional type to a nalgebra type.
 ///
@@ -10,3 +27,178 @@ pub trait IntoNalgebra {
 
     fn into_nalgebra(self) -> Self::Out;
 }
+
+/// Converts a 2 dimensional type to a nalgebra type, splitting the copy across the rayon thread pool.
+///
+/// The result is identical to [`IntoNalgebra`].
+///
+/// This uses an associated type to avoid ambiguity for the compiler.
+/// By calling this, the compiler always knows the returned type.
+#[cfg(feature = "rayon")]
+pub trait IntoNalgebraPar {
+    type Out;
+
+    fn into_nalgebra_par(self) -> Self::Out;
+}
+
+/// Converts a 1d type to a nalgebra column vector type.
+///
+/// This is separate from [`IntoNalgebra`] because an array of arrays is also an array,
+/// so fixed-size arrays could not otherwise be converted both as vectors and as matrices.
+///
+/// This uses an associated type to avoid ambiguity for the compiler.
+/// By calling this, the compiler always knows the returned type.
+pub trait IntoNalgebraVector {
+    type Out;
+
+    fn into_nalgebra_vector(self) -> Self::Out;
+}
+
+/// Converts a row-major 2d type to a nalgebra view of its transpose without copying.
+///
+/// Row-major data is laid out like a column-major matrix with the rows and columns swapped,
+/// so the result is contiguous, unlike the natural orientation that [`IntoNalgebra`] gives.
+///
+/// This uses an associated type to avoid ambiguity for the compiler.
+/// By calling this, the compiler always knows the returned type.
+pub trait IntoNalgebraTransposed {
+    type Out;
+
+    fn into_nalgebra_transposed(self) -> Self::Out;
+}
+
+/// Converts a 2d type of coordinates in `(point, coordinate)` into a slice of `D`-dimensional points without copying.
+///
+/// The input must be in standard layout with `D` columns, which is the layout produced by
+/// [`IntoNdarray2`](crate::IntoNdarray2) for slices of points.
+///
+/// The dimension is a parameter of the trait since it cannot be known from the type of the input.
+pub trait IntoNalgebraPoints<const D: usize> {
+    type Out;
+
+    fn into_nalgebra_points(self) -> Self::Out;
+}
+
+/// Converts a 3d type in `(matrix, row, col)` into `R x C` nalgebra matrices.
+///
+/// Views are reinterpreted without copying, so they must be column-major within each matrix and have
+/// no gaps between matrices, which is the layout produced by [`IntoNdarray3`](crate::IntoNdarray3) for
+/// slices of matrices. Owned arrays of any layout are copied and their shape is checked instead.
+///
+/// The dimensions are parameters of the trait since they cannot be known from the type of the input.
+pub trait IntoNalgebraMatrices<const R: usize, const C: usize> {
+    type Out;
+
+    fn into_nalgebra_matrices(self) -> Self::Out;
+}
+
+/// The shape of the matrices in an array did not match the ones requested from [`IntoNalgebraMatrices`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MatrixShapeError {
+    /// The `(rows, cols)` of the requested matrices.
+    pub expected: (usize, usize),
+    /// The `(rows, cols)` of the matrices in the array.
+    pub found: (usize, usize),
+}
+
+impl fmt::Display for MatrixShapeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self {
+            expected: (rows, cols),
+            found: (found_rows, found_cols),
+        } = self;
+        write!(
+            f,
+            "the matrices must be {rows}x{cols}, but the array holds {found_rows}x{found_cols} matrices"
+        )
+    }
+}
+
+impl core::error::Error for MatrixShapeError {}
+
+/// The reason an array was rejected by [`IntoNalgebraGeometry`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GeometryError {
+    /// The quaternion is zero, so it cannot be normalized into a rotation.
+    ZeroQuaternion,
+    /// The linear part is not a rotation, or not a rotation times a positive scaling for similarities.
+    NotRotation,
+    /// The linear part of a translation is not the identity.
+    NotTranslation,
+    /// The bottom row of the homogeneous matrix is not `0 .. 0 1`.
+    NotAffine,
+    /// The homogeneous matrix does not satisfy the invariants of the category of the transform.
+    Category,
+}
+
+impl fmt::Display for GeometryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ZeroQuaternion => write!(f, "the quaternion is zero"),
+            Self::NotRotation => write!(f, "the linear part is not a rotation"),
+            Self::NotTranslation => write!(f, "the linear part is not the identity"),
+            Self::NotAffine => write!(f, "the bottom row is not that of an affine transformation"),
+            Self::Category => write!(f, "the matrix is not in the category of the transform"),
+        }
+    }
+}
+
+impl core::error::Error for GeometryError {}
+
+/// Converts a ndarray type into the nalgebra geometric transformation `G`, checking that it is one.
+///
+/// Quaternions are read from their coefficients in `[i, j, k, w]`, and unit quaternions are normalized.
+/// Rotations are read from a `(D, D)` matrix, and translations, isometries, similarities and transforms
+/// from a `(D + 1, D + 1)` homogeneous matrix. Rotations are accepted when they are orthonormal with a
+/// positive determinant, up to the square root of the machine epsilon, since matrices that went through
+/// a file or another library are rarely exact.
+///
+/// Arrays with an extra leading axis convert into a `Vec` of transformations.
+///
+/// The transformation is a type parameter of the trait since the same array may be read as several of them.
+pub trait IntoNalgebraGeometry<G> {
+    fn into_nalgebra_geometry(self) -> Result<G, GeometryError>;
+}
+
+/// A nalgebra matrix that either borrows its elements or owns a copy of them.
+///
+/// This is what typed bytes convert to, since they can only be borrowed when they are aligned and in the byte
+/// order of the target. It plays the role of ndarray's `CowArray`.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone)]
+pub enum CowMatrix<'a, T: nalgebra::Scalar> {
+    View(nalgebra::MatrixView<'a, T, nalgebra::Dyn, nalgebra::Dyn, nalgebra::Dyn, nalgebra::Dyn>),
+    Owned(nalgebra::DMatrix<T>),
+}
+
+#[cfg(feature = "alloc")]
+impl<T: nalgebra::Scalar> CowMatrix<'_, T> {
+    /// Whether the elements are borrowed.
+    pub fn is_view(&self) -> bool {
+        matches!(self, Self::View(_))
+    }
+
+    /// Whether the elements were copied.
+    pub fn is_owned(&self) -> bool {
+        matches!(self, Self::Owned(_))
+    }
+
+    /// Borrows the matrix, whichever way it holds its elements.
+    pub fn view(
+        &self,
+    ) -> nalgebra::MatrixView<'_, T, nalgebra::Dyn, nalgebra::Dyn, nalgebra::Dyn, nalgebra::Dyn>
+    {
+        match self {
+            Self::View(view) => view.as_view(),
+            Self::Owned(m) => m.as_view(),
+        }
+    }
+
+    /// Copies the elements if they are borrowed.
+    pub fn into_owned(self) -> nalgebra::DMatrix<T> {
+        match self {
+            Self::View(view) => view.into_owned(),
+            Self::Owned(m) => m,
+        }
+    }
+}
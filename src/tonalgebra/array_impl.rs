@@ -0,0 +1,261 @@
+//! Implementations for fixed-size arrays being converted to nalgebra types.
+//!
+//! Arrays of rows are row-major while nalgebra is column-major. Views in the natural orientation
+//! step over whole rows to go down a column, and [`IntoNalgebraTransposed`] gives contiguous views instead.
+
+use super::*;
+use nalgebra::{Const, Dyn, MatrixView, MatrixViewMut, SMatrix, SVector, Scalar, U1};
+
+/// ```
+/// use nshare::IntoNalgebraVector;
+///
+/// let v = [1.0, 2.0, 3.0].into_nalgebra_vector();
+/// assert_eq!(v, nalgebra::Vector3::new(1.0, 2.0, 3.0));
+/// ```
+impl<T, const N: usize> IntoNalgebraVector for [T; N]
+where
+    T: Scalar,
+{
+    type Out = SVector<T, N>;
+
+    fn into_nalgebra_vector(self) -> Self::Out {
+        SVector::from(self)
+    }
+}
+
+/// The rows are copied into the column-major storage of nalgebra.
+///
+/// ```
+/// use nshare::IntoNalgebra;
+///
+/// let m = [[1, 2, 3], [4, 5, 6]].into_nalgebra();
+/// assert_eq!(m, nalgebra::Matrix2x3::new(
+///     1, 2, 3,
+///     4, 5, 6,
+/// ));
+/// ```
+impl<T, const R: usize, const C: usize> IntoNalgebra for [[T; C]; R]
+where
+    T: Scalar,
+{
+    type Out = SMatrix<T, R, C>;
+
+    fn into_nalgebra(self) -> Self::Out {
+        SMatrix::<T, R, C>::from_fn(|row, col| self[row][col].clone())
+    }
+}
+
+/// ```
+/// use nshare::IntoNalgebraVector;
+///
+/// let arr = [1, 2, 3];
+/// let v = (&arr).into_nalgebra_vector();
+/// assert_eq!(v.shape(), (3, 1));
+/// assert_eq!(v[2], 3);
+/// ```
+impl<'a, T, const N: usize> IntoNalgebraVector for &'a [T; N]
+where
+    T: Scalar,
+{
+    type Out = MatrixView<'a, T, Const<N>, U1>;
+
+    fn into_nalgebra_vector(self) -> Self::Out {
+        MatrixView::from_slice_generic(self, Const::<N>, U1)
+    }
+}
+
+/// ```
+/// use nshare::IntoNalgebraVector;
+///
+/// let mut arr = [1, 2, 3];
+/// (&mut arr).into_nalgebra_vector().apply(|x| *x *= 2);
+/// assert_eq!(arr, [2, 4, 6]);
+/// ```
+impl<'a, T, const N: usize> IntoNalgebraVector for &'a mut [T; N]
+where
+    T: Scalar,
+{
+    type Out = MatrixViewMut<'a, T, Const<N>, U1>;
+
+    fn into_nalgebra_vector(self) -> Self::Out {
+        MatrixViewMut::from_slice_generic(self, Const::<N>, U1)
+    }
+}
+
+/// ```
+/// use nshare::IntoNalgebra;
+///
+/// let arr = [[1, 2, 3], [4, 5, 6]];
+/// let m = (&arr).into_nalgebra();
+/// assert_eq!(m.shape(), (2, 3));
+/// assert_eq!(m[(1, 0)], 4);
+/// // Going down a column steps over a whole row.
+/// assert_eq!(m.strides(), (3, 1));
+/// ```
+impl<'a, T, const R: usize, const C: usize> IntoNalgebra for &'a [[T; C]; R]
+where
+    T: Scalar,
+{
+    type Out = MatrixView<'a, T, Const<R>, Const<C>, Const<C>, U1>;
+
+    fn into_nalgebra(self) -> Self::Out {
+        MatrixView::from_slice_with_strides_generic(
+            self.as_flattened(),
+            Const::<R>,
+            Const::<C>,
+            Const::<C>,
+            U1,
+        )
+    }
+}
+
+/// ```
+/// use nshare::IntoNalgebra;
+///
+/// let mut arr = [[0; 3]; 2];
+/// (&mut arr).into_nalgebra().column_mut(1).fill(7);
+/// assert_eq!(arr, [[0, 7, 0], [0, 7, 0]]);
+/// ```
+impl<'a, T, const R: usize, const C: usize> IntoNalgebra for &'a mut [[T; C]; R]
+where
+    T: Scalar,
+{
+    type Out = MatrixViewMut<'a, T, Const<R>, Const<C>, Const<C>, U1>;
+
+    fn into_nalgebra(self) -> Self::Out {
+        MatrixViewMut::from_slice_with_strides_generic(
+            self.as_flattened_mut(),
+            Const::<R>,
+            Const::<C>,
+            Const::<C>,
+            U1,
+        )
+    }
+}
+
+/// ```
+/// use nshare::IntoNalgebra;
+///
+/// let rows: &[[f32; 2]] = &[[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]];
+/// let m = rows.into_nalgebra();
+/// assert_eq!(m.shape(), (3, 2));
+/// assert_eq!(m[(2, 1)], 6.0);
+/// ```
+impl<'a, T, const C: usize> IntoNalgebra for &'a [[T; C]]
+where
+    T: Scalar,
+{
+    type Out = MatrixView<'a, T, Dyn, Const<C>, Const<C>, U1>;
+
+    fn into_nalgebra(self) -> Self::Out {
+        MatrixView::from_slice_with_strides_generic(
+            self.as_flattened(),
+            Dyn(self.len()),
+            Const::<C>,
+            Const::<C>,
+            U1,
+        )
+    }
+}
+
+/// ```
+/// use nshare::IntoNalgebra;
+///
+/// let rows: &mut [[f32; 2]] = &mut [[1.0, 2.0], [3.0, 4.0]];
+/// rows.into_nalgebra().row_mut(0).fill(0.0);
+/// assert_eq!(rows, &[[0.0, 0.0], [3.0, 4.0]]);
+/// ```
+impl<'a, T, const C: usize> IntoNalgebra for &'a mut [[T; C]]
+where
+    T: Scalar,
+{
+    type Out = MatrixViewMut<'a, T, Dyn, Const<C>, Const<C>, U1>;
+
+    fn into_nalgebra(self) -> Self::Out {
+        let rows = Dyn(self.len());
+        MatrixViewMut::from_slice_with_strides_generic(
+            self.as_flattened_mut(),
+            rows,
+            Const::<C>,
+            Const::<C>,
+            U1,
+        )
+    }
+}
+
+/// ```
+/// use nshare::IntoNalgebraTransposed;
+///
+/// let arr = [[1, 2, 3], [4, 5, 6]];
+/// let m = (&arr).into_nalgebra_transposed();
+/// // Each row of the array is a column of the view.
+/// assert_eq!(m.shape(), (3, 2));
+/// assert_eq!(m.column(1), nalgebra::Vector3::new(4, 5, 6));
+/// assert_eq!(m.strides(), (1, 3));
+/// ```
+impl<'a, T, const R: usize, const C: usize> IntoNalgebraTransposed for &'a [[T; C]; R]
+where
+    T: Scalar,
+{
+    type Out = MatrixView<'a, T, Const<C>, Const<R>>;
+
+    fn into_nalgebra_transposed(self) -> Self::Out {
+        MatrixView::from_slice_generic(self.as_flattened(), Const::<C>, Const::<R>)
+    }
+}
+
+/// ```
+/// use nshare::IntoNalgebraTransposed;
+///
+/// let mut arr = [[0, 0], [0, 0]];
+/// (&mut arr).into_nalgebra_transposed()[(1, 0)] = 5;
+/// assert_eq!(arr, [[0, 5], [0, 0]]);
+/// ```
+impl<'a, T, const R: usize, const C: usize> IntoNalgebraTransposed for &'a mut [[T; C]; R]
+where
+    T: Scalar,
+{
+    type Out = MatrixViewMut<'a, T, Const<C>, Const<R>>;
+
+    fn into_nalgebra_transposed(self) -> Self::Out {
+        MatrixViewMut::from_slice_generic(self.as_flattened_mut(), Const::<C>, Const::<R>)
+    }
+}
+
+/// ```
+/// use nshare::IntoNalgebraTransposed;
+///
+/// let rows: &[[f32; 3]] = &[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]];
+/// let m = rows.into_nalgebra_transposed();
+/// assert_eq!(m.shape(), (3, 2));
+/// assert_eq!(m.column(0).sum(), 6.0);
+/// ```
+impl<'a, T, const C: usize> IntoNalgebraTransposed for &'a [[T; C]]
+where
+    T: Scalar,
+{
+    type Out = MatrixView<'a, T, Const<C>, Dyn>;
+
+    fn into_nalgebra_transposed(self) -> Self::Out {
+        MatrixView::from_slice_generic(self.as_flattened(), Const::<C>, Dyn(self.len()))
+    }
+}
+
+/// ```
+/// use nshare::IntoNalgebraTransposed;
+///
+/// let rows: &mut [[f32; 3]] = &mut [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]];
+/// rows.into_nalgebra_transposed().column_mut(1).fill(0.0);
+/// assert_eq!(rows, &[[1.0, 2.0, 3.0], [0.0, 0.0, 0.0]]);
+/// ```
+impl<'a, T, const C: usize> IntoNalgebraTransposed for &'a mut [[T; C]]
+where
+    T: Scalar,
+{
+    type Out = MatrixViewMut<'a, T, Const<C>, Dyn>;
+
+    fn into_nalgebra_transposed(self) -> Self::Out {
+        let rows = Dyn(self.len());
+        MatrixViewMut::from_slice_generic(self.as_flattened_mut(), Const::<C>, rows)
+    }
+}
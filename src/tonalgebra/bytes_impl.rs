@@ -0,0 +1,36 @@
+//! Implementations for typed bytes being converted to nalgebra types.
+//!
+//! The bytes are borrowed when possible and copied otherwise.
+
+use super::*;
+use crate::{ByteElement, TypedBytes};
+use nalgebra::{DMatrix, Scalar};
+
+/// The bytes are in `(row, col)` and row-major order.
+///
+/// ```
+/// use nshare::{ByteOrder, IntoNalgebra, TypedBytes};
+///
+/// let values = [1u16, 2, 3, 4, 5, 6];
+/// let bytes: Vec<u8> = values.iter().flat_map(|x| x.to_be_bytes()).collect();
+/// let typed = TypedBytes::<u16, 2>::new(&bytes, [2, 3], ByteOrder::Big).unwrap();
+/// // Whether this borrows depends on the target and on the alignment of the `Vec`.
+/// let m = typed.into_nalgebra();
+/// assert_eq!(m.view(), nalgebra::Matrix2x3::new(1, 2, 3, 4, 5, 6));
+/// ```
+impl<'a, T> IntoNalgebra for TypedBytes<'a, T, 2>
+where
+    T: ByteElement + Scalar,
+{
+    type Out = CowMatrix<'a, T>;
+
+    fn into_nalgebra(self) -> Self::Out {
+        match self.as_strided() {
+            Ok(s) => CowMatrix::View(s.into_nalgebra()),
+            Err(_) => {
+                let [rows, cols] = self.shape();
+                CowMatrix::Owned(DMatrix::from_row_slice(rows, cols, &self.to_vec()))
+            }
+        }
+    }
+}
@@ -0,0 +1,181 @@
+//! Implementations for embedded-graphics types being converted to nalgebra types.
+
+use super::*;
+use embedded_graphics::framebuffer::Framebuffer;
+#[cfg(target_endian = "big")]
+use embedded_graphics::pixelcolor::raw::BigEndian;
+#[cfg(target_endian = "little")]
+use embedded_graphics::pixelcolor::raw::LittleEndian;
+use embedded_graphics::pixelcolor::raw::{RawU16, RawU8};
+use embedded_graphics::pixelcolor::PixelColor;
+use nalgebra::{Const, MatrixView, MatrixViewMut, U1};
+
+/// Framebuffers with one byte per pixel are viewed as their raw values, with a row for each line of the display.
+///
+/// ```
+/// use embedded_graphics::framebuffer::{buffer_size, Framebuffer};
+/// use embedded_graphics::pixelcolor::raw::{LittleEndian, RawU8};
+/// use embedded_graphics::pixelcolor::Gray8;
+/// use embedded_graphics::prelude::*;
+/// use nshare::IntoNalgebra;
+///
+/// let mut fb = Framebuffer::<Gray8, RawU8, LittleEndian, 3, 2, { buffer_size::<Gray8>(3, 2) }>::new();
+/// fb.set_pixel(Point::new(2, 1), Gray8::new(200));
+/// let m = fb.into_nalgebra();
+/// assert_eq!(m.shape(), (2, 3));
+/// assert_eq!(m[(1, 2)], 200);
+/// ```
+impl<'a, C, BO, const WIDTH: usize, const HEIGHT: usize, const N: usize> IntoNalgebra
+    for &'a Framebuffer<C, RawU8, BO, WIDTH, HEIGHT, N>
+where
+    C: PixelColor<Raw = RawU8>,
+{
+    type Out = MatrixView<'a, u8, Const<HEIGHT>, Const<WIDTH>, Const<WIDTH>, U1>;
+
+    fn into_nalgebra(self) -> Self::Out {
+        MatrixView::from_slice_with_strides_generic(
+            &self.data()[..WIDTH * HEIGHT],
+            Const::<HEIGHT>,
+            Const::<WIDTH>,
+            Const::<WIDTH>,
+            U1,
+        )
+    }
+}
+
+/// ```
+/// use embedded_graphics::framebuffer::{buffer_size, Framebuffer};
+/// use embedded_graphics::image::GetPixel;
+/// use embedded_graphics::pixelcolor::raw::{LittleEndian, RawU8};
+/// use embedded_graphics::pixelcolor::Gray8;
+/// use embedded_graphics::prelude::*;
+/// use nshare::IntoNalgebra;
+///
+/// let mut fb = Framebuffer::<Gray8, RawU8, LittleEndian, 3, 2, { buffer_size::<Gray8>(3, 2) }>::new();
+/// // Fill the bottom line of the display.
+/// (&mut fb).into_nalgebra().row_mut(1).fill(255);
+/// assert_eq!(fb.pixel(Point::new(0, 1)), Some(Gray8::WHITE));
+/// assert_eq!(fb.pixel(Point::new(0, 0)), Some(Gray8::BLACK));
+/// ```
+impl<'a, C, BO, const WIDTH: usize, const HEIGHT: usize, const N: usize> IntoNalgebra
+    for &'a mut Framebuffer<C, RawU8, BO, WIDTH, HEIGHT, N>
+where
+    C: PixelColor<Raw = RawU8>,
+{
+    type Out = MatrixViewMut<'a, u8, Const<HEIGHT>, Const<WIDTH>, Const<WIDTH>, U1>;
+
+    fn into_nalgebra(self) -> Self::Out {
+        MatrixViewMut::from_slice_with_strides_generic(
+            &mut self.data_mut()[..WIDTH * HEIGHT],
+            Const::<HEIGHT>,
+            Const::<WIDTH>,
+            Const::<WIDTH>,
+            U1,
+        )
+    }
+}
+
+/// The byte order in which `u16` is stored on the target.
+///
+/// Only framebuffers in this order can be viewed as `u16`. A framebuffer in the other order, such as a
+/// big endian Rgb565 buffer for an SPI display on a little endian microcontroller, has every value
+/// byte swapped, so it cannot be viewed without copying.
+#[cfg(target_endian = "little")]
+type NativeEndian = LittleEndian;
+#[cfg(target_endian = "big")]
+type NativeEndian = BigEndian;
+
+/// Framebuffers with two bytes per pixel in the byte order of the target, such as Rgb565 ones, are viewed as
+/// their raw `u16` values, with a row for each line of the display.
+///
+/// The bytes of a framebuffer have no alignment of their own, so this gives `None` unless the framebuffer
+/// happens to be stored at an even address. Wrapping it in a type with `#[repr(align(2))]` guarantees that.
+///
+/// ```
+/// use embedded_graphics::framebuffer::{buffer_size, Framebuffer};
+/// use embedded_graphics::pixelcolor::raw::{LittleEndian, RawU16};
+/// use embedded_graphics::pixelcolor::Rgb565;
+/// use embedded_graphics::prelude::*;
+/// use nshare::IntoNalgebra;
+///
+/// #[repr(align(2))]
+/// struct Aligned<T>(T);
+///
+/// # #[cfg(target_endian = "little")] {
+/// let mut fb = Aligned(Framebuffer::<Rgb565, RawU16, LittleEndian, 3, 2, { buffer_size::<Rgb565>(3, 2) }>::new());
+/// fb.0.set_pixel(Point::new(2, 1), Rgb565::RED);
+/// let m = (&fb.0).into_nalgebra().unwrap();
+/// assert_eq!(m.shape(), (2, 3));
+/// assert_eq!(m[(1, 2)], RawU16::from(Rgb565::RED).into_inner());
+/// assert_eq!(m[(0, 0)], 0);
+/// # }
+/// ```
+impl<'a, C, const WIDTH: usize, const HEIGHT: usize, const N: usize> IntoNalgebra
+    for &'a Framebuffer<C, RawU16, NativeEndian, WIDTH, HEIGHT, N>
+where
+    C: PixelColor<Raw = RawU16>,
+{
+    type Out = Option<MatrixView<'a, u16, Const<HEIGHT>, Const<WIDTH>, Const<WIDTH>, U1>>;
+
+    fn into_nalgebra(self) -> Self::Out {
+        let bytes = &self.data()[..2 * WIDTH * HEIGHT];
+        let words = bytes.as_ptr().cast::<u16>();
+        if !words.is_aligned() {
+            return None;
+        }
+        // SAFETY: The words are aligned and cover exactly the borrowed bytes, and every bit pattern is
+        // a valid `u16`.
+        let words = unsafe { core::slice::from_raw_parts(words, WIDTH * HEIGHT) };
+        Some(MatrixView::from_slice_with_strides_generic(
+            words,
+            Const::<HEIGHT>,
+            Const::<WIDTH>,
+            Const::<WIDTH>,
+            U1,
+        ))
+    }
+}
+
+/// ```
+/// use embedded_graphics::framebuffer::{buffer_size, Framebuffer};
+/// use embedded_graphics::image::GetPixel;
+/// use embedded_graphics::pixelcolor::raw::{LittleEndian, RawU16};
+/// use embedded_graphics::pixelcolor::Rgb565;
+/// use embedded_graphics::prelude::*;
+/// use nshare::IntoNalgebra;
+///
+/// #[repr(align(2))]
+/// struct Aligned<T>(T);
+///
+/// # #[cfg(target_endian = "little")] {
+/// let mut fb = Aligned(Framebuffer::<Rgb565, RawU16, LittleEndian, 3, 2, { buffer_size::<Rgb565>(3, 2) }>::new());
+/// // Fill the bottom line of the display.
+/// (&mut fb.0).into_nalgebra().unwrap().row_mut(1).fill(RawU16::from(Rgb565::BLUE).into_inner());
+/// assert_eq!(fb.0.pixel(Point::new(0, 1)), Some(Rgb565::BLUE));
+/// assert_eq!(fb.0.pixel(Point::new(0, 0)), Some(Rgb565::BLACK));
+/// # }
+/// ```
+impl<'a, C, const WIDTH: usize, const HEIGHT: usize, const N: usize> IntoNalgebra
+    for &'a mut Framebuffer<C, RawU16, NativeEndian, WIDTH, HEIGHT, N>
+where
+    C: PixelColor<Raw = RawU16>,
+{
+    type Out = Option<MatrixViewMut<'a, u16, Const<HEIGHT>, Const<WIDTH>, Const<WIDTH>, U1>>;
+
+    fn into_nalgebra(self) -> Self::Out {
+        let bytes = &mut self.data_mut()[..2 * WIDTH * HEIGHT];
+        let words = bytes.as_mut_ptr().cast::<u16>();
+        if !words.is_aligned() {
+            return None;
+        }
+        // SAFETY: See the shared view.
+        let words = unsafe { core::slice::from_raw_parts_mut(words, WIDTH * HEIGHT) };
+        Some(MatrixViewMut::from_slice_with_strides_generic(
+            words,
+            Const::<HEIGHT>,
+            Const::<WIDTH>,
+            Const::<WIDTH>,
+            U1,
+        ))
+    }
+}
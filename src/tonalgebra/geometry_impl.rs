@@ -0,0 +1,342 @@
+//! Implementations for ndarray types being converted to nalgebra geometric transformations.
+
+use super::*;
+use nalgebra::{
+    allocator::Allocator, AbstractRotation, Const, DefaultAllocator, DimMin, DimNameAdd,
+    DimNameSum, Isometry, OMatrix, Quaternion, RealField, Rotation, SMatrix, SVector, Scalar,
+    Similarity, TCategory, Transform, Translation, UnitQuaternion, U1,
+};
+use ndarray::{ArrayView1, ArrayView2};
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+mod sealed {
+    use nalgebra::{RealField, Rotation, Rotation2, Rotation3, UnitComplex, UnitQuaternion};
+
+    /// A rotation type that isometries and similarities can be made of.
+    pub trait FromRotation<T, const D: usize> {
+        fn from_rotation(rotation: Rotation<T, D>) -> Self;
+    }
+
+    impl<T: RealField, const D: usize> FromRotation<T, D> for Rotation<T, D> {
+        fn from_rotation(rotation: Rotation<T, D>) -> Self {
+            rotation
+        }
+    }
+
+    impl<T: RealField> FromRotation<T, 3> for UnitQuaternion<T> {
+        fn from_rotation(rotation: Rotation3<T>) -> Self {
+            Self::from_rotation_matrix(&rotation)
+        }
+    }
+
+    impl<T: RealField> FromRotation<T, 2> for UnitComplex<T> {
+        fn from_rotation(rotation: Rotation2<T>) -> Self {
+            Self::from_rotation_matrix(&rotation)
+        }
+    }
+}
+
+/// ```
+/// use nalgebra::Quaternion;
+/// use nshare::IntoNalgebraGeometry;
+///
+/// let arr = ndarray::array![2.0, 3.0, 4.0, 1.0];
+/// let q: Quaternion<f64> = arr.view().into_nalgebra_geometry().unwrap();
+/// assert_eq!(q, Quaternion::new(1.0, 2.0, 3.0, 4.0));
+/// ```
+impl<T> IntoNalgebraGeometry<Quaternion<T>> for ArrayView1<'_, T>
+where
+    T: Scalar,
+{
+    fn into_nalgebra_geometry(self) -> Result<Quaternion<T>, GeometryError> {
+        assert_eq!(
+            self.len(),
+            4,
+            "the array must hold the 4 coefficients of a quaternion"
+        );
+        let [i, j, k, w] = core::array::from_fn(|c| self[c].clone());
+        Ok(Quaternion::new(w, i, j, k))
+    }
+}
+
+/// The quaternion is normalized, so coefficients that were rounded on the way still give a rotation.
+///
+/// ```
+/// use nalgebra::UnitQuaternion;
+/// use nshare::{GeometryError, IntoNalgebraGeometry};
+///
+/// let arr = ndarray::array![0.0, 0.0, 0.0, 2.0];
+/// let q: UnitQuaternion<f64> = arr.view().into_nalgebra_geometry().unwrap();
+/// assert_eq!(q, UnitQuaternion::identity());
+///
+/// let zero = ndarray::Array1::<f64>::zeros(4);
+/// let q: Result<UnitQuaternion<f64>, _> = zero.view().into_nalgebra_geometry();
+/// assert_eq!(q, Err(GeometryError::ZeroQuaternion));
+/// ```
+impl<T> IntoNalgebraGeometry<UnitQuaternion<T>> for ArrayView1<'_, T>
+where
+    T: RealField,
+{
+    fn into_nalgebra_geometry(self) -> Result<UnitQuaternion<T>, GeometryError> {
+        let quaternion: Quaternion<T> = self.into_nalgebra_geometry()?;
+        UnitQuaternion::try_new(quaternion, T::zero()).ok_or(GeometryError::ZeroQuaternion)
+    }
+}
+
+/// The columns are orthonormalized, so entries that were rounded on the way still give an exact rotation.
+///
+/// ```
+/// use nalgebra::Rotation2;
+/// use nshare::{GeometryError, IntoNalgebraGeometry};
+///
+/// let arr = ndarray::array![[0.0, -1.0], [1.0, 0.0]];
+/// let r: Rotation2<f64> = arr.view().into_nalgebra_geometry().unwrap();
+/// assert!((r.angle() - std::f64::consts::FRAC_PI_2).abs() < 1e-12);
+///
+/// // A reflection is orthonormal, but not a rotation.
+/// let arr = ndarray::array![[1.0, 0.0], [0.0, -1.0]];
+/// let r: Result<Rotation2<f64>, _> = arr.view().into_nalgebra_geometry();
+/// assert_eq!(r, Err(GeometryError::NotRotation));
+/// ```
+impl<T, const D: usize> IntoNalgebraGeometry<Rotation<T, D>> for ArrayView2<'_, T>
+where
+    T: RealField,
+    Const<D>: DimMin<Const<D>, Output = Const<D>>,
+{
+    fn into_nalgebra_geometry(self) -> Result<Rotation<T, D>, GeometryError> {
+        assert_eq!(
+            self.dim(),
+            (D, D),
+            "the array must have the shape of the rotation matrix"
+        );
+        rotation(SMatrix::from_fn(|row, col| self[(row, col)].clone()))
+    }
+}
+
+/// ```
+/// use nalgebra::Translation2;
+/// use nshare::{GeometryError, IntoNalgebraGeometry};
+///
+/// let arr = ndarray::array![[1.0, 0.0, 5.0], [0.0, 1.0, 6.0], [0.0, 0.0, 1.0]];
+/// let t: Translation2<f64> = arr.view().into_nalgebra_geometry().unwrap();
+/// assert_eq!(t, Translation2::new(5.0, 6.0));
+///
+/// let arr = ndarray::array![[2.0, 0.0, 5.0], [0.0, 1.0, 6.0], [0.0, 0.0, 1.0]];
+/// let t: Result<Translation2<f64>, _> = arr.view().into_nalgebra_geometry();
+/// assert_eq!(t, Err(GeometryError::NotTranslation));
+/// ```
+impl<T, const D: usize> IntoNalgebraGeometry<Translation<T, D>> for ArrayView2<'_, T>
+where
+    T: RealField,
+{
+    fn into_nalgebra_geometry(self) -> Result<Translation<T, D>, GeometryError> {
+        let (linear, translation) = affine_parts::<T, D>(self)?;
+        if !linear.is_identity(tolerance()) {
+            return Err(GeometryError::NotTranslation);
+        }
+        Ok(Translation::from(translation))
+    }
+}
+
+/// The rotation can be a rotation matrix, or a unit quaternion or unit complex number in 3 or 2 dimensions.
+///
+/// ```
+/// use nalgebra::{Isometry3, Vector3};
+/// use nshare::{IntoNalgebraGeometry, IntoNdarray2};
+///
+/// let iso = Isometry3::new(Vector3::new(1.0, 2.0, 3.0), Vector3::new(0.1, 0.2, 0.3));
+/// let arr = iso.into_ndarray2();
+/// let back: Isometry3<f64> = arr.view().into_nalgebra_geometry().unwrap();
+/// assert!((back.to_homogeneous() - iso.to_homogeneous()).abs().max() < 1e-12);
+/// ```
+impl<T, R, const D: usize> IntoNalgebraGeometry<Isometry<T, R, D>> for ArrayView2<'_, T>
+where
+    T: RealField,
+    R: AbstractRotation<T, D> + sealed::FromRotation<T, D>,
+    Const<D>: DimMin<Const<D>, Output = Const<D>>,
+{
+    fn into_nalgebra_geometry(self) -> Result<Isometry<T, R, D>, GeometryError> {
+        let (linear, translation) = affine_parts(self)?;
+        let rotation = R::from_rotation(rotation(linear)?);
+        Ok(Isometry::from_parts(
+            Translation::from(translation),
+            rotation,
+        ))
+    }
+}
+
+/// The scaling is the length of the columns of the linear part, which must all be the same.
+///
+/// ```
+/// use nalgebra::{Similarity2, Vector2};
+/// use nshare::{GeometryError, IntoNalgebraGeometry};
+///
+/// let arr = ndarray::array![[0.0, -2.0, 1.0], [2.0, 0.0, 1.0], [0.0, 0.0, 1.0]];
+/// let sim: Similarity2<f64> = arr.view().into_nalgebra_geometry().unwrap();
+/// assert!((sim.scaling() - 2.0).abs() < 1e-12);
+///
+/// // Scaling the axes differently is not a similarity.
+/// let arr = ndarray::array![[1.0, 0.0, 0.0], [0.0, 2.0, 0.0], [0.0, 0.0, 1.0]];
+/// let sim: Result<Similarity2<f64>, _> = arr.view().into_nalgebra_geometry();
+/// assert_eq!(sim, Err(GeometryError::NotRotation));
+/// ```
+impl<T, R, const D: usize> IntoNalgebraGeometry<Similarity<T, R, D>> for ArrayView2<'_, T>
+where
+    T: RealField,
+    R: AbstractRotation<T, D> + sealed::FromRotation<T, D>,
+    Const<D>: DimMin<Const<D>, Output = Const<D>>,
+{
+    fn into_nalgebra_geometry(self) -> Result<Similarity<T, R, D>, GeometryError> {
+        let (linear, translation) = affine_parts::<T, D>(self)?;
+        let scaling = linear.column(0).norm();
+        if scaling <= tolerance() {
+            return Err(GeometryError::NotRotation);
+        }
+        let rotation = R::from_rotation(rotation(linear / scaling.clone())?);
+        Ok(Similarity::from_parts(
+            Translation::from(translation),
+            rotation,
+            scaling,
+        ))
+    }
+}
+
+/// The matrix is checked against the category of the transform by nalgebra, so a `TGeneral` transform
+/// accepts any matrix.
+///
+/// ```
+/// use nalgebra::{Affine2, Projective2};
+/// use nshare::{GeometryError, IntoNalgebraGeometry};
+///
+/// let arr = ndarray::array![[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.5, 0.0, 1.0]];
+/// let t: Result<Projective2<f64>, _> = arr.view().into_nalgebra_geometry();
+/// assert!(t.is_ok());
+/// let t: Result<Affine2<f64>, _> = arr.view().into_nalgebra_geometry();
+/// assert_eq!(t, Err(GeometryError::Category));
+/// ```
+impl<T, C, const D: usize> IntoNalgebraGeometry<Transform<T, C, D>> for ArrayView2<'_, T>
+where
+    T: RealField,
+    C: TCategory,
+    Const<D>: DimNameAdd<U1>,
+    DefaultAllocator: Allocator<DimNameSum<Const<D>, U1>, DimNameSum<Const<D>, U1>>,
+{
+    fn into_nalgebra_geometry(self) -> Result<Transform<T, C, D>, GeometryError> {
+        assert_eq!(
+            self.dim(),
+            (D + 1, D + 1),
+            "the array must have the shape of the homogeneous matrix"
+        );
+        let matrix = OMatrix::<T, DimNameSum<Const<D>, U1>, DimNameSum<Const<D>, U1>>::from_fn(
+            |row, col| self[(row, col)].clone(),
+        );
+        match C::check_homogeneous_invariants(&matrix) {
+            true => Ok(Transform::from_matrix_unchecked(matrix)),
+            false => Err(GeometryError::Category),
+        }
+    }
+}
+
+/// Every row is converted, and the first error is returned.
+///
+/// ```
+/// use nalgebra::UnitQuaternion;
+/// use nshare::IntoNalgebraGeometry;
+///
+/// let arr = ndarray::array![[0.0, 0.0, 0.0, 1.0], [0.0, 0.0, 2.0, 0.0]];
+/// let qs: Vec<UnitQuaternion<f64>> = arr.view().into_nalgebra_geometry().unwrap();
+/// assert_eq!(qs[1].angle(), std::f64::consts::PI);
+/// ```
+#[cfg(feature = "alloc")]
+impl<T, G> IntoNalgebraGeometry<Vec<G>> for ArrayView2<'_, T>
+where
+    for<'b> ArrayView1<'b, T>: IntoNalgebraGeometry<G>,
+{
+    fn into_nalgebra_geometry(self) -> Result<Vec<G>, GeometryError> {
+        self.outer_iter()
+            .map(IntoNalgebraGeometry::into_nalgebra_geometry)
+            .collect()
+    }
+}
+
+/// Every matrix along the first axis is converted, and the first error is returned.
+///
+/// ```
+/// use nalgebra::Isometry2;
+/// use nshare::{IntoNalgebraGeometry, IntoNdarray3};
+///
+/// let poses = [Isometry2::translation(1.0, 0.0), Isometry2::rotation(0.5)];
+/// let arr = poses.as_slice().into_ndarray3();
+/// let back: Vec<Isometry2<f64>> = arr.view().into_nalgebra_geometry().unwrap();
+/// assert_eq!(back.len(), 2);
+/// assert!((back[1].rotation.angle() - 0.5).abs() < 1e-12);
+/// ```
+#[cfg(feature = "alloc")]
+impl<T, G> IntoNalgebraGeometry<Vec<G>> for ndarray::ArrayView3<'_, T>
+where
+    for<'b> ArrayView2<'b, T>: IntoNalgebraGeometry<G>,
+{
+    fn into_nalgebra_geometry(self) -> Result<Vec<G>, GeometryError> {
+        self.outer_iter()
+            .map(IntoNalgebraGeometry::into_nalgebra_geometry)
+            .collect()
+    }
+}
+
+/// How far from orthonormal a rotation, or from the identity and `0 .. 0 1` an affine matrix, may be.
+fn tolerance<T: RealField>() -> T {
+    T::default_epsilon().sqrt()
+}
+
+/// Checks that a matrix is a rotation within the tolerance, and orthonormalizes its columns so the result is exact.
+fn rotation<T, const D: usize>(
+    mut linear: SMatrix<T, D, D>,
+) -> Result<Rotation<T, D>, GeometryError>
+where
+    T: RealField,
+    Const<D>: DimMin<Const<D>, Output = Const<D>>,
+{
+    if !linear.is_orthogonal(tolerance()) || linear.determinant() <= T::zero() {
+        return Err(GeometryError::NotRotation);
+    }
+    // Gram-Schmidt keeps the orientation, and the columns are already close to orthonormal.
+    for col in 0..D {
+        for prev in 0..col {
+            let prev = linear.column(prev).clone_owned();
+            let projection = prev.dot(&linear.column(col));
+            linear.column_mut(col).axpy(-projection, &prev, T::one());
+        }
+        linear.column_mut(col).normalize_mut();
+    }
+    Ok(Rotation::from_matrix_unchecked(linear))
+}
+
+/// Splits a `(D + 1, D + 1)` homogeneous matrix into its linear part and translation, checking that it is affine.
+fn affine_parts<T, const D: usize>(
+    view: ArrayView2<'_, T>,
+) -> Result<(SMatrix<T, D, D>, SVector<T, D>), GeometryError>
+where
+    T: RealField,
+{
+    assert_eq!(
+        view.dim(),
+        (D + 1, D + 1),
+        "the array must have the shape of the homogeneous matrix"
+    );
+    let tolerance = tolerance::<T>();
+    let is_affine = view.row(D).iter().enumerate().all(|(col, value)| {
+        let expected = if col == D { T::one() } else { T::zero() };
+        (value.clone() - expected).abs() <= tolerance
+    });
+    if !is_affine {
+        return Err(GeometryError::NotAffine);
+    }
+    let linear = SMatrix::from_fn(|row, col| view[(row, col)].clone());
+    let translation = SVector::from_fn(|row, _| view[(row, D)].clone());
+    Ok((linear, translation))
+}
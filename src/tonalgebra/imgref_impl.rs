@@ -0,0 +1,78 @@
+//! Implementations for imgref types being converted to nalgebra types.
+
+use super::*;
+use imgref::{ImgRef, ImgRefMut};
+use nalgebra::{Dyn, Scalar};
+
+/// ```
+/// use imgref::ImgVec;
+/// use nshare::IntoNalgebra;
+///
+/// let img = ImgVec::new(vec![0u8, 1, 2, 3, 4, 5, 6, 7], 4, 2);
+/// let m = img.sub_image(1, 0, 2, 2).into_nalgebra();
+/// // nalgebra uses (row, col), so the dims get flipped.
+/// assert_eq!(m.shape(), (2, 2));
+/// assert_eq!(m, nalgebra::Matrix2::new(1, 2, 5, 6));
+/// ```
+impl<'a, T> IntoNalgebra for ImgRef<'a, T>
+where
+    T: Scalar,
+{
+    type Out = nalgebra::DMatrixView<'a, T, Dyn, Dyn>;
+
+    fn into_nalgebra(self) -> Self::Out {
+        let (nrows, ncols, stride) = (self.height(), self.width(), self.stride());
+        assert!(
+            self.buf().len() >= buffer_len(nrows, ncols, stride),
+            "imgref buffer is too small for its dimensions"
+        );
+        let storage = unsafe {
+            nalgebra::ViewStorage::from_raw_parts(
+                self.buf().as_ptr(),
+                (Dyn(nrows), Dyn(ncols)),
+                (Dyn(stride), Dyn(1)),
+            )
+        };
+        nalgebra::Matrix::from_data(storage)
+    }
+}
+
+/// ```
+/// use imgref::ImgVec;
+/// use nshare::IntoNalgebra;
+///
+/// let mut img = ImgVec::new(vec![0u8; 8], 4, 2);
+/// img.sub_image_mut(1, 0, 2, 2).into_nalgebra().column_mut(1).fill(255);
+/// assert_eq!(img.buf(), &[0, 0, 255, 0, 0, 0, 255, 0]);
+/// ```
+impl<'a, T> IntoNalgebra for ImgRefMut<'a, T>
+where
+    T: Scalar,
+{
+    type Out = nalgebra::DMatrixViewMut<'a, T, Dyn, Dyn>;
+
+    fn into_nalgebra(self) -> Self::Out {
+        let (nrows, ncols, stride) = (self.height(), self.width(), self.stride());
+        let buf = self.into_buf();
+        assert!(
+            buf.len() >= buffer_len(nrows, ncols, stride),
+            "imgref buffer is too small for its dimensions"
+        );
+        let storage = unsafe {
+            nalgebra::ViewStorageMut::from_raw_parts(
+                buf.as_mut_ptr(),
+                (Dyn(nrows), Dyn(ncols)),
+                (Dyn(stride), Dyn(1)),
+            )
+        };
+        nalgebra::Matrix::from_data(storage)
+    }
+}
+
+/// The number of pixels spanned by the image, where the last row is not padded.
+fn buffer_len(height: usize, width: usize, stride: usize) -> usize {
+    match height {
+        0 => 0,
+        _ => (height - 1) * stride + width,
+    }
+}
@@ -0,0 +1,59 @@
+//! Implementations for nalgebra-sparse types being converted to nalgebra types.
+
+use super::*;
+use nalgebra::{DMatrix, Scalar};
+use nalgebra_sparse::{CscMatrix, CsrMatrix};
+use num_traits::Zero;
+
+/// ```
+/// use nshare::IntoNalgebra;
+/// use nalgebra_sparse::CsrMatrix;
+///
+/// let s = CsrMatrix::try_from_csr_data(2, 3, vec![0, 1, 3], vec![2, 0, 1], vec![1.0, 2.0, 3.0]).unwrap();
+/// let m = s.into_nalgebra();
+/// assert_eq!(m, nalgebra::Matrix2x3::new(
+///     0.0, 0.0, 1.0,
+///     2.0, 3.0, 0.0,
+/// ));
+/// ```
+impl<T> IntoNalgebra for &CsrMatrix<T>
+where
+    T: Scalar + Zero,
+{
+    type Out = DMatrix<T>;
+
+    fn into_nalgebra(self) -> Self::Out {
+        let mut m = DMatrix::zeros(self.nrows(), self.ncols());
+        for (row, col, value) in self.triplet_iter() {
+            m[(row, col)] = value.clone();
+        }
+        m
+    }
+}
+
+/// ```
+/// use nshare::IntoNalgebra;
+/// use nalgebra_sparse::CscMatrix;
+///
+/// let s = CscMatrix::try_from_csc_data(3, 2, vec![0, 1, 3], vec![2, 0, 1], vec![1.0, 2.0, 3.0]).unwrap();
+/// let m = s.into_nalgebra();
+/// assert_eq!(m, nalgebra::Matrix3x2::new(
+///     0.0, 2.0,
+///     0.0, 3.0,
+///     1.0, 0.0,
+/// ));
+/// ```
+impl<T> IntoNalgebra for &CscMatrix<T>
+where
+    T: Scalar + Zero,
+{
+    type Out = DMatrix<T>;
+
+    fn into_nalgebra(self) -> Self::Out {
+        let mut m = DMatrix::zeros(self.nrows(), self.ncols());
+        for (row, col, value) in self.triplet_iter() {
+            m[(row, col)] = value.clone();
+        }
+        m
+    }
+}
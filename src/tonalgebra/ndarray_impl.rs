@@ -3,6 +3,10 @@
 use super::*;
 
 use core::convert::TryFrom;
+
+extern crate alloc;
+
+use alloc::vec::Vec;
 use nalgebra::Dyn;
 
 /// ```
@@ -185,3 +189,178 @@ where
         Self::Out::from_iterator_generic(nrows, ncols, self.t().iter().cloned())
     }
 }
+
+/// Each column of the matrix is copied from the array on its own thread.
+///
+/// ```
+/// use nshare::IntoNalgebraPar;
+///
+/// let arr = ndarray::Array2::from_shape_fn((3, 4), |(row, col)| row * 10 + col);
+/// let m = arr.into_nalgebra_par();
+/// assert_eq!(m.shape(), (3, 4));
+/// assert_eq!(m[(2, 1)], 21);
+/// ```
+#[cfg(feature = "rayon")]
+impl<T> IntoNalgebraPar for ndarray::Array2<T>
+where
+    T: nalgebra::Scalar + Send + Sync,
+{
+    type Out = nalgebra::DMatrix<T>;
+    fn into_nalgebra_par(self) -> Self::Out {
+        use rayon::prelude::*;
+        let nrows = Dyn(self.nrows());
+        let ncols = Dyn(self.ncols());
+        let data: Vec<T> = self
+            .axis_iter(ndarray::Axis(1))
+            .into_par_iter()
+            .flat_map_iter(|column| column.into_iter().cloned())
+            .collect();
+        Self::Out::from_vec_generic(nrows, ncols, data)
+    }
+}
+
+/// ```
+/// use nalgebra::Point3;
+/// use nshare::IntoNalgebraPoints;
+///
+/// let arr = ndarray::array![[1.0f32, 2.0, 3.0], [4.0, 5.0, 6.0]];
+/// let points: &[Point3<f32>] = arr.view().into_nalgebra_points();
+/// assert_eq!(points, &[Point3::new(1.0, 2.0, 3.0), Point3::new(4.0, 5.0, 6.0)]);
+/// ```
+impl<'a, T, const D: usize> IntoNalgebraPoints<D> for ndarray::ArrayView2<'a, T>
+where
+    T: nalgebra::Scalar,
+{
+    type Out = &'a [nalgebra::Point<T, D>];
+
+    fn into_nalgebra_points(self) -> Self::Out {
+        assert_eq!(
+            self.ncols(),
+            D,
+            "the array must have one column per coordinate"
+        );
+        let coords = self
+            .to_slice()
+            .expect("the array must be in standard layout");
+        crate::points::coords_points(coords.as_chunks().0)
+    }
+}
+
+/// ```
+/// use nalgebra::Point2;
+/// use nshare::IntoNalgebraPoints;
+///
+/// let mut arr = ndarray::Array2::<f64>::zeros((3, 2));
+/// let points: &mut [Point2<f64>] = arr.view_mut().into_nalgebra_points();
+/// points[1] = Point2::new(1.0, 2.0);
+/// assert_eq!(arr.row(1), ndarray::array![1.0, 2.0]);
+/// ```
+impl<'a, T, const D: usize> IntoNalgebraPoints<D> for ndarray::ArrayViewMut2<'a, T>
+where
+    T: nalgebra::Scalar,
+{
+    type Out = &'a mut [nalgebra::Point<T, D>];
+
+    fn into_nalgebra_points(self) -> Self::Out {
+        assert_eq!(
+            self.ncols(),
+            D,
+            "the array must have one column per coordinate"
+        );
+        let coords = self
+            .into_slice()
+            .expect("the array must be in standard layout");
+        crate::points::coords_points_mut(coords.as_chunks_mut().0)
+    }
+}
+
+/// ```
+/// use nalgebra::Matrix2x3;
+/// use nshare::{IntoNalgebraMatrices, IntoNdarray3};
+///
+/// let matrices = vec![Matrix2x3::new(1, 2, 3, 4, 5, 6); 4];
+/// let arr = matrices.as_slice().into_ndarray3();
+/// let back: &[Matrix2x3<i32>] = arr.into_nalgebra_matrices();
+/// assert_eq!(back, matrices.as_slice());
+/// ```
+impl<'a, T, const R: usize, const C: usize> IntoNalgebraMatrices<R, C>
+    for ndarray::ArrayView3<'a, T>
+where
+    T: nalgebra::Scalar,
+{
+    type Out = &'a [nalgebra::SMatrix<T, R, C>];
+
+    fn into_nalgebra_matrices(self) -> Self::Out {
+        let (len, rows, cols) = self.dim();
+        assert_eq!((rows, cols), (R, C), "the array must hold {R}x{C} matrices");
+        // Column-major matrices without gaps are in standard layout once rows and columns are swapped.
+        let elements = self
+            .permuted_axes([0, 2, 1])
+            .to_slice()
+            .expect("the matrices must be column-major and contiguous");
+        crate::points::elements_matrices(elements, len)
+    }
+}
+
+/// ```
+/// use nalgebra::Matrix2;
+/// use nshare::{IntoNalgebraMatrices, IntoNdarray3};
+///
+/// let mut matrices = vec![Matrix2::<f64>::zeros(); 3];
+/// let mut arr = matrices.as_mut_slice().into_ndarray3();
+/// let back: &mut [Matrix2<f64>] = arr.view_mut().into_nalgebra_matrices();
+/// back[1].fill_with_identity();
+/// assert_eq!(matrices[1], Matrix2::identity());
+/// ```
+impl<'a, T, const R: usize, const C: usize> IntoNalgebraMatrices<R, C>
+    for ndarray::ArrayViewMut3<'a, T>
+where
+    T: nalgebra::Scalar,
+{
+    type Out = &'a mut [nalgebra::SMatrix<T, R, C>];
+
+    fn into_nalgebra_matrices(self) -> Self::Out {
+        let (len, rows, cols) = self.dim();
+        assert_eq!((rows, cols), (R, C), "the array must hold {R}x{C} matrices");
+        let elements = self
+            .permuted_axes([0, 2, 1])
+            .into_slice()
+            .expect("the matrices must be column-major and contiguous");
+        crate::points::elements_matrices_mut(elements, len)
+    }
+}
+
+/// The matrices are copied from any layout, and a mismatched shape is an error instead of a panic.
+///
+/// ```
+/// use nalgebra::{Matrix2, Matrix3};
+/// use nshare::{IntoNalgebraMatrices, MatrixShapeError};
+///
+/// // A standard layout array is row-major within each matrix.
+/// let arr = ndarray::Array3::from_shape_fn((5, 2, 2), |(i, r, c)| i * 4 + r * 2 + c);
+/// let matrices: Vec<Matrix2<usize>> = arr.clone().into_nalgebra_matrices().unwrap();
+/// assert_eq!(matrices[1], Matrix2::new(4, 5, 6, 7));
+///
+/// let wrong: Result<Vec<Matrix3<usize>>, _> = arr.into_nalgebra_matrices();
+/// assert_eq!(wrong, Err(MatrixShapeError { expected: (3, 3), found: (2, 2) }));
+/// ```
+impl<T, const R: usize, const C: usize> IntoNalgebraMatrices<R, C> for ndarray::Array3<T>
+where
+    T: nalgebra::Scalar,
+{
+    type Out = Result<Vec<nalgebra::SMatrix<T, R, C>>, MatrixShapeError>;
+
+    fn into_nalgebra_matrices(self) -> Self::Out {
+        let (_, rows, cols) = self.dim();
+        if (rows, cols) != (R, C) {
+            return Err(MatrixShapeError {
+                expected: (R, C),
+                found: (rows, cols),
+            });
+        }
+        Ok(self
+            .outer_iter()
+            .map(|matrix| nalgebra::SMatrix::from_fn(|row, col| matrix[(row, col)].clone()))
+            .collect())
+    }
+}
@@ -0,0 +1,86 @@
+//! Implementations for slices of nalgebra points and vectors being converted to nalgebra matrix views.
+//!
+//! nalgebra stores point clouds as one point per column, and that is also how a slice of points is laid out,
+//! so the views are contiguous.
+
+use super::*;
+use crate::points::{point_coords, point_coords_mut, vector_coords, vector_coords_mut};
+use nalgebra::{Const, Dyn, MatrixView, MatrixViewMut, Point, SVector, Scalar};
+
+/// ```
+/// use nalgebra::{Matrix3xX, Point3};
+/// use nshare::IntoNalgebra;
+///
+/// let points = vec![Point3::new(1.0, 2.0, 3.0), Point3::new(4.0, 5.0, 6.0)];
+/// let m = points.as_slice().into_nalgebra();
+/// assert_eq!(m, Matrix3xX::from_columns(&[points[0].coords, points[1].coords]));
+/// ```
+impl<'a, T, const D: usize> IntoNalgebra for &'a [Point<T, D>]
+where
+    T: Scalar,
+{
+    type Out = MatrixView<'a, T, Const<D>, Dyn>;
+
+    fn into_nalgebra(self) -> Self::Out {
+        point_coords(self).into_nalgebra_transposed()
+    }
+}
+
+/// ```
+/// use nalgebra::Point3;
+/// use nshare::IntoNalgebra;
+///
+/// let mut points = vec![Point3::new(1.0, 2.0, 3.0), Point3::new(4.0, 5.0, 6.0)];
+/// // Move every point down by one.
+/// points.as_mut_slice().into_nalgebra().row_mut(2).add_scalar_mut(-1.0);
+/// assert_eq!(points[1], Point3::new(4.0, 5.0, 5.0));
+/// ```
+impl<'a, T, const D: usize> IntoNalgebra for &'a mut [Point<T, D>]
+where
+    T: Scalar,
+{
+    type Out = MatrixViewMut<'a, T, Const<D>, Dyn>;
+
+    fn into_nalgebra(self) -> Self::Out {
+        point_coords_mut(self).into_nalgebra_transposed()
+    }
+}
+
+/// ```
+/// use nalgebra::Vector2;
+/// use nshare::IntoNalgebra;
+///
+/// let vectors = [Vector2::new(1, 2), Vector2::new(3, 4), Vector2::new(5, 6)];
+/// let m = vectors.as_slice().into_nalgebra();
+/// assert_eq!(m.shape(), (2, 3));
+/// assert_eq!(m.column(2), vectors[2]);
+/// ```
+impl<'a, T, const D: usize> IntoNalgebra for &'a [SVector<T, D>]
+where
+    T: Scalar,
+{
+    type Out = MatrixView<'a, T, Const<D>, Dyn>;
+
+    fn into_nalgebra(self) -> Self::Out {
+        vector_coords(self).into_nalgebra_transposed()
+    }
+}
+
+/// ```
+/// use nalgebra::Vector2;
+/// use nshare::IntoNalgebra;
+///
+/// let mut vectors = [Vector2::new(1, 2), Vector2::new(3, 4)];
+/// vectors.as_mut_slice().into_nalgebra().column_mut(0).fill(0);
+/// assert_eq!(vectors[0], Vector2::zeros());
+/// ```
+impl<'a, T, const D: usize> IntoNalgebra for &'a mut [SVector<T, D>]
+where
+    T: Scalar,
+{
+    type Out = MatrixViewMut<'a, T, Const<D>, Dyn>;
+
+    fn into_nalgebra(self) -> Self::Out {
+        vector_coords_mut(self).into_nalgebra_transposed()
+    }
+}
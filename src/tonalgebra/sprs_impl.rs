@@ -0,0 +1,33 @@
+//! Implementations for sprs types being converted to nalgebra types.
+
+use super::*;
+use nalgebra::{DMatrix, Scalar};
+use num_traits::Zero;
+use sprs::CsMat;
+
+/// ```
+/// use nshare::IntoNalgebra;
+/// use sprs::CsMat;
+///
+/// let s = CsMat::new((2, 3), vec![0, 1, 3], vec![2, 0, 1], vec![1.0, 2.0, 3.0]);
+/// let m = s.into_nalgebra();
+/// assert_eq!(m, nalgebra::Matrix2x3::new(
+///     0.0, 0.0, 1.0,
+///     2.0, 3.0, 0.0,
+/// ));
+/// ```
+impl<T> IntoNalgebra for &CsMat<T>
+where
+    T: Scalar + Zero,
+{
+    type Out = DMatrix<T>;
+
+    fn into_nalgebra(self) -> Self::Out {
+        let (rows, cols) = self.shape();
+        let mut m = DMatrix::zeros(rows, cols);
+        for (value, index) in self.iter() {
+            m[index] = value.clone();
+        }
+        m
+    }
+}
@@ -0,0 +1,118 @@
+//! Implementations for strided slices being converted to nalgebra types.
+//!
+//! nalgebra only supports positive strides, so layouts with negative strides panic.
+
+use super::*;
+use crate::{StridedSlice, StridedSliceMut};
+use nalgebra::{Dyn, MatrixView, MatrixViewMut, U1};
+
+/// ```
+/// use nshare::{IntoNalgebra, StridedSlice};
+///
+/// let data = [0, 1, 2, 3, 4, 5];
+/// let v = StridedSlice::new(&data, [3], [2], 1).unwrap().into_nalgebra();
+/// assert!(v.iter().eq(&[1, 3, 5]));
+/// ```
+impl<'a, T> IntoNalgebra for StridedSlice<'a, T, 1>
+where
+    T: nalgebra::Scalar,
+{
+    type Out = MatrixView<'a, T, Dyn, U1, Dyn, Dyn>;
+
+    fn into_nalgebra(self) -> Self::Out {
+        let [len] = self.shape;
+        let [stride] = positive_strides(self.strides);
+        // The column stride is never used, but must be one that nalgebra accepts for the slice.
+        MatrixView::from_slice_with_strides_generic(
+            &self.data[self.offset..],
+            Dyn(len),
+            U1,
+            Dyn(stride),
+            Dyn(stride * len),
+        )
+    }
+}
+
+/// ```
+/// use nshare::{IntoNalgebra, StridedSliceMut};
+///
+/// let mut data = [0; 6];
+/// StridedSliceMut::new(&mut data, [3], [2], 0).unwrap().into_nalgebra().fill(1);
+/// assert_eq!(data, [1, 0, 1, 0, 1, 0]);
+/// ```
+impl<'a, T> IntoNalgebra for StridedSliceMut<'a, T, 1>
+where
+    T: nalgebra::Scalar,
+{
+    type Out = MatrixViewMut<'a, T, Dyn, U1, Dyn, Dyn>;
+
+    fn into_nalgebra(self) -> Self::Out {
+        let [len] = self.shape;
+        let [stride] = positive_strides(self.strides);
+        MatrixViewMut::from_slice_with_strides_generic(
+            &mut self.data[self.offset..],
+            Dyn(len),
+            U1,
+            Dyn(stride),
+            Dyn(stride * len),
+        )
+    }
+}
+
+/// ```
+/// use nshare::{IntoNalgebra, StridedSlice};
+///
+/// // A 2x2 region of a 3x4 row-major image, starting at row 1 and column 1.
+/// let data: Vec<u8> = (0..12).collect();
+/// let m = StridedSlice::new(&data, [2, 2], [4, 1], 5).unwrap().into_nalgebra();
+/// assert_eq!(m, nalgebra::Matrix2::new(5, 6, 9, 10));
+/// ```
+impl<'a, T> IntoNalgebra for StridedSlice<'a, T, 2>
+where
+    T: nalgebra::Scalar,
+{
+    type Out = MatrixView<'a, T, Dyn, Dyn, Dyn, Dyn>;
+
+    fn into_nalgebra(self) -> Self::Out {
+        let [rows, cols] = self.shape;
+        let [row_stride, col_stride] = positive_strides(self.strides);
+        MatrixView::from_slice_with_strides_generic(
+            &self.data[self.offset..],
+            Dyn(rows),
+            Dyn(cols),
+            Dyn(row_stride),
+            Dyn(col_stride),
+        )
+    }
+}
+
+/// ```
+/// use nshare::{IntoNalgebra, StridedSliceMut};
+///
+/// let mut data = [0u8; 6];
+/// let s = StridedSliceMut::contiguous(&mut data, [2, 3]).unwrap();
+/// s.into_nalgebra().column_mut(1).fill(1);
+/// assert_eq!(data, [0, 1, 0, 0, 1, 0]);
+/// ```
+impl<'a, T> IntoNalgebra for StridedSliceMut<'a, T, 2>
+where
+    T: nalgebra::Scalar,
+{
+    type Out = MatrixViewMut<'a, T, Dyn, Dyn, Dyn, Dyn>;
+
+    fn into_nalgebra(self) -> Self::Out {
+        let [rows, cols] = self.shape;
+        let [row_stride, col_stride] = positive_strides(self.strides);
+        MatrixViewMut::from_slice_with_strides_generic(
+            &mut self.data[self.offset..],
+            Dyn(rows),
+            Dyn(cols),
+            Dyn(row_stride),
+            Dyn(col_stride),
+        )
+    }
+}
+
+fn positive_strides<const N: usize>(strides: [isize; N]) -> [usize; N] {
+    strides.map(|stride| usize::try_from(stride).expect("Negative stride"))
+}
@@ -0,0 +1,47 @@
+#[cfg(feature = "nalgebra")]
+mod nalgebra_impl;
+#[cfg(feature = "ndarray")]
+mod ndarray_impl;
+#[cfg(feature = "sprs")]
+mod sprs_impl;
+
+/// Converts a sparse matrix type to a nalgebra-sparse CSR matrix type.
+///
+/// The index and value buffers are moved into the CSR matrix without being copied
+/// when the source is already stored by rows.
+///
+/// This uses an associated type to avoid ambiguity for the compiler.
+/// By calling this, the compiler always knows the returned type.
+pub trait IntoNalgebraCsr {
+    type Out;
+
+    fn into_nalgebra_csr(self) -> Self::Out;
+}
+
+/// Converts a sparse matrix type to a nalgebra-sparse CSC matrix type.
+///
+/// The index and value buffers are moved into the CSC matrix without being copied
+/// when the source is already stored by columns.
+///
+/// This uses an associated type to avoid ambiguity for the compiler.
+/// By calling this, the compiler always knows the returned type.
+pub trait IntoNalgebraCsc {
+    type Out;
+
+    fn into_nalgebra_csc(self) -> Self::Out;
+}
+
+/// Converts a dense 2d type to a nalgebra-sparse matrix type, dropping the values within `tolerance` of zero.
+///
+/// Row-major sources become CSR matrices and column-major sources become CSC matrices.
+/// A value `v` is dropped when `-tolerance <= v <= tolerance`, so a tolerance of zero only drops
+/// exact zeros. NaN is never dropped.
+///
+/// This uses an associated type to avoid ambiguity for the compiler.
+/// By calling this, the compiler always knows the returned type.
+pub trait IntoNalgebraSparseWithTolerance {
+    type Elem;
+    type Out;
+
+    fn into_nalgebra_sparse_with_tolerance(self, tolerance: Self::Elem) -> Self::Out;
+}
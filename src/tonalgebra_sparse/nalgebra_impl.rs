@@ -0,0 +1,39 @@
+//! Implementations for nalgebra types being converted to nalgebra-sparse types.
+
+use super::*;
+use crate::sparse::compress_lanes;
+use core::ops::Neg;
+use nalgebra::{Dim, Matrix, Scalar, Storage};
+use nalgebra_sparse::CscMatrix;
+
+/// ```
+/// use nshare::IntoNalgebraSparseWithTolerance;
+/// use nalgebra::Matrix2x3;
+///
+/// let m = Matrix2x3::new(
+///     1.0, 0.0, 1e-9,
+///     0.0, -2.0, 3.0,
+/// );
+/// // nalgebra matrices are column-major, so they become CSC matrices.
+/// let s = m.into_nalgebra_sparse_with_tolerance(1e-6);
+/// assert_eq!((s.nrows(), s.ncols()), (2, 3));
+/// assert_eq!(s.col_offsets(), &[0, 1, 2, 3]);
+/// assert_eq!(s.row_indices(), &[0, 1, 1]);
+/// assert_eq!(s.values(), &[1.0, -2.0, 3.0]);
+/// ```
+impl<T, R, C, S> IntoNalgebraSparseWithTolerance for Matrix<T, R, C, S>
+where
+    T: Scalar + PartialOrd + Neg<Output = T>,
+    R: Dim,
+    C: Dim,
+    S: Storage<T, R, C>,
+{
+    type Elem = T;
+    type Out = CscMatrix<T>;
+
+    fn into_nalgebra_sparse_with_tolerance(self, tolerance: T) -> Self::Out {
+        let (rows, cols) = self.shape();
+        let (col_offsets, row_indices, values) = compress_lanes(self.column_iter(), &tolerance);
+        CscMatrix::try_from_csc_data(rows, cols, col_offsets, row_indices, values).unwrap()
+    }
+}
@@ -0,0 +1,36 @@
+//! Implementations for ndarray types being converted to nalgebra-sparse types.
+
+use super::*;
+use crate::sparse::compress_lanes;
+use core::ops::Neg;
+use nalgebra_sparse::CsrMatrix;
+use ndarray::{ArrayBase, Data, Ix2};
+
+/// ```
+/// use nshare::IntoNalgebraSparseWithTolerance;
+///
+/// let arr = ndarray::array![
+///     [1.0, 0.0, 1e-9],
+///     [0.0, -2.0, 3.0],
+/// ];
+/// // Arrays are row-major, so they become CSR matrices.
+/// let m = arr.view().into_nalgebra_sparse_with_tolerance(1e-6);
+/// assert_eq!((m.nrows(), m.ncols()), (2, 3));
+/// assert_eq!(m.row_offsets(), &[0, 1, 3]);
+/// assert_eq!(m.col_indices(), &[0, 1, 2]);
+/// assert_eq!(m.values(), &[1.0, -2.0, 3.0]);
+/// ```
+impl<T, S> IntoNalgebraSparseWithTolerance for ArrayBase<S, Ix2>
+where
+    T: Clone + PartialOrd + Neg<Output = T>,
+    S: Data<Elem = T>,
+{
+    type Elem = T;
+    type Out = CsrMatrix<T>;
+
+    fn into_nalgebra_sparse_with_tolerance(self, tolerance: T) -> Self::Out {
+        let (rows, cols) = self.dim();
+        let (row_offsets, col_indices, values) = compress_lanes(self.rows(), &tolerance);
+        CsrMatrix::try_from_csr_data(rows, cols, row_offsets, col_indices, values).unwrap()
+    }
+}
@@ -0,0 +1,80 @@
+//! Implementations for sprs types being converted to nalgebra-sparse types.
+
+use super::*;
+use nalgebra_sparse::{CscMatrix, CsrMatrix};
+use sprs::CsMat;
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+/// ```
+/// use nshare::IntoNalgebraCsr;
+/// use sprs::CsMat;
+///
+/// let s = CsMat::new((2, 3), vec![0, 1, 3], vec![2, 0, 1], vec![1.0, 2.0, 3.0]);
+/// let m = s.into_nalgebra_csr();
+/// assert_eq!((m.nrows(), m.ncols()), (2, 3));
+/// assert_eq!(m.row_offsets(), &[0, 1, 3]);
+/// assert_eq!(m.col_indices(), &[2, 0, 1]);
+/// assert_eq!(m.values(), &[1.0, 2.0, 3.0]);
+///
+/// // CSC matrices are converted to CSR first.
+/// let s = CsMat::new_csc((3, 2), vec![0, 1, 3], vec![2, 0, 1], vec![1.0, 2.0, 3.0]);
+/// let m = s.into_nalgebra_csr();
+/// assert_eq!(m.row_offsets(), &[0, 1, 2, 3]);
+/// assert_eq!(m.col_indices(), &[1, 1, 0]);
+/// assert_eq!(m.values(), &[2.0, 3.0, 1.0]);
+/// ```
+impl<T> IntoNalgebraCsr for CsMat<T>
+where
+    T: Clone + Default,
+{
+    type Out = CsrMatrix<T>;
+
+    fn into_nalgebra_csr(self) -> Self::Out {
+        let (rows, cols) = self.shape();
+        let (row_offsets, col_indices, values) = into_raw_parts(self.into_csr());
+        CsrMatrix::try_from_csr_data(rows, cols, row_offsets, col_indices, values).unwrap()
+    }
+}
+
+/// ```
+/// use nshare::IntoNalgebraCsc;
+/// use sprs::CsMat;
+///
+/// let s = CsMat::new_csc((3, 2), vec![0, 1, 3], vec![2, 0, 1], vec![1.0, 2.0, 3.0]);
+/// let m = s.into_nalgebra_csc();
+/// assert_eq!((m.nrows(), m.ncols()), (3, 2));
+/// assert_eq!(m.col_offsets(), &[0, 1, 3]);
+/// assert_eq!(m.row_indices(), &[2, 0, 1]);
+/// assert_eq!(m.values(), &[1.0, 2.0, 3.0]);
+/// ```
+impl<T> IntoNalgebraCsc for CsMat<T>
+where
+    T: Clone + Default,
+{
+    type Out = CscMatrix<T>;
+
+    fn into_nalgebra_csc(self) -> Self::Out {
+        let (rows, cols) = self.shape();
+        let (col_offsets, row_indices, values) = into_raw_parts(self.into_csc());
+        CscMatrix::try_from_csc_data(rows, cols, col_offsets, row_indices, values).unwrap()
+    }
+}
+
+/// Takes the buffers out of a sprs matrix, trimming them if its offsets do not start at zero.
+fn into_raw_parts<T>(matrix: CsMat<T>) -> (Vec<usize>, Vec<usize>, Vec<T>) {
+    let (mut offsets, mut indices, mut values) = matrix.into_raw_storage();
+    let (start, end) = (offsets[0], offsets[offsets.len() - 1]);
+    if start != 0 || end != indices.len() {
+        indices.truncate(end);
+        indices.drain(..start);
+        values.truncate(end);
+        values.drain(..start);
+        for offset in &mut offsets {
+            *offset -= start;
+        }
+    }
+    (offsets, indices, values)
+}
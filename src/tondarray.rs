@@ -1,7 +1,31 @@
+mod array_impl;
+mod bayer_impl;
+mod bytes_impl;
+#[cfg(feature = "num-complex")]
+mod complex_impl;
+#[cfg(feature = "nalgebra")]
+mod depth_impl;
+#[cfg(feature = "nalgebra")]
+mod geometry_impl;
 #[cfg(feature = "image")]
 mod image_impl;
+#[cfg(feature = "imgref")]
+mod imgref_impl;
 #[cfg(feature = "nalgebra")]
 mod nalgebra_impl;
+#[cfg(feature = "nalgebra-sparse")]
+mod nalgebra_sparse_impl;
+mod packed_impl;
+#[cfg(feature = "palette")]
+mod palette_impl;
+#[cfg(feature = "rgb")]
+mod rgb_impl;
+#[cfg(feature = "sprs")]
+mod sprs_impl;
+mod strided_impl;
+#[cfg(feature = "tiny-skia")]
+mod tiny_skia_impl;
+mod yuv_impl;
 
 /// Converts a 1d type to a ndarray 1d array type.
 ///
@@ -38,6 +62,80 @@ pub trait IntoNdarray3 {
     fn into_ndarray3(self) -> Self::Out;
 }
 
+/// Converts a 3d type to a ndarray 3d array type in standard layout.
+///
+/// Coordinates are in `(channel, row, col)`, where channel is typically a color channel.
+/// Unlike [`IntoNdarray3`], which keeps interleaved data as it is, every channel of the output
+/// is a contiguous plane, so the result can be passed on as one contiguous buffer.
+///
+/// This uses an associated type to avoid ambiguity for the compiler.
+/// By calling this, the compiler always knows the returned type.
+pub trait IntoNdarray3Planar {
+    type Out;
+
+    fn into_ndarray3_planar(self) -> Self::Out;
+}
+
+/// Converts a 3d type to a ndarray 3d array type like [`IntoNdarray3Planar`], splitting the rows across the
+/// rayon thread pool.
+///
+/// The result is identical to [`IntoNdarray3Planar`].
+///
+/// This uses an associated type to avoid ambiguity for the compiler.
+/// By calling this, the compiler always knows the returned type.
+#[cfg(feature = "rayon")]
+pub trait IntoNdarray3PlanarPar {
+    type Out;
+
+    fn into_ndarray3_planar_par(self) -> Self::Out;
+}
+
+/// Converts a type with any number of dimensions to a ndarray array type with a dynamic number of axes.
+///
+/// This uses an associated type to avoid ambiguity for the compiler.
+/// By calling this, the compiler always knows the returned type.
+pub trait IntoNdarrayDyn {
+    type Out;
+
+    fn into_ndarray_dyn(self) -> Self::Out;
+}
+
+/// Borrows a slice of pixels to a ndarray 3d array type, given the dimensions of the image.
+///
+/// The order of the axes is chosen with `order`, so coordinates are either in `(channel, row, col)`
+/// or in `(row, col, channel)`. The pixels are expected in row-major order without padding.
+///
+/// This uses an associated type to avoid ambiguity for the compiler.
+/// By calling this, the compiler always knows the returned type.
+pub trait AsNdarray3Pixels {
+    type Out<'a>
+    where
+        Self: 'a;
+
+    fn as_ndarray3_pixels(&self, width: usize, height: usize, order: ChannelOrder)
+        -> Self::Out<'_>;
+}
+
+/// Mutably borrows a slice of pixels to a ndarray 3d array type, given the dimensions of the image.
+///
+/// The order of the axes is chosen with `order`, so coordinates are either in `(channel, row, col)`
+/// or in `(row, col, channel)`. The pixels are expected in row-major order without padding.
+///
+/// This uses an associated type to avoid ambiguity for the compiler.
+/// By calling this, the compiler always knows the returned type.
+pub trait AsNdarray3PixelsMut {
+    type Out<'a>
+    where
+        Self: 'a;
+
+    fn as_ndarray3_pixels_mut(
+        &mut self,
+        width: usize,
+        height: usize,
+        order: ChannelOrder,
+    ) -> Self::Out<'_>;
+}
+
 /// Borrows a 1d type to a ndarray 1d array type.
 ///
 /// This uses an associated type to avoid ambiguity for the compiler.
@@ -119,3 +217,306 @@ pub trait AsNdarray3Mut {
 
     fn as_ndarray3_mut(&mut self) -> Self::Out<'_>;
 }
+
+/// Borrows an image with an alpha channel as a ndarray 3d view of its colors and a 2d view of its alpha.
+///
+/// The colors are in `(channel, row, col)` without the alpha channel, and the alpha is in `(row, col)`.
+///
+/// This uses an associated type to avoid ambiguity for the compiler.
+/// By calling this, the compiler always knows the returned type.
+pub trait AsNdarray3Alpha {
+    type Out<'a>
+    where
+        Self: 'a;
+
+    fn as_ndarray3_alpha(&self) -> Self::Out<'_>;
+}
+
+/// Mutably borrows an image with an alpha channel as a ndarray 3d view of its colors and a 2d view of its alpha.
+///
+/// The layouts are the same as with [`AsNdarray3Alpha`].
+///
+/// This uses an associated type to avoid ambiguity for the compiler.
+/// By calling this, the compiler always knows the returned type.
+pub trait AsNdarray3AlphaMut {
+    type Out<'a>
+    where
+        Self: 'a;
+
+    fn as_ndarray3_alpha_mut(&mut self) -> Self::Out<'_>;
+}
+
+/// Borrows a 2d type of complex numbers as a ndarray 3d array type of their real and imaginary parts.
+///
+/// Coordinates are in `(part, row, col)`, where part `0` is the real part and part `1` is the imaginary part.
+///
+/// This uses an associated type to avoid ambiguity for the compiler.
+/// By calling this, the compiler always knows the returned type.
+pub trait AsNdarray3Complex {
+    type Out<'a>
+    where
+        Self: 'a;
+
+    fn as_ndarray3_complex(&self) -> Self::Out<'_>;
+}
+
+/// Mutably borrows a 2d type of complex numbers as a ndarray 3d array type of their real and imaginary parts.
+///
+/// Coordinates are in `(part, row, col)`, where part `0` is the real part and part `1` is the imaginary part.
+///
+/// This uses an associated type to avoid ambiguity for the compiler.
+/// By calling this, the compiler always knows the returned type.
+pub trait AsNdarray3ComplexMut {
+    type Out<'a>
+    where
+        Self: 'a;
+
+    fn as_ndarray3_complex_mut(&mut self) -> Self::Out<'_>;
+}
+
+/// Converts a 3d type of real and imaginary parts to a ndarray 2d array type of complex numbers.
+///
+/// Coordinates are in `(part, row, col)`, where part `0` is the real part and part `1` is the imaginary part.
+/// The two parts of each number must be adjacent in memory.
+///
+/// This uses an associated type to avoid ambiguity for the compiler.
+/// By calling this, the compiler always knows the returned type.
+pub trait IntoNdarray2Complex {
+    type Out;
+
+    fn into_ndarray2_complex(self) -> Self::Out;
+}
+
+/// Converts a type holding colors into a ndarray array type of their components.
+///
+/// The components of each color are put on an extra axis at the end, so a slice of colors has coordinates in
+/// `(index, channel)` and a 2d array of colors has coordinates in `(row, col, channel)`.
+///
+/// This uses an associated type to avoid ambiguity for the compiler.
+/// By calling this, the compiler always knows the returned type.
+pub trait IntoNdarrayComponents {
+    type Out;
+
+    fn into_ndarray_components(self) -> Self::Out;
+}
+
+/// Converts the frames of an animation into a ndarray 4d array type, together with the delay of each frame.
+///
+/// Coordinates are in `(time, channel, row, col)` or `(time, row, col, channel)` depending on `order`,
+/// with RGBA channels, and the output is always in standard layout. Frames smaller than the canvas are
+/// drawn over the previous frame at their offset, blending with their alpha.
+///
+/// This uses an associated type to avoid ambiguity for the compiler.
+/// By calling this, the compiler always knows the returned type.
+pub trait IntoNdarray4Frames {
+    type Out;
+
+    fn into_ndarray4_frames(self, order: ChannelOrder) -> Self::Out;
+}
+
+/// Back-projects a depth map into a ndarray 2d array type of points.
+///
+/// Coordinates are in `(point, axis)` with the `x`, `y` and `z` axes of the camera, and pixels with
+/// an invalid depth are skipped, so the remaining points are in row-major order of their pixels.
+///
+/// This uses an associated type to avoid ambiguity for the compiler.
+/// By calling this, the compiler always knows the returned type.
+#[cfg(feature = "nalgebra")]
+pub trait IntoNdarray2Points {
+    type Out;
+
+    fn into_ndarray2_points(self, camera: &crate::DepthCamera) -> Self::Out;
+}
+
+/// Back-projects a depth map into a ndarray 2d array type of points with the colors of an aligned image.
+///
+/// Coordinates are in `(point, axis)`, with the `x`, `y` and `z` axes of the camera followed by the red,
+/// green and blue channels scaled to `[0, 1]`. Pixels are skipped the same way as with [`IntoNdarray2Points`].
+///
+/// This uses an associated type to avoid ambiguity for the compiler.
+/// By calling this, the compiler always knows the returned type.
+#[cfg(all(feature = "nalgebra", feature = "image"))]
+pub trait IntoNdarray2PointsColored {
+    type Out;
+
+    fn into_ndarray2_points_colored(
+        self,
+        camera: &crate::DepthCamera,
+        colors: &image::RgbImage,
+    ) -> Self::Out;
+}
+
+/// Projects points in `(point, axis)` into a ndarray 2d depth map of the given `(rows, cols)`.
+///
+/// Depths are in the raw units of the camera, so the result back-projects into the same points up to
+/// the pixel grid. Pixels without a point are zero, and the nearest point wins where several land on one pixel.
+///
+/// This uses an associated type to avoid ambiguity for the compiler.
+/// By calling this, the compiler always knows the returned type.
+#[cfg(feature = "nalgebra")]
+pub trait IntoNdarray2Depth {
+    type Out;
+
+    fn into_ndarray2_depth(self, camera: &crate::DepthCamera, shape: (usize, usize)) -> Self::Out;
+}
+
+/// Converts a type into a [`Labeled`](crate::Labeled) ndarray array that records the meaning of each axis.
+///
+/// Images are in `(Channel, Row, Col)`, matrices in `(Row, Col)`, batches of matrices in `(Batch, Row, Col)`
+/// and stacks of image slices in `(Z, Row, Col)`, the same as the unlabeled conversions.
+///
+/// This uses an associated type to avoid ambiguity for the compiler.
+/// By calling this, the compiler always knows the returned type.
+pub trait IntoNdarrayLabeled {
+    type Out;
+
+    fn into_ndarray_labeled(self) -> Self::Out;
+}
+
+/// Borrows a type as a [`Labeled`](crate::Labeled) ndarray view that records the meaning of each axis.
+///
+/// The layouts are the same as with [`IntoNdarrayLabeled`].
+///
+/// This uses an associated type to avoid ambiguity for the compiler.
+/// By calling this, the compiler always knows the returned type.
+pub trait AsNdarrayLabeled {
+    type Out<'a>
+    where
+        Self: 'a;
+
+    fn as_ndarray_labeled(&self) -> Self::Out<'_>;
+}
+
+/// Mutably borrows a type as a [`Labeled`](crate::Labeled) ndarray view that records the meaning of each axis.
+///
+/// The layouts are the same as with [`IntoNdarrayLabeled`].
+///
+/// This uses an associated type to avoid ambiguity for the compiler.
+/// By calling this, the compiler always knows the returned type.
+pub trait AsNdarrayLabeledMut {
+    type Out<'a>
+    where
+        Self: 'a;
+
+    fn as_ndarray_labeled_mut(&mut self) -> Self::Out<'_>;
+}
+
+/// Converts an image into a ndarray 2d array type of colors of type `C`.
+///
+/// Coordinates are in (row, col).
+///
+/// The color type is a type parameter of the trait since the same image may be converted to any color space.
+pub trait IntoNdarray2Color<C> {
+    type Out;
+
+    fn into_ndarray2_color(self) -> Self::Out;
+}
+
+/// The order of the axes in a 3d array holding an image.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Default)]
+pub enum ChannelOrder {
+    /// Coordinates are in `(channel, row, col)`, as with [`IntoNdarray3`].
+    #[default]
+    Chw,
+    /// Coordinates are in `(row, col, channel)`, the same order as interleaved pixel data.
+    Hwc,
+}
+
+/// Describes how subpixels are mapped to `f32` by [`IntoNdarray3Normalized`].
+///
+/// A subpixel `x` in channel `c` becomes `(x / max_value - mean[c]) / std[c]`.
+/// An empty `mean` or `std` leaves the channels unshifted or unscaled, and a single
+/// value is applied to every channel. Otherwise there must be one value per channel.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Normalization<'a> {
+    /// The value that subpixels are divided by first.
+    ///
+    /// If this is `None`, the `DEFAULT_MAX_VALUE` of the subpixel type is used,
+    /// which maps the full range of the image into `[0, 1]`.
+    pub max_value: Option<f32>,
+    /// The per-channel mean subtracted after the range is scaled.
+    pub mean: &'a [f32],
+    /// The per-channel standard deviation divided by after the mean is subtracted.
+    pub std: &'a [f32],
+    /// The order of the axes in the output array.
+    pub order: ChannelOrder,
+}
+
+impl Normalization<'static> {
+    /// Scales the subpixels into `[0, 1]` without any mean or standard deviation.
+    pub const UNIT: Self = Self {
+        max_value: None,
+        mean: &[],
+        std: &[],
+        order: ChannelOrder::Chw,
+    };
+
+    /// Scales the subpixels into `[0, 1]` and applies the ImageNet RGB mean and standard deviation.
+    pub const IMAGENET: Self = Self {
+        max_value: None,
+        mean: &[0.485, 0.456, 0.406],
+        std: &[0.229, 0.224, 0.225],
+        order: ChannelOrder::Chw,
+    };
+}
+
+impl Default for Normalization<'static> {
+    fn default() -> Self {
+        Self::UNIT
+    }
+}
+
+/// Converts a 3d type to a normalized `f32` ndarray 3d array type in a single pass.
+///
+/// The layout of the output is chosen with [`Normalization::order`] and is always in standard layout.
+///
+/// The `mean` and `std` of the normalization must each be empty, a single value, or one value per channel
+/// of the image, and the conversion panics otherwise. [`Normalization::IMAGENET`] has three values, so it
+/// only applies to RGB images and panics on a `GrayImage`.
+///
+/// This uses an associated type to avoid ambiguity for the compiler.
+/// By calling this, the compiler always knows the returned type.
+pub trait IntoNdarray3Normalized {
+    type Out;
+
+    fn into_ndarray3_normalized(self, normalization: &Normalization<'_>) -> Self::Out;
+}
+
+/// Converts a 3d type to a normalized `f32` ndarray 3d array type like [`IntoNdarray3Normalized`], splitting
+/// the rows across the rayon thread pool.
+///
+/// The result is identical to [`IntoNdarray3Normalized`].
+///
+/// This uses an associated type to avoid ambiguity for the compiler.
+/// By calling this, the compiler always knows the returned type.
+#[cfg(feature = "rayon")]
+pub trait IntoNdarray3NormalizedPar {
+    type Out;
+
+    fn into_ndarray3_normalized_par(self, normalization: &Normalization<'_>) -> Self::Out;
+}
+
+/// Converts a 3d type with premultiplied alpha into a `f32` ndarray 3d array type with straight alpha.
+///
+/// Coordinates are in `(channel, row, col)`, and the values are in the range `0.0..=1.0`.
+/// The color channels of fully transparent pixels are `0.0`.
+///
+/// This uses an associated type to avoid ambiguity for the compiler.
+/// By calling this, the compiler always knows the returned type.
+pub trait IntoNdarray3Demultiplied {
+    type Out;
+
+    fn into_ndarray3_demultiplied(self) -> Self::Out;
+}
+
+/// Demosaics a raw image behind a color filter array into a ndarray 3d array type.
+///
+/// Coordinates are in `(channel, row, col)`, with the channels in RGB order.
+///
+/// This uses an associated type to avoid ambiguity for the compiler.
+/// By calling this, the compiler always knows the returned type.
+pub trait IntoNdarray3Demosaiced {
+    type Out;
+
+    fn into_ndarray3_demosaiced(self, algorithm: crate::Demosaic) -> Self::Out;
+}
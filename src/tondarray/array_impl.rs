@@ -0,0 +1,94 @@
+//! Implementations for fixed-size arrays being converted to ndarray types.
+
+use super::*;
+use ndarray::{ArrayView1, ArrayView2, ArrayViewMut1, ArrayViewMut2};
+
+/// ```
+/// use nshare::IntoNdarray1;
+///
+/// let arr = [1, 2, 3];
+/// assert_eq!((&arr).into_ndarray1(), ndarray::array![1, 2, 3]);
+/// ```
+impl<'a, T, const N: usize> IntoNdarray1 for &'a [T; N] {
+    type Out = ArrayView1<'a, T>;
+
+    fn into_ndarray1(self) -> Self::Out {
+        ArrayView1::from(self)
+    }
+}
+
+/// ```
+/// use nshare::IntoNdarray1;
+///
+/// let mut arr = [1, 2, 3];
+/// (&mut arr).into_ndarray1()[0] = 0;
+/// assert_eq!(arr, [0, 2, 3]);
+/// ```
+impl<'a, T, const N: usize> IntoNdarray1 for &'a mut [T; N] {
+    type Out = ArrayViewMut1<'a, T>;
+
+    fn into_ndarray1(self) -> Self::Out {
+        ArrayViewMut1::from(self)
+    }
+}
+
+/// ```
+/// use nshare::IntoNdarray2;
+///
+/// let arr = [[1, 2, 3], [4, 5, 6]];
+/// let view = (&arr).into_ndarray2();
+/// // Both are row-major, so no transposition is needed.
+/// assert_eq!(view, ndarray::array![[1, 2, 3], [4, 5, 6]]);
+/// assert!(view.is_standard_layout());
+/// ```
+impl<'a, T, const R: usize, const C: usize> IntoNdarray2 for &'a [[T; C]; R] {
+    type Out = ArrayView2<'a, T>;
+
+    fn into_ndarray2(self) -> Self::Out {
+        ArrayView2::from(self)
+    }
+}
+
+/// ```
+/// use nshare::IntoNdarray2;
+///
+/// let mut arr = [[0; 2]; 2];
+/// (&mut arr).into_ndarray2().row_mut(1).fill(1);
+/// assert_eq!(arr, [[0, 0], [1, 1]]);
+/// ```
+impl<'a, T, const R: usize, const C: usize> IntoNdarray2 for &'a mut [[T; C]; R] {
+    type Out = ArrayViewMut2<'a, T>;
+
+    fn into_ndarray2(self) -> Self::Out {
+        ArrayViewMut2::from(self)
+    }
+}
+
+/// ```
+/// use nshare::IntoNdarray2;
+///
+/// let rows: &[[u8; 2]] = &[[1, 2], [3, 4], [5, 6]];
+/// assert_eq!(rows.into_ndarray2().dim(), (3, 2));
+/// ```
+impl<'a, T, const C: usize> IntoNdarray2 for &'a [[T; C]] {
+    type Out = ArrayView2<'a, T>;
+
+    fn into_ndarray2(self) -> Self::Out {
+        ArrayView2::from(self)
+    }
+}
+
+/// ```
+/// use nshare::IntoNdarray2;
+///
+/// let rows: &mut [[u8; 2]] = &mut [[1, 2], [3, 4]];
+/// rows.into_ndarray2().column_mut(0).fill(0);
+/// assert_eq!(rows, &[[0, 2], [0, 4]]);
+/// ```
+impl<'a, T, const C: usize> IntoNdarray2 for &'a mut [[T; C]] {
+    type Out = ArrayViewMut2<'a, T>;
+
+    fn into_ndarray2(self) -> Self::Out {
+        ArrayViewMut2::from(self)
+    }
+}
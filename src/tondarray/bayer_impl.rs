@@ -0,0 +1,81 @@
+//! Implementations for Bayer images being converted to ndarray types.
+
+use super::*;
+use crate::{BayerImage, BayerSample, Demosaic};
+use ndarray::{Array3, ArrayView2, ShapeBuilder};
+
+/// Views of the sub-planes of an image.
+impl<'a, T> BayerImage<'a, T>
+where
+    T: BayerSample,
+{
+    /// Views the pixels at `row` and `col` of every 2x2 tile of the pattern, in (row, col).
+    ///
+    /// The color of the sub-plane is `self.pattern().tile()[row][col]`.
+    ///
+    /// ```
+    /// use nshare::{BayerColor, BayerImage, BayerPattern};
+    ///
+    /// let raw: Vec<u16> = (0..16).collect();
+    /// let bayer = BayerImage::new(&raw, 4, 4, BayerPattern::Rggb).unwrap();
+    /// assert_eq!(bayer.pattern().tile()[1][1], BayerColor::Blue);
+    /// assert_eq!(bayer.cfa_view(1, 1), ndarray::array![[5, 7], [13, 15]]);
+    /// ```
+    pub fn cfa_view(&self, row: usize, col: usize) -> ArrayView2<'a, T> {
+        assert!(row < 2 && col < 2, "the position must be in the 2x2 tile");
+        let rows = self.height().saturating_sub(row).div_ceil(2);
+        let cols = self.width().saturating_sub(col).div_ceil(2);
+        if rows == 0 || cols == 0 {
+            return ArrayView2::from_shape((rows, cols), &[]).unwrap();
+        }
+        let shape = (rows, cols).strides((self.stride() * 2, 2));
+        ArrayView2::from_shape(shape, &self.data()[row * self.stride() + col..]).unwrap()
+    }
+}
+
+/// ```
+/// use nshare::{BayerImage, BayerPattern, IntoNdarray2};
+///
+/// // Rows of 3 pixels padded to 4 samples.
+/// let raw = [1u8, 2, 3, 0, 4, 5, 6, 0];
+/// let bayer = BayerImage::with_stride(&raw, 3, 2, 4, BayerPattern::Gbrg).unwrap();
+/// assert_eq!(bayer.into_ndarray2(), ndarray::array![[1, 2, 3], [4, 5, 6]]);
+/// ```
+impl<'a, T> IntoNdarray2 for BayerImage<'a, T>
+where
+    T: BayerSample,
+{
+    type Out = ArrayView2<'a, T>;
+
+    fn into_ndarray2(self) -> Self::Out {
+        let (rows, cols) = (self.height(), self.width());
+        if rows == 0 || cols == 0 {
+            return ArrayView2::from_shape((rows, cols), &[]).unwrap();
+        }
+        ArrayView2::from_shape((rows, cols).strides((self.stride(), 1)), self.data()).unwrap()
+    }
+}
+
+/// ```
+/// use nshare::{BayerImage, BayerPattern, Demosaic, IntoNdarray3Demosaiced};
+///
+/// // Only the red pixels see any light.
+/// let raw = [[200u8, 0, 200, 0], [0; 4]].concat().repeat(2);
+/// let bayer = BayerImage::new(&raw, 4, 4, BayerPattern::Rggb).unwrap();
+/// let arr = bayer.into_ndarray3_demosaiced(Demosaic::Bilinear);
+/// assert_eq!(arr.dim(), (3, 4, 4));
+/// assert_eq!(arr[(0, 0, 0)], 200);
+/// assert!(arr.slice(ndarray::s![1..3, .., ..]).iter().all(|&x| x == 0));
+/// ```
+impl<T> IntoNdarray3Demosaiced for BayerImage<'_, T>
+where
+    T: BayerSample,
+{
+    type Out = Array3<T>;
+
+    fn into_ndarray3_demosaiced(self, algorithm: Demosaic) -> Self::Out {
+        let mut rgb = Array3::from_elem((self.height(), self.width(), 3), T::default());
+        self.demosaic_into(algorithm, rgb.as_slice_mut().unwrap());
+        rgb.permuted_axes([2, 0, 1])
+    }
+}
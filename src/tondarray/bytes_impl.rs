@@ -0,0 +1,106 @@
+//! Implementations for typed bytes being converted to ndarray types.
+//!
+//! The bytes are borrowed when possible and copied otherwise.
+
+use super::*;
+use crate::{ByteElement, TypedBytes};
+use ndarray::{Array, CowArray, Ix1, Ix2, Ix3, IxDyn};
+
+/// ```
+/// use nshare::{ByteOrder, IntoNdarray1, TypedBytes};
+///
+/// let bytes = [0x01, 0x00, 0x00, 0x02];
+/// let typed = TypedBytes::<i16, 1>::new(&bytes, [2], ByteOrder::Little).unwrap();
+/// assert_eq!(typed.into_ndarray1(), ndarray::array![1, 512]);
+/// ```
+impl<'a, T> IntoNdarray1 for TypedBytes<'a, T, 1>
+where
+    T: ByteElement,
+{
+    type Out = CowArray<'a, T, Ix1>;
+
+    fn into_ndarray1(self) -> Self::Out {
+        match self.as_strided() {
+            Ok(s) => s.into_ndarray1().into(),
+            Err(_) => Array::from_shape_vec(self.shape(), self.to_vec())
+                .unwrap()
+                .into(),
+        }
+    }
+}
+
+/// ```
+/// use nshare::{ByteOrder, IntoNdarray2, TypedBytes};
+///
+/// let values = [1u16, 2, 3, 4, 5, 6];
+/// let bytes: Vec<u8> = values.iter().flat_map(|x| x.to_be_bytes()).collect();
+/// let typed = TypedBytes::<u16, 2>::new(&bytes, [2, 3], ByteOrder::Big).unwrap();
+/// let arr = typed.into_ndarray2();
+/// assert_eq!(arr, ndarray::array![[1, 2, 3], [4, 5, 6]]);
+/// ```
+impl<'a, T> IntoNdarray2 for TypedBytes<'a, T, 2>
+where
+    T: ByteElement,
+{
+    type Out = CowArray<'a, T, Ix2>;
+
+    fn into_ndarray2(self) -> Self::Out {
+        match self.as_strided() {
+            Ok(s) => s.into_ndarray2().into(),
+            Err(_) => Array::from_shape_vec(self.shape(), self.to_vec())
+                .unwrap()
+                .into(),
+        }
+    }
+}
+
+/// ```
+/// use nshare::{ByteOrder, IntoNdarray3, TypedBytes};
+///
+/// // Bytes have no alignment or byte order, so they are always borrowed.
+/// let bytes: Vec<u8> = (0..12).collect();
+/// let typed = TypedBytes::<u8, 3>::new(&bytes, [3, 2, 2], ByteOrder::Big).unwrap();
+/// let arr = typed.into_ndarray3();
+/// assert!(arr.is_view());
+/// assert_eq!(arr[(2, 1, 0)], 10);
+/// ```
+impl<'a, T> IntoNdarray3 for TypedBytes<'a, T, 3>
+where
+    T: ByteElement,
+{
+    type Out = CowArray<'a, T, Ix3>;
+
+    fn into_ndarray3(self) -> Self::Out {
+        match self.as_strided() {
+            Ok(s) => s.into_ndarray3().into(),
+            Err(_) => Array::from_shape_vec(self.shape(), self.to_vec())
+                .unwrap()
+                .into(),
+        }
+    }
+}
+
+/// ```
+/// use nshare::{ByteOrder, IntoNdarrayDyn, TypedBytes};
+///
+/// let bytes: Vec<u8> = [0.5f64; 16].iter().flat_map(|x| x.to_le_bytes()).collect();
+/// let typed = TypedBytes::<f64, 4>::new(&bytes, [2, 2, 2, 2], ByteOrder::Little).unwrap();
+/// let arr = typed.into_ndarray_dyn();
+/// assert_eq!(arr.shape(), &[2, 2, 2, 2]);
+/// assert!(arr.iter().all(|&x| x == 0.5));
+/// ```
+impl<'a, T, const N: usize> IntoNdarrayDyn for TypedBytes<'a, T, N>
+where
+    T: ByteElement,
+{
+    type Out = CowArray<'a, T, IxDyn>;
+
+    fn into_ndarray_dyn(self) -> Self::Out {
+        match self.as_strided() {
+            Ok(s) => s.into_ndarray_dyn().into(),
+            Err(_) => Array::from_shape_vec(IxDyn(&self.shape()), self.to_vec())
+                .unwrap()
+                .into(),
+        }
+    }
+}
@@ -0,0 +1,257 @@
+//! Implementations for arrays of complex numbers being converted to ndarray types.
+//!
+//! [`Complex`] is `repr(C)`, so the real part of each number is immediately followed by its imaginary part.
+
+use super::*;
+use ndarray::{
+    ArrayBase, ArrayView2, ArrayView3, ArrayViewMut2, ArrayViewMut3, Axis, Data, DataMut,
+    Dimension, Ix2, RawData, ShapeBuilder,
+};
+use num_complex::Complex;
+
+/// ```
+/// use nshare::AsNdarray3Complex;
+/// use num_complex::Complex;
+/// use ndarray::s;
+///
+/// let arr = ndarray::array![
+///     [Complex::new(0.1, 1.1), Complex::new(0.2, 1.2)],
+///     [Complex::new(0.3, 1.3), Complex::new(0.4, 1.4)],
+/// ];
+/// let parts = arr.as_ndarray3_complex();
+/// assert_eq!(parts.dim(), (2, 2, 2));
+/// assert!(parts.slice(s![0, .., 1]).iter().eq(&[0.2, 0.4]));
+/// assert!(parts.slice(s![1, 1, ..]).iter().eq(&[1.3, 1.4]));
+/// // Negative strides are also supported.
+/// let flipped = arr.slice(s![..;-1, ..]);
+/// assert!(flipped.as_ndarray3_complex().slice(s![0, .., 0]).iter().eq(&[0.3, 0.1]));
+/// ```
+impl<T, S> AsNdarray3Complex for ArrayBase<S, Ix2>
+where
+    T: 'static,
+    S: Data<Elem = Complex<T>>,
+{
+    type Out<'a> = ArrayView3<'a, T>
+    where
+        Self: 'a;
+
+    fn as_ndarray3_complex(&self) -> Self::Out<'_> {
+        let mut view = self.view();
+        let inverted = invert_negative_axes(&mut view);
+        let mut parts =
+            unsafe { ArrayView3::from_shape_ptr(parts_shape(&view), view.as_ptr() as *const T) };
+        for axis in (0..2).filter(|&axis| inverted[axis]) {
+            parts.invert_axis(Axis(axis + 1));
+        }
+        parts
+    }
+}
+
+/// ```
+/// use nshare::AsNdarray3ComplexMut;
+/// use num_complex::Complex;
+/// use ndarray::s;
+///
+/// let mut arr = ndarray::Array2::from_elem((2, 3), Complex::new(1.0, 1.0));
+/// // Clear the imaginary part.
+/// arr.as_ndarray3_complex_mut().slice_mut(s![1, .., ..]).fill(0.0);
+/// assert!(arr.iter().all(|&c| c == Complex::new(1.0, 0.0)));
+/// ```
+impl<T, S> AsNdarray3ComplexMut for ArrayBase<S, Ix2>
+where
+    T: 'static,
+    S: DataMut<Elem = Complex<T>>,
+{
+    type Out<'a> = ArrayViewMut3<'a, T>
+    where
+        Self: 'a;
+
+    fn as_ndarray3_complex_mut(&mut self) -> Self::Out<'_> {
+        let mut view = self.view_mut();
+        let inverted = invert_negative_axes(&mut view);
+        let mut parts = unsafe {
+            ArrayViewMut3::from_shape_ptr(parts_shape(&view), view.as_mut_ptr() as *mut T)
+        };
+        for axis in (0..2).filter(|&axis| inverted[axis]) {
+            parts.invert_axis(Axis(axis + 1));
+        }
+        parts
+    }
+}
+
+/// ```
+/// use nshare::IntoNdarray2Complex;
+/// use num_complex::Complex;
+/// use ndarray::ArrayView3;
+///
+/// // Interleaved real and imaginary parts, as produced by many FFT libraries.
+/// let data = [0.1, 1.1, 0.2, 1.2, 0.3, 1.3, 0.4, 1.4];
+/// let parts = ArrayView3::from_shape((2, 2, 2), &data).unwrap().permuted_axes([2, 0, 1]);
+/// let arr = parts.into_ndarray2_complex();
+/// assert_eq!(arr.dim(), (2, 2));
+/// assert_eq!(arr[(1, 0)], Complex::new(0.3, 1.3));
+/// ```
+impl<'a, T> IntoNdarray2Complex for ArrayView3<'a, T> {
+    type Out = ArrayView2<'a, Complex<T>>;
+
+    fn into_ndarray2_complex(mut self) -> Self::Out {
+        let inverted = invert_negative_axes(&mut self);
+        let shape = complex_shape(&self, inverted);
+        let mut arr =
+            unsafe { ArrayView2::from_shape_ptr(shape, self.as_ptr() as *const Complex<T>) };
+        for axis in (0..2).filter(|&axis| inverted[axis + 1]) {
+            arr.invert_axis(Axis(axis));
+        }
+        arr
+    }
+}
+
+/// ```
+/// use nshare::IntoNdarray2Complex;
+/// use num_complex::Complex;
+/// use ndarray::ArrayViewMut3;
+///
+/// let mut data = [0.0; 8];
+/// let parts = ArrayViewMut3::from_shape((2, 2, 2), &mut data).unwrap().permuted_axes([2, 0, 1]);
+/// parts.into_ndarray2_complex()[(0, 1)] = Complex::new(2.0, 3.0);
+/// assert_eq!(data, [0.0, 0.0, 2.0, 3.0, 0.0, 0.0, 0.0, 0.0]);
+/// ```
+impl<'a, T> IntoNdarray2Complex for ArrayViewMut3<'a, T> {
+    type Out = ArrayViewMut2<'a, Complex<T>>;
+
+    fn into_ndarray2_complex(mut self) -> Self::Out {
+        let inverted = invert_negative_axes(&mut self);
+        let shape = complex_shape(&self, inverted);
+        let mut arr =
+            unsafe { ArrayViewMut2::from_shape_ptr(shape, self.as_mut_ptr() as *mut Complex<T>) };
+        for axis in (0..2).filter(|&axis| inverted[axis + 1]) {
+            arr.invert_axis(Axis(axis));
+        }
+        arr
+    }
+}
+
+/// Inverts every axis with a negative stride so that a view can be rebuilt from its first element.
+///
+/// Returns which of the (at most three) axes were inverted.
+fn invert_negative_axes<S, D>(view: &mut ArrayBase<S, D>) -> [bool; 3]
+where
+    S: RawData,
+    D: Dimension,
+{
+    let mut inverted = [false; 3];
+    for (axis, inverted) in inverted.iter_mut().enumerate().take(view.ndim()) {
+        if view.stride_of(Axis(axis)) < 0 {
+            view.invert_axis(Axis(axis));
+            *inverted = true;
+        }
+    }
+    inverted
+}
+
+/// The shape of the parts of a 2d array of complex numbers with positive strides.
+fn parts_shape<S, T>(view: &ArrayBase<S, Ix2>) -> ndarray::StrideShape<ndarray::Ix3>
+where
+    S: RawData<Elem = Complex<T>>,
+{
+    let (rows, cols) = view.dim();
+    let strides = view.strides();
+    (2, rows, cols).strides((1, 2 * strides[0] as usize, 2 * strides[1] as usize))
+}
+
+/// The shape of the complex numbers made from a 3d array of parts with positive strides.
+fn complex_shape<S, T>(
+    view: &ArrayBase<S, ndarray::Ix3>,
+    inverted: [bool; 3],
+) -> ndarray::StrideShape<Ix2>
+where
+    S: RawData<Elem = T>,
+{
+    let (parts, rows, cols) = view.dim();
+    let strides = view.strides();
+    assert_eq!(parts, 2, "the part axis must have a length of 2");
+    assert!(
+        strides[0] == 1 && !inverted[0],
+        "the imaginary part must immediately follow the real part in memory"
+    );
+    assert!(
+        strides[1] % 2 == 0 && strides[2] % 2 == 0,
+        "the row and col strides must be a multiple of 2 to stay aligned to complex numbers"
+    );
+    (rows, cols).strides((strides[1] as usize / 2, strides[2] as usize / 2))
+}
+
+/// ```
+/// use nshare::AsNdarray3Complex;
+/// use num_complex::Complex;
+/// use nalgebra::Matrix2;
+/// use ndarray::s;
+///
+/// let m = Matrix2::new(
+///     Complex::new(0.1, 1.1), Complex::new(0.2, 1.2),
+///     Complex::new(0.3, 1.3), Complex::new(0.4, 1.4),
+/// );
+/// let parts = m.as_ndarray3_complex();
+/// assert_eq!(parts.dim(), (2, 2, 2));
+/// assert!(parts.slice(s![0, 0, ..]).iter().eq(&[0.1, 0.2]));
+/// assert!(parts.slice(s![1, .., 1]).iter().eq(&[1.2, 1.4]));
+/// ```
+#[cfg(feature = "nalgebra")]
+impl<T, R, C, S> AsNdarray3Complex for nalgebra::Matrix<Complex<T>, R, C, S>
+where
+    T: nalgebra::Scalar,
+    R: nalgebra::Dim,
+    C: nalgebra::Dim,
+    S: nalgebra::Storage<Complex<T>, R, C>,
+{
+    type Out<'a> = ArrayView3<'a, T>
+    where
+        S: 'a;
+
+    fn as_ndarray3_complex(&self) -> Self::Out<'_> {
+        let (rows, cols) = self.shape();
+        let (row_stride, col_stride) = self.strides();
+        unsafe {
+            ArrayView3::from_shape_ptr(
+                (2, rows, cols).strides((1, 2 * row_stride, 2 * col_stride)),
+                self.as_ptr() as *const T,
+            )
+        }
+    }
+}
+
+/// ```
+/// use nshare::AsNdarray3ComplexMut;
+/// use num_complex::Complex;
+/// use nalgebra::DMatrix;
+/// use ndarray::s;
+///
+/// let mut m = DMatrix::from_element(2, 3, Complex::new(1.0, 1.0));
+/// // Clear the real part of the first row.
+/// m.as_ndarray3_complex_mut().slice_mut(s![0, 0, ..]).fill(0.0);
+/// assert!(m.row(0).iter().all(|&c| c == Complex::new(0.0, 1.0)));
+/// assert!(m.row(1).iter().all(|&c| c == Complex::new(1.0, 1.0)));
+/// ```
+#[cfg(feature = "nalgebra")]
+impl<T, R, C, S> AsNdarray3ComplexMut for nalgebra::Matrix<Complex<T>, R, C, S>
+where
+    T: nalgebra::Scalar,
+    R: nalgebra::Dim,
+    C: nalgebra::Dim,
+    S: nalgebra::StorageMut<Complex<T>, R, C>,
+{
+    type Out<'a> = ArrayViewMut3<'a, T>
+    where
+        S: 'a;
+
+    fn as_ndarray3_complex_mut(&mut self) -> Self::Out<'_> {
+        let (rows, cols) = self.shape();
+        let (row_stride, col_stride) = self.strides();
+        unsafe {
+            ArrayViewMut3::from_shape_ptr(
+                (2, rows, cols).strides((1, 2 * row_stride, 2 * col_stride)),
+                self.as_mut_ptr() as *mut T,
+            )
+        }
+    }
+}
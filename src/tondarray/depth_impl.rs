@@ -0,0 +1,152 @@
+//! Implementations for depth maps being back-projected into ndarray points, and points being projected back.
+
+use super::*;
+use crate::DepthCamera;
+use nalgebra::{Dim, Matrix, Scalar, Storage, Vector3};
+use ndarray::{Array2, ArrayView2};
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+/// ```
+/// use nalgebra::Matrix3;
+/// use nshare::{DepthCamera, IntoNdarray2Points};
+///
+/// let intrinsics = Matrix3::new(
+///     2.0, 0.0, 1.0,
+///     0.0, 2.0, 1.0,
+///     0.0, 0.0, 1.0,
+/// );
+/// let camera = DepthCamera::new(intrinsics, 0.001);
+/// // A depth map in millimeters with a missing measurement in the center.
+/// let depth = ndarray::array![
+///     [2000u16, 2000, 2000],
+///     [2000, 0, 2000],
+///     [2000, 2000, 2000],
+/// ];
+/// let points = depth.view().into_ndarray2_points(&camera);
+/// assert_eq!(points.dim(), (8, 3));
+/// // The top left pixel is up and to the left of the principal point.
+/// assert_eq!(points.row(0), ndarray::array![-1.0, -1.0, 2.0]);
+/// ```
+impl<T> IntoNdarray2Points for ArrayView2<'_, T>
+where
+    T: Copy + Into<f32>,
+{
+    type Out = Array2<f32>;
+
+    fn into_ndarray2_points(self, camera: &DepthCamera) -> Self::Out {
+        back_project(self, camera, 3, |_, _, _| {})
+    }
+}
+
+/// ```
+/// use nalgebra::{DMatrix, Matrix3};
+/// use nshare::{DepthCamera, IntoNdarray2Points};
+///
+/// let camera = DepthCamera::new(Matrix3::identity(), 1.0);
+/// let depth = DMatrix::from_element(4, 5, 1.5f32);
+/// let points = depth.into_ndarray2_points(&camera);
+/// assert_eq!(points.dim(), (20, 3));
+/// // The last pixel is at row 3 and column 4.
+/// assert_eq!(points.row(19), ndarray::array![6.0, 4.5, 1.5]);
+/// ```
+impl<T, R, C, S> IntoNdarray2Points for &Matrix<T, R, C, S>
+where
+    T: Scalar + Copy + Into<f32>,
+    R: Dim,
+    C: Dim,
+    S: Storage<T, R, C>,
+{
+    type Out = Array2<f32>;
+
+    fn into_ndarray2_points(self, camera: &DepthCamera) -> Self::Out {
+        self.as_ndarray2().into_ndarray2_points(camera)
+    }
+}
+
+/// ```
+/// use nalgebra::Matrix3;
+/// use nshare::{DepthCamera, IntoNdarray2Depth, IntoNdarray2Points};
+///
+/// let intrinsics = Matrix3::new(
+///     4.0, 0.0, 2.0,
+///     0.0, 4.0, 2.0,
+///     0.0, 0.0, 1.0,
+/// );
+/// let camera = DepthCamera::new(intrinsics, 1.0);
+/// let points = ndarray::array![
+///     [0.0, 0.0, 1.0],
+///     // Behind the first point, so it is hidden.
+///     [0.0, 0.0, 3.0],
+///     [0.25, -0.5, 1.0],
+///     // Outside of the image.
+///     [10.0, 0.0, 1.0],
+/// ];
+/// let depth = points.view().into_ndarray2_depth(&camera, (5, 5));
+/// assert_eq!(depth[(2, 2)], 1.0);
+/// assert_eq!(depth[(0, 3)], 1.0);
+/// assert_eq!(depth.iter().filter(|&&d| d != 0.0).count(), 2);
+///
+/// // The depth map back-projects into the visible points.
+/// let back = depth.view().into_ndarray2_points(&camera);
+/// assert_eq!(back, ndarray::array![[0.25, -0.5, 1.0], [0.0, 0.0, 1.0]]);
+/// ```
+impl IntoNdarray2Depth for ArrayView2<'_, f32> {
+    type Out = Array2<f32>;
+
+    fn into_ndarray2_depth(self, camera: &DepthCamera, shape: (usize, usize)) -> Self::Out {
+        assert_eq!(self.ncols(), 3, "the points must have 3 coordinates");
+        let mut depth = Array2::zeros(shape);
+        for point in self.rows() {
+            let point = Vector3::new(point[0], point[1], point[2]);
+            if !camera.is_valid(point.z) {
+                continue;
+            }
+            let pixel = camera.intrinsics * point;
+            let (col, row) = (pixel.x / pixel.z, pixel.y / pixel.z);
+            // This also skips points that project to NaN.
+            if !(col >= -0.5 && row >= -0.5) {
+                continue;
+            }
+            // Rounds to the nearest pixel, since the coordinates are not negative.
+            let index = ((row + 0.5) as usize, (col + 0.5) as usize);
+            let raw = point.z / camera.depth_scale;
+            if let Some(out) = depth.get_mut(index) {
+                if *out == 0.0 || raw < *out {
+                    *out = raw;
+                }
+            }
+        }
+        depth
+    }
+}
+
+/// Back-projects every valid pixel into a row of `columns` values, where `extra` appends the ones after `z`.
+pub(crate) fn back_project<T>(
+    depth: ArrayView2<'_, T>,
+    camera: &DepthCamera,
+    columns: usize,
+    mut extra: impl FnMut(&mut Vec<f32>, usize, usize),
+) -> Array2<f32>
+where
+    T: Copy + Into<f32>,
+{
+    let inverse = camera
+        .intrinsics
+        .try_inverse()
+        .expect("the intrinsic matrix must be invertible");
+    let mut data = Vec::new();
+    for ((row, col), &raw) in depth.indexed_iter() {
+        let z = raw.into() * camera.depth_scale;
+        if !camera.is_valid(z) {
+            continue;
+        }
+        let ray = inverse * Vector3::new(col as f32, row as f32, 1.0);
+        let point = ray * (z / ray.z);
+        data.extend_from_slice(point.as_slice());
+        extra(&mut data, row, col);
+    }
+    Array2::from_shape_vec((data.len() / columns, columns), data).unwrap()
+}
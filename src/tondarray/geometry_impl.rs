@@ -0,0 +1,365 @@
+//! Implementations for nalgebra geometric transformations being converted to ndarray types.
+//!
+//! Quaternions become their coefficients in `[i, j, k, w]`, the order nalgebra stores them in. Rotations
+//! become their `(D, D)` matrix, and the other transformations their `(D + 1, D + 1)` homogeneous matrix.
+//! Slices of them are stacked along a new first axis.
+
+use super::*;
+use nalgebra::{
+    allocator::Allocator, AbstractRotation, Const, DefaultAllocator, DimNameAdd, DimNameSum,
+    Isometry, Quaternion, RealField, Rotation, SVector, Scalar, Similarity, TCategory, Transform,
+    Translation, UnitQuaternion, U1,
+};
+use ndarray::{Array2, Array3, ArrayView1, ArrayView2};
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+/// ```
+/// use nalgebra::{Quaternion, UnitQuaternion, Vector3};
+/// use nshare::AsNdarray1;
+///
+/// let q = Quaternion::new(1.0, 2.0, 3.0, 4.0);
+/// // The scalar part comes last.
+/// assert_eq!(q.as_ndarray1(), ndarray::array![2.0, 3.0, 4.0, 1.0]);
+///
+/// // Unit quaternions are viewed through the quaternion they wrap.
+/// let q = UnitQuaternion::from_axis_angle(&Vector3::z_axis(), std::f64::consts::PI);
+/// assert!((q.as_ndarray1()[2] - 1.0).abs() < 1e-12);
+/// ```
+impl<T> AsNdarray1 for Quaternion<T>
+where
+    T: Scalar,
+{
+    type Out<'a> = ArrayView1<'a, T>;
+
+    fn as_ndarray1(&self) -> Self::Out<'_> {
+        ArrayView1::from(self.coords.as_slice())
+    }
+}
+
+/// ```
+/// use nalgebra::Rotation2;
+/// use nshare::AsNdarray2;
+///
+/// let r = Rotation2::new(std::f64::consts::FRAC_PI_2);
+/// let arr = r.as_ndarray2();
+/// assert_eq!(arr.dim(), (2, 2));
+/// assert!((arr[(1, 0)] - 1.0).abs() < 1e-12);
+/// ```
+impl<T, const D: usize> AsNdarray2 for Rotation<T, D>
+where
+    T: Scalar,
+{
+    type Out<'a> = ArrayView2<'a, T>;
+
+    fn as_ndarray2(&self) -> Self::Out<'_> {
+        self.matrix().as_ndarray2()
+    }
+}
+
+/// ```
+/// use nalgebra::Translation2;
+/// use nshare::IntoNdarray2;
+///
+/// let arr = Translation2::new(10.0, 20.0).into_ndarray2();
+/// assert_eq!(arr, ndarray::array![
+///     [1.0, 0.0, 10.0],
+///     [0.0, 1.0, 20.0],
+///     [0.0, 0.0, 1.0],
+/// ]);
+/// ```
+impl<T, const D: usize> IntoNdarray2 for Translation<T, D>
+where
+    T: RealField,
+{
+    type Out = Array2<T>;
+
+    fn into_ndarray2(self) -> Self::Out {
+        homogeneous(&Rotation::<T, D>::identity(), T::one(), &self)
+    }
+}
+
+/// ```
+/// use nalgebra::{Isometry3, Vector3};
+/// use nshare::IntoNdarray2;
+///
+/// let iso = Isometry3::new(Vector3::new(1.0, 2.0, 3.0), Vector3::zeros());
+/// let arr = iso.into_ndarray2();
+/// assert_eq!(arr.dim(), (4, 4));
+/// assert_eq!(arr.column(3), ndarray::array![1.0, 2.0, 3.0, 1.0]);
+/// ```
+impl<T, R, const D: usize> IntoNdarray2 for Isometry<T, R, D>
+where
+    T: RealField,
+    R: AbstractRotation<T, D>,
+{
+    type Out = Array2<T>;
+
+    fn into_ndarray2(self) -> Self::Out {
+        homogeneous(&self.rotation, T::one(), &self.translation)
+    }
+}
+
+/// ```
+/// use nalgebra::{Similarity2, Vector2};
+/// use nshare::IntoNdarray2;
+///
+/// let sim = Similarity2::new(Vector2::new(1.0, 2.0), 0.0, 3.0);
+/// assert_eq!(sim.into_ndarray2(), ndarray::array![
+///     [3.0, 0.0, 1.0],
+///     [0.0, 3.0, 2.0],
+///     [0.0, 0.0, 1.0],
+/// ]);
+/// ```
+impl<T, R, const D: usize> IntoNdarray2 for Similarity<T, R, D>
+where
+    T: RealField,
+    R: AbstractRotation<T, D>,
+{
+    type Out = Array2<T>;
+
+    fn into_ndarray2(self) -> Self::Out {
+        homogeneous(
+            &self.isometry.rotation,
+            self.scaling(),
+            &self.isometry.translation,
+        )
+    }
+}
+
+/// ```
+/// use nalgebra::{Matrix3, Projective2};
+/// use nshare::IntoNdarray2;
+///
+/// let m = Matrix3::new(
+///     1.0, 0.0, 0.0,
+///     0.0, 1.0, 0.0,
+///     0.5, 0.0, 1.0,
+/// );
+/// let arr = Projective2::from_matrix_unchecked(m).into_ndarray2();
+/// assert_eq!(arr[(2, 0)], 0.5);
+/// ```
+impl<T, C, const D: usize> IntoNdarray2 for Transform<T, C, D>
+where
+    T: RealField,
+    C: TCategory,
+    Const<D>: DimNameAdd<U1>,
+    DefaultAllocator: Allocator<DimNameSum<Const<D>, U1>, DimNameSum<Const<D>, U1>>,
+{
+    type Out = Array2<T>;
+
+    fn into_ndarray2(self) -> Self::Out {
+        self.matrix().as_ndarray2().to_owned()
+    }
+}
+
+/// Quaternions are stacked in `(quaternion, coefficient)`.
+///
+/// ```
+/// use nalgebra::Quaternion;
+/// use nshare::IntoNdarray2;
+///
+/// let qs = [Quaternion::new(1.0, 0.0, 0.0, 0.0), Quaternion::new(0.0, 1.0, 0.0, 0.0)];
+/// assert_eq!(qs.as_slice().into_ndarray2(), ndarray::array![
+///     [0.0, 0.0, 0.0, 1.0],
+///     [1.0, 0.0, 0.0, 0.0],
+/// ]);
+/// ```
+impl<T> IntoNdarray2 for &[Quaternion<T>]
+where
+    T: Scalar,
+{
+    type Out = Array2<T>;
+
+    fn into_ndarray2(self) -> Self::Out {
+        Array2::from_shape_fn((self.len(), 4), |(i, c)| self[i].coords[c].clone())
+    }
+}
+
+/// ```
+/// use nalgebra::{UnitQuaternion, Vector3};
+/// use nshare::IntoNdarray2;
+///
+/// let qs: Vec<_> = (0..3)
+///     .map(|i| UnitQuaternion::from_axis_angle(&Vector3::x_axis(), i as f32))
+///     .collect();
+/// let arr = qs.as_slice().into_ndarray2();
+/// assert_eq!(arr.dim(), (3, 4));
+/// assert_eq!(arr.row(0), ndarray::array![0.0, 0.0, 0.0, 1.0]);
+/// ```
+impl<T> IntoNdarray2 for &[UnitQuaternion<T>]
+where
+    T: Scalar,
+{
+    type Out = Array2<T>;
+
+    fn into_ndarray2(self) -> Self::Out {
+        Array2::from_shape_fn((self.len(), 4), |(i, c)| self[i].coords[c].clone())
+    }
+}
+
+/// Rotations are stacked in `(rotation, row, col)`.
+///
+/// ```
+/// use nalgebra::Rotation3;
+/// use nshare::IntoNdarray3;
+///
+/// let rs = [Rotation3::identity(), Rotation3::from_euler_angles(0.0, 0.0, 1.0)];
+/// let arr = rs.as_slice().into_ndarray3();
+/// assert_eq!(arr.dim(), (2, 3, 3));
+/// assert_eq!(arr.slice(ndarray::s![0, .., ..]), ndarray::Array2::eye(3));
+/// ```
+impl<T, const D: usize> IntoNdarray3 for &[Rotation<T, D>]
+where
+    T: Scalar,
+{
+    type Out = Array3<T>;
+
+    fn into_ndarray3(self) -> Self::Out {
+        stack((D, D), self.iter().map(|rotation| rotation.as_ndarray2()))
+    }
+}
+
+/// Translations are stacked in `(translation, row, col)`.
+///
+/// ```
+/// use nalgebra::Translation2;
+/// use nshare::IntoNdarray3;
+///
+/// let ts = [Translation2::new(1.0, 2.0), Translation2::new(3.0, 4.0)];
+/// let arr = ts.as_slice().into_ndarray3();
+/// assert_eq!(arr.dim(), (2, 3, 3));
+/// assert_eq!(arr.slice(ndarray::s![.., 0, 2]), ndarray::array![1.0, 3.0]);
+/// ```
+impl<T, const D: usize> IntoNdarray3 for &[Translation<T, D>]
+where
+    T: RealField,
+{
+    type Out = Array3<T>;
+
+    fn into_ndarray3(self) -> Self::Out {
+        let matrices: Vec<_> = self.iter().map(|t| t.clone().into_ndarray2()).collect();
+        stack((D + 1, D + 1), matrices.iter().map(Array2::view))
+    }
+}
+
+/// Isometries are stacked in `(isometry, row, col)`.
+///
+/// ```
+/// use nalgebra::{Isometry3, Vector3};
+/// use nshare::IntoNdarray3;
+///
+/// let poses: Vec<_> = (0..4)
+///     .map(|i| Isometry3::translation(i as f64, 0.0, 0.0))
+///     .collect();
+/// let arr = poses.as_slice().into_ndarray3();
+/// assert_eq!(arr.dim(), (4, 4, 4));
+/// assert_eq!(arr.slice(ndarray::s![.., 0, 3]), ndarray::array![0.0, 1.0, 2.0, 3.0]);
+/// ```
+impl<T, R, const D: usize> IntoNdarray3 for &[Isometry<T, R, D>]
+where
+    T: RealField,
+    R: AbstractRotation<T, D>,
+{
+    type Out = Array3<T>;
+
+    fn into_ndarray3(self) -> Self::Out {
+        let matrices: Vec<_> = self.iter().map(|iso| iso.clone().into_ndarray2()).collect();
+        stack((D + 1, D + 1), matrices.iter().map(Array2::view))
+    }
+}
+
+/// Similarities are stacked in `(similarity, row, col)`.
+///
+/// ```
+/// use nalgebra::{Similarity2, Vector2};
+/// use nshare::IntoNdarray3;
+///
+/// let sims = [Similarity2::new(Vector2::zeros(), 0.0, 2.0); 2];
+/// let arr = sims.as_slice().into_ndarray3();
+/// assert_eq!(arr.dim(), (2, 3, 3));
+/// assert_eq!(arr[(1, 1, 1)], 2.0);
+/// ```
+impl<T, R, const D: usize> IntoNdarray3 for &[Similarity<T, R, D>]
+where
+    T: RealField,
+    R: AbstractRotation<T, D>,
+{
+    type Out = Array3<T>;
+
+    fn into_ndarray3(self) -> Self::Out {
+        let matrices: Vec<_> = self.iter().map(|sim| sim.clone().into_ndarray2()).collect();
+        stack((D + 1, D + 1), matrices.iter().map(Array2::view))
+    }
+}
+
+/// Transforms are stacked in `(transform, row, col)`.
+///
+/// ```
+/// use nalgebra::{Affine2, Matrix3};
+/// use nshare::IntoNdarray3;
+///
+/// let ts = [Affine2::identity(), Affine2::from_matrix_unchecked(Matrix3::new_scaling(2.0))];
+/// let arr = ts.as_slice().into_ndarray3();
+/// assert_eq!(arr.dim(), (2, 3, 3));
+/// assert_eq!(arr[(1, 0, 0)], 2.0);
+/// ```
+impl<T, C, const D: usize> IntoNdarray3 for &[Transform<T, C, D>]
+where
+    T: RealField,
+    C: TCategory,
+    Const<D>: DimNameAdd<U1>,
+    DefaultAllocator: Allocator<DimNameSum<Const<D>, U1>, DimNameSum<Const<D>, U1>>,
+{
+    type Out = Array3<T>;
+
+    fn into_ndarray3(self) -> Self::Out {
+        stack(
+            (D + 1, D + 1),
+            self.iter()
+                .map(|transform| transform.matrix().as_ndarray2()),
+        )
+    }
+}
+
+/// The `(D + 1, D + 1)` homogeneous matrix of a rotation, followed by a uniform scaling and a translation.
+fn homogeneous<T, R, const D: usize>(
+    rotation: &R,
+    scaling: T,
+    translation: &Translation<T, D>,
+) -> Array2<T>
+where
+    T: RealField,
+    R: AbstractRotation<T, D>,
+{
+    let mut arr = Array2::eye(D + 1);
+    // The columns of the linear part are the images of the basis vectors.
+    for (axis, mut column) in arr.columns_mut().into_iter().take(D).enumerate() {
+        let mut basis = SVector::<T, D>::zeros();
+        basis[axis] = scaling.clone();
+        let image = rotation.transform_vector(&basis);
+        for (out, value) in column.iter_mut().zip(image.iter()) {
+            *out = value.clone();
+        }
+    }
+    for (out, value) in arr.column_mut(D).iter_mut().zip(translation.vector.iter()) {
+        *out = value.clone();
+    }
+    arr
+}
+
+/// Copies matrices of the given shape into an array in `(matrix, row, col)`.
+fn stack<'a, T, I>(shape: (usize, usize), matrices: I) -> Array3<T>
+where
+    T: Clone + 'a,
+    I: ExactSizeIterator<Item = ArrayView2<'a, T>>,
+{
+    let len = matrices.len();
+    let mut data = Vec::with_capacity(len * shape.0 * shape.1);
+    for matrix in matrices {
+        data.extend(matrix.iter().cloned());
+    }
+    Array3::from_shape_vec((len, shape.0, shape.1), data).unwrap()
+}
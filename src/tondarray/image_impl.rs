@@ -1,13 +1,20 @@
 //! Implementations for conversions from image types to ndarray types.
 
 use super::*;
+use crate::{Channel, Col, Labeled, LabeledArray, LabeledView, LabeledViewMut, Row, Z};
 use core::ops::{Deref, DerefMut};
-use image::{flat::SampleLayout, ImageBuffer, Luma, Pixel, Primitive};
-use ndarray::{Array2, Array3, ArrayView2, ArrayView3, ArrayViewMut2, ArrayViewMut3, ShapeBuilder};
+use image::{
+    flat::SampleLayout, Delay, Frame, Frames, ImageBuffer, ImageResult, Luma, LumaA, Pixel,
+    Primitive, Rgba, RgbaImage,
+};
+use ndarray::{
+    Array2, Array3, Array4, ArrayView2, ArrayView3, ArrayViewMut2, ArrayViewMut3, Axis, Ix3,
+    ShapeBuilder, StrideShape,
+};
 
 extern crate alloc;
 
-use alloc::vec::Vec;
+use alloc::{vec, vec::Vec};
 
 /// ```
 /// use image::GrayImage;
@@ -40,6 +47,38 @@ where
     }
 }
 
+/// Stacks luma images of the same dimensions into a volume in `(z, y, x)`.
+///
+/// ```
+/// use image::{GrayImage, Luma};
+/// use nshare::IntoNdarray3;
+///
+/// let slices: Vec<_> = (0..3).map(|z| GrayImage::from_pixel(2, 4, Luma([z * 10]))).collect();
+/// let volume = slices.into_ndarray3();
+/// assert_eq!(volume.dim(), (3, 4, 2));
+/// assert_eq!(volume[(2, 3, 1)], 20);
+/// ```
+impl<A> IntoNdarray3 for Vec<ImageBuffer<Luma<A>, Vec<A>>>
+where
+    A: Primitive + 'static,
+{
+    type Out = Array3<A>;
+
+    fn into_ndarray3(self) -> Self::Out {
+        let (width, height) = self.first().map_or((0, 0), |slice| slice.dimensions());
+        let mut data = Vec::with_capacity(self.len() * width as usize * height as usize);
+        for slice in &self {
+            assert_eq!(
+                slice.dimensions(),
+                (width, height),
+                "all slices must have the same dimensions"
+            );
+            data.extend_from_slice(slice.as_raw());
+        }
+        Array3::from_shape_vec((self.len(), height as usize, width as usize), data).unwrap()
+    }
+}
+
 /// ```
 /// use image::{GrayImage, Luma};
 /// use nshare::AsNdarray2;
@@ -208,3 +247,667 @@ where
         ArrayViewMut3::from_shape(shape.strides(strides), self).unwrap()
     }
 }
+
+/// ```
+/// use image::{Rgba, RgbaImage};
+/// use nshare::AsNdarray3Alpha;
+///
+/// let mut img = RgbaImage::new(2, 4);
+/// img[(1, 0)] = Rgba([10, 20, 30, 128]);
+/// let (color, alpha) = img.as_ndarray3_alpha();
+/// assert_eq!(color.dim(), (3, 4, 2));
+/// assert_eq!(alpha.dim(), (4, 2));
+/// assert_eq!(color.slice(ndarray::s![.., 0, 1]), ndarray::array![10, 20, 30]);
+/// assert_eq!(alpha[(0, 1)], 128);
+/// ```
+impl<T, Container> AsNdarray3Alpha for ImageBuffer<Rgba<T>, Container>
+where
+    T: Primitive + 'static,
+    Rgba<T>: Pixel<Subpixel = T>,
+    Container: Deref<Target = [T]>,
+{
+    type Out<'a> = (ArrayView3<'a, T>, ArrayView2<'a, T>)
+    where
+        Container: 'a;
+
+    fn as_ndarray3_alpha(&self) -> Self::Out<'_> {
+        let view = ArrayView3::from_shape(planar_shape(self.sample_layout()), self).unwrap();
+        let (color, alpha) = view.split_at(Axis(0), 3);
+        (color, alpha.index_axis_move(Axis(0), 0))
+    }
+}
+
+/// ```
+/// use image::{LumaA, GrayAlphaImage};
+/// use nshare::AsNdarray3Alpha;
+///
+/// let img = GrayAlphaImage::from_pixel(2, 4, LumaA([7, 255]));
+/// let (color, alpha) = img.as_ndarray3_alpha();
+/// assert_eq!(color.dim(), (1, 4, 2));
+/// assert!(alpha.iter().all(|&a| a == 255));
+/// ```
+impl<T, Container> AsNdarray3Alpha for ImageBuffer<LumaA<T>, Container>
+where
+    T: Primitive + 'static,
+    LumaA<T>: Pixel<Subpixel = T>,
+    Container: Deref<Target = [T]>,
+{
+    type Out<'a> = (ArrayView3<'a, T>, ArrayView2<'a, T>)
+    where
+        Container: 'a;
+
+    fn as_ndarray3_alpha(&self) -> Self::Out<'_> {
+        let view = ArrayView3::from_shape(planar_shape(self.sample_layout()), self).unwrap();
+        let (color, alpha) = view.split_at(Axis(0), 1);
+        (color, alpha.index_axis_move(Axis(0), 0))
+    }
+}
+
+/// ```
+/// use image::{Rgba, RgbaImage};
+/// use nshare::AsNdarray3AlphaMut;
+///
+/// let mut img = RgbaImage::from_pixel(2, 4, Rgba([200, 100, 50, 255]));
+/// let (mut color, mut alpha) = img.as_ndarray3_alpha_mut();
+/// // Fade the image out, premultiplying the colors by the new alpha.
+/// color.mapv_inplace(|c| c / 2);
+/// alpha.fill(128);
+/// assert_eq!(img[(1, 3)], Rgba([100, 50, 25, 128]));
+/// ```
+impl<T, Container> AsNdarray3AlphaMut for ImageBuffer<Rgba<T>, Container>
+where
+    T: Primitive + 'static,
+    Rgba<T>: Pixel<Subpixel = T>,
+    Container: DerefMut<Target = [T]>,
+{
+    type Out<'a> = (ArrayViewMut3<'a, T>, ArrayViewMut2<'a, T>)
+    where
+        Container: 'a;
+
+    fn as_ndarray3_alpha_mut(&mut self) -> Self::Out<'_> {
+        let shape = planar_shape(self.sample_layout());
+        let view = ArrayViewMut3::from_shape(shape, self).unwrap();
+        let (color, alpha) = view.split_at(Axis(0), 3);
+        (color, alpha.index_axis_move(Axis(0), 0))
+    }
+}
+
+/// ```
+/// use image::{LumaA, GrayAlphaImage};
+/// use nshare::AsNdarray3AlphaMut;
+///
+/// let mut img = GrayAlphaImage::new(2, 4);
+/// img.as_ndarray3_alpha_mut().1.fill(255);
+/// assert_eq!(img[(1, 3)], LumaA([0, 255]));
+/// ```
+impl<T, Container> AsNdarray3AlphaMut for ImageBuffer<LumaA<T>, Container>
+where
+    T: Primitive + 'static,
+    LumaA<T>: Pixel<Subpixel = T>,
+    Container: DerefMut<Target = [T]>,
+{
+    type Out<'a> = (ArrayViewMut3<'a, T>, ArrayViewMut2<'a, T>)
+    where
+        Container: 'a;
+
+    fn as_ndarray3_alpha_mut(&mut self) -> Self::Out<'_> {
+        let shape = planar_shape(self.sample_layout());
+        let view = ArrayViewMut3::from_shape(shape, self).unwrap();
+        let (color, alpha) = view.split_at(Axis(0), 1);
+        (color, alpha.index_axis_move(Axis(0), 0))
+    }
+}
+
+/// The shape and strides of a view of an image in `(channel, row, col)`.
+fn planar_shape(layout: SampleLayout) -> StrideShape<Ix3> {
+    let SampleLayout {
+        channels,
+        channel_stride,
+        height,
+        height_stride,
+        width,
+        width_stride,
+    } = layout;
+    let shape = (channels as usize, height as usize, width as usize);
+    shape.strides((channel_stride, height_stride, width_stride))
+}
+
+/// The image is moved into an array in `(Channel, Row, Col)`.
+///
+/// ```
+/// use image::{Rgb, RgbImage};
+/// use nshare::{Channel, IntoNdarrayLabeled};
+///
+/// let img = RgbImage::from_pixel(2, 4, Rgb([1, 2, 3]));
+/// let labeled = img.into_ndarray_labeled();
+/// assert_eq!(labeled.len_of::<Channel>(), 3);
+/// assert_eq!(labeled.pixel((1, 3)).unwrap().to_vec(), [1, 2, 3]);
+/// ```
+impl<P> IntoNdarrayLabeled for ImageBuffer<P, Vec<P::Subpixel>>
+where
+    P: Pixel + 'static,
+{
+    type Out = LabeledArray<P::Subpixel, (Channel, Row, Col)>;
+
+    fn into_ndarray_labeled(self) -> Self::Out {
+        Labeled::new(self.into_ndarray3())
+    }
+}
+
+/// Luma slices of the same dimensions are stacked into a volume in `(Z, Row, Col)`.
+///
+/// ```
+/// use image::{GrayImage, Luma};
+/// use nshare::{IntoNdarrayLabeled, Z};
+///
+/// let slices: Vec<_> = (0..3).map(|z| GrayImage::from_pixel(2, 4, Luma([z * 10]))).collect();
+/// let volume = slices.into_ndarray_labeled();
+/// assert_eq!(volume.len_of::<Z>(), 3);
+/// assert_eq!(volume.pixel((1, 3)).unwrap().to_vec(), [0, 10, 20]);
+/// ```
+impl<A> IntoNdarrayLabeled for Vec<ImageBuffer<Luma<A>, Vec<A>>>
+where
+    A: Primitive + 'static,
+{
+    type Out = LabeledArray<A, (Z, Row, Col)>;
+
+    fn into_ndarray_labeled(self) -> Self::Out {
+        Labeled::new(self.into_ndarray3())
+    }
+}
+
+/// The image is viewed in `(Channel, Row, Col)`.
+///
+/// ```
+/// use image::{Rgb, RgbImage};
+/// use nshare::{AsNdarrayLabeled, Col, Row};
+///
+/// let mut img = RgbImage::new(2, 4);
+/// img[(1, 0)] = Rgb([0, 255, 0]);
+/// let labeled = img.as_ndarray_labeled();
+/// // The image is 2 pixels wide and 4 pixels high, whatever the order of the axes.
+/// assert_eq!((labeled.len_of::<Col>(), labeled.len_of::<Row>()), (2, 4));
+/// assert_eq!(labeled.pixel((1, 0)).unwrap()[1], 255);
+/// ```
+impl<P> AsNdarrayLabeled for ImageBuffer<P, Vec<P::Subpixel>>
+where
+    P: Pixel + 'static,
+{
+    type Out<'a> = LabeledView<'a, P::Subpixel, (Channel, Row, Col)>;
+
+    fn as_ndarray_labeled(&self) -> Self::Out<'_> {
+        Labeled::new(self.as_ndarray3())
+    }
+}
+
+/// ```
+/// use image::{Rgb, RgbImage};
+/// use nshare::AsNdarrayLabeledMut;
+///
+/// let mut img = RgbImage::new(2, 4);
+/// img.as_ndarray_labeled_mut().pixel_mut((1, 3)).unwrap().fill(9);
+/// assert_eq!(img[(1, 3)], Rgb([9, 9, 9]));
+/// ```
+impl<P> AsNdarrayLabeledMut for ImageBuffer<P, Vec<P::Subpixel>>
+where
+    P: Pixel + 'static,
+{
+    type Out<'a> = LabeledViewMut<'a, P::Subpixel, (Channel, Row, Col)>;
+
+    fn as_ndarray_labeled_mut(&mut self) -> Self::Out<'_> {
+        Labeled::new(self.as_ndarray3_mut())
+    }
+}
+
+/// ```
+/// use image::{RgbImage, Rgb};
+/// use nshare::{ChannelOrder, IntoNdarray3Normalized, Normalization};
+///
+/// let mut img = RgbImage::new(2, 4);
+/// img[(1, 0)] = Rgb([255, 51, 0]);
+/// let normalization = Normalization {
+///     mean: &[0.5],
+///     std: &[0.5],
+///     ..Normalization::UNIT
+/// };
+/// let nd = img.into_ndarray3_normalized(&normalization);
+/// // ndarray uses (channel, row, col), so the dims get flipped.
+/// assert_eq!(nd.dim(), (3, 4, 2));
+/// assert_eq!(nd[(0, 0, 1)], 1.0);
+/// assert!((nd[(1, 0, 1)] + 0.6).abs() < 1e-6);
+/// assert_eq!(nd[(2, 0, 1)], -1.0);
+/// assert!(nd.is_standard_layout());
+///
+/// // The channels can also be placed last.
+/// let normalization = Normalization {
+///     order: ChannelOrder::Hwc,
+///     ..Normalization::IMAGENET
+/// };
+/// let nd = img.into_ndarray3_normalized(&normalization);
+/// assert_eq!(nd.dim(), (4, 2, 3));
+/// ```
+impl<P, Container> IntoNdarray3Normalized for &ImageBuffer<P, Container>
+where
+    P: Pixel + 'static,
+    Container: Deref<Target = [P::Subpixel]>,
+{
+    type Out = Array3<f32>;
+
+    fn into_ndarray3_normalized(self, normalization: &Normalization<'_>) -> Self::Out {
+        let (mut out, row_axis, coefficients) = normalized_output(self, normalization);
+        let row_len = P::CHANNEL_COUNT as usize * self.width() as usize;
+        if row_len == 0 {
+            return out;
+        }
+        let samples = &self.as_raw()[..row_len * self.height() as usize];
+        out.axis_iter_mut(row_axis)
+            .zip(samples.chunks_exact(row_len))
+            .for_each(|(out_row, row)| {
+                normalize_row(row, out_row, &coefficients, normalization.order)
+            });
+        out
+    }
+}
+
+/// ```
+/// use image::{Rgb, RgbImage};
+/// use nshare::{IntoNdarray3Normalized, IntoNdarray3NormalizedPar, Normalization};
+///
+/// let img = RgbImage::from_fn(48, 64, |x, y| Rgb([x as u8, y as u8, (x ^ y) as u8]));
+/// let nd = img.into_ndarray3_normalized_par(&Normalization::IMAGENET);
+/// assert_eq!(nd, img.into_ndarray3_normalized(&Normalization::IMAGENET));
+/// ```
+#[cfg(feature = "rayon")]
+impl<P, Container> IntoNdarray3NormalizedPar for &ImageBuffer<P, Container>
+where
+    P: Pixel + 'static,
+    P::Subpixel: Send + Sync,
+    Container: Deref<Target = [P::Subpixel]>,
+{
+    type Out = Array3<f32>;
+
+    fn into_ndarray3_normalized_par(self, normalization: &Normalization<'_>) -> Self::Out {
+        use rayon::prelude::*;
+        let (mut out, row_axis, coefficients) = normalized_output(self, normalization);
+        let row_len = P::CHANNEL_COUNT as usize * self.width() as usize;
+        if row_len == 0 {
+            return out;
+        }
+        let samples = &self.as_raw()[..row_len * self.height() as usize];
+        out.axis_iter_mut(row_axis)
+            .into_par_iter()
+            .zip(samples.par_chunks_exact(row_len))
+            .for_each(|(out_row, row)| {
+                normalize_row(row, out_row, &coefficients, normalization.order)
+            });
+        out
+    }
+}
+
+/// The zeroed output of a normalization, the axis of its rows and the coefficients of every channel.
+fn normalized_output<P, Container>(
+    image: &ImageBuffer<P, Container>,
+    normalization: &Normalization<'_>,
+) -> (Array3<f32>, Axis, Vec<(f32, f32)>)
+where
+    P: Pixel,
+    Container: Deref<Target = [P::Subpixel]>,
+{
+    let channels = P::CHANNEL_COUNT as usize;
+    let (width, height) = (image.width() as usize, image.height() as usize);
+    let coefficients = normalization_coefficients::<P::Subpixel>(normalization, channels);
+    let (shape, row_axis) = match normalization.order {
+        ChannelOrder::Chw => ((channels, height, width), Axis(1)),
+        ChannelOrder::Hwc => ((height, width, channels), Axis(0)),
+    };
+    (Array3::zeros(shape), row_axis, coefficients)
+}
+
+/// Normalizes a row of interleaved pixels into a `(channel, col)` view of the output row, or a
+/// `(col, channel)` view when the channels are last.
+fn normalize_row<A: Primitive>(
+    row: &[A],
+    mut out_row: ArrayViewMut2<'_, f32>,
+    coefficients: &[(f32, f32)],
+    order: ChannelOrder,
+) {
+    if order == ChannelOrder::Hwc {
+        out_row = out_row.reversed_axes();
+    }
+    let channels = coefficients.len();
+    for (c, (mut out_channel, &(scale, offset))) in
+        out_row.outer_iter_mut().zip(coefficients).enumerate()
+    {
+        for (out, pixel) in out_channel.iter_mut().zip(row.chunks_exact(channels)) {
+            *out = normalize(pixel[c], scale, offset);
+        }
+    }
+}
+
+/// Folds a [`Normalization`] into a `(scale, offset)` pair for every channel.
+fn normalization_coefficients<A>(
+    normalization: &Normalization<'_>,
+    channels: usize,
+) -> Vec<(f32, f32)>
+where
+    A: Primitive,
+{
+    let per_channel = |values: &[f32], default: f32, name: &str| -> Vec<f32> {
+        match values.len() {
+            0 => vec![default; channels],
+            1 => vec![values[0]; channels],
+            len if len == channels => values.to_vec(),
+            len => panic!(
+                "the normalization {name} must be empty, a single value or one value per channel, \
+                 but it has {len} values for {channels} channel{}",
+                if channels == 1 { "" } else { "s" }
+            ),
+        }
+    };
+    let max_value = normalization
+        .max_value
+        .unwrap_or_else(|| A::DEFAULT_MAX_VALUE.to_f32().unwrap());
+    let mean = per_channel(normalization.mean, 0.0, "mean");
+    let std = per_channel(normalization.std, 1.0, "std");
+    mean.iter()
+        .zip(&std)
+        .map(|(&mean, &std)| (1.0 / (max_value * std), -mean / std))
+        .collect()
+}
+
+#[inline(always)]
+fn normalize<A: Primitive>(sample: A, scale: f32, offset: f32) -> f32 {
+    sample.to_f32().unwrap() * scale + offset
+}
+
+/// ```
+/// use image::{Rgb, RgbImage};
+/// use nshare::IntoNdarray3Planar;
+/// use ndarray::s;
+///
+/// let mut img = RgbImage::new(2, 4);
+/// img[(1, 0)] = Rgb([0, 255, 0]);
+/// let nd = img.into_ndarray3_planar();
+/// // ndarray uses (channel, row, col), so the dims get flipped.
+/// assert_eq!(nd.dim(), (3, 4, 2));
+/// assert_eq!(nd.slice(s![1, 0, ..]).sum(), 255);
+/// // Each channel is a contiguous plane.
+/// assert!(nd.is_standard_layout());
+/// assert_eq!(&nd.as_slice().unwrap()[8..10], &[0, 255]);
+/// ```
+impl<P, Container> IntoNdarray3Planar for &ImageBuffer<P, Container>
+where
+    P: Pixel + 'static,
+    Container: Deref<Target = [P::Subpixel]>,
+{
+    type Out = Array3<P::Subpixel>;
+
+    fn into_ndarray3_planar(self) -> Self::Out {
+        let channels = P::CHANNEL_COUNT as usize;
+        let (width, height) = (self.width() as usize, self.height() as usize);
+        let mut out = Array3::from_elem((channels, height, width), P::Subpixel::DEFAULT_MIN_VALUE);
+        let row_len = channels * width;
+        if row_len == 0 {
+            return out;
+        }
+        let samples = &self.as_raw()[..row_len * height];
+        // Rows are deinterleaved one at a time so that they stay in cache while every plane is written.
+        out.axis_iter_mut(Axis(1))
+            .zip(samples.chunks_exact(row_len))
+            .for_each(|(out_row, row)| deinterleave_row(row, out_row));
+        out
+    }
+}
+
+/// ```
+/// use image::{Rgb, RgbImage};
+/// use nshare::{IntoNdarray3Planar, IntoNdarray3PlanarPar};
+///
+/// let img = RgbImage::from_fn(48, 64, |x, y| Rgb([x as u8, y as u8, (x ^ y) as u8]));
+/// assert_eq!(img.into_ndarray3_planar_par(), img.into_ndarray3_planar());
+/// ```
+#[cfg(feature = "rayon")]
+impl<P, Container> IntoNdarray3PlanarPar for &ImageBuffer<P, Container>
+where
+    P: Pixel + 'static,
+    P::Subpixel: Send + Sync,
+    Container: Deref<Target = [P::Subpixel]>,
+{
+    type Out = Array3<P::Subpixel>;
+
+    fn into_ndarray3_planar_par(self) -> Self::Out {
+        use rayon::prelude::*;
+        let channels = P::CHANNEL_COUNT as usize;
+        let (width, height) = (self.width() as usize, self.height() as usize);
+        let mut out = Array3::from_elem((channels, height, width), P::Subpixel::DEFAULT_MIN_VALUE);
+        let row_len = channels * width;
+        if row_len == 0 {
+            return out;
+        }
+        let samples = &self.as_raw()[..row_len * height];
+        out.axis_iter_mut(Axis(1))
+            .into_par_iter()
+            .zip(samples.par_chunks_exact(row_len))
+            .for_each(|(out_row, row)| deinterleave_row(row, out_row));
+        out
+    }
+}
+
+/// Splits a row of interleaved pixels into a contiguous `(channel, col)` view.
+fn deinterleave_row<T: Copy>(row: &[T], mut out: ArrayViewMut2<'_, T>) {
+    match out.nrows() {
+        1 => deinterleave_lanes::<T, 1>(row, out),
+        2 => deinterleave_lanes::<T, 2>(row, out),
+        3 => deinterleave_lanes::<T, 3>(row, out),
+        4 => deinterleave_lanes::<T, 4>(row, out),
+        channels => {
+            for (c, mut lane) in out.outer_iter_mut().enumerate() {
+                for (out, pixel) in lane.iter_mut().zip(row.chunks_exact(channels)) {
+                    *out = pixel[c];
+                }
+            }
+        }
+    }
+}
+
+/// Deinterleaves a known number of channels, which lets the compiler unroll and vectorize the loops.
+fn deinterleave_lanes<T: Copy, const C: usize>(row: &[T], mut out: ArrayViewMut2<'_, T>) {
+    for (c, mut lane) in out.outer_iter_mut().enumerate() {
+        let lane = lane.as_slice_mut().unwrap();
+        for (out, pixel) in lane.iter_mut().zip(row.chunks_exact(C)) {
+            *out = pixel[c];
+        }
+    }
+}
+
+/// Decoding stops at the first frame that fails, and its error is returned.
+///
+/// ```
+/// use image::{Delay, Frame, Frames, Rgba, RgbaImage};
+/// use nshare::{ChannelOrder, IntoNdarray4Frames};
+///
+/// let delay = Delay::from_numer_denom_ms(100, 1);
+/// let frames = vec![
+///     Frame::from_parts(RgbaImage::from_pixel(2, 2, Rgba([255, 0, 0, 255])), 0, 0, delay),
+///     // A partial frame covering only the bottom right pixel.
+///     Frame::from_parts(RgbaImage::from_pixel(1, 1, Rgba([0, 0, 255, 255])), 1, 1, delay),
+/// ];
+/// let frames = Frames::new(Box::new(frames.into_iter().map(Ok)));
+/// let (arr, delays) = frames.into_ndarray4_frames(ChannelOrder::Chw).unwrap();
+/// assert_eq!(arr.dim(), (2, 4, 2, 2));
+/// assert_eq!(delays, vec![delay; 2]);
+/// // The second frame keeps the first one where it does not cover the canvas.
+/// assert!(arr.slice(ndarray::s![1, .., 0, 0]).iter().eq(&[255, 0, 0, 255]));
+/// assert!(arr.slice(ndarray::s![1, .., 1, 1]).iter().eq(&[0, 0, 255, 255]));
+/// ```
+impl IntoNdarray4Frames for Frames<'_> {
+    type Out = ImageResult<(Array4<u8>, Vec<Delay>)>;
+
+    fn into_ndarray4_frames(self, order: ChannelOrder) -> Self::Out {
+        Ok(self.collect_frames()?.into_ndarray4_frames(order))
+    }
+}
+
+/// ```
+/// use image::{Delay, Frame, Rgba, RgbaImage};
+/// use nshare::{ChannelOrder, IntoNdarray4Frames};
+///
+/// let frames = [Frame::new(RgbaImage::from_pixel(3, 2, Rgba([1, 2, 3, 4])))];
+/// let (arr, delays) = frames.as_slice().into_ndarray4_frames(ChannelOrder::Hwc);
+/// assert_eq!(arr.dim(), (1, 2, 3, 4));
+/// assert!(arr.is_standard_layout());
+/// assert_eq!(delays.len(), 1);
+/// ```
+impl IntoNdarray4Frames for &[Frame] {
+    type Out = (Array4<u8>, Vec<Delay>);
+
+    fn into_ndarray4_frames(self, order: ChannelOrder) -> Self::Out {
+        // The canvas is the smallest one that every frame fits on at its offset.
+        let (width, height) = self.iter().fold((0, 0), |(width, height), frame| {
+            let buffer = frame.buffer();
+            (
+                width.max(frame.left() + buffer.width()),
+                height.max(frame.top() + buffer.height()),
+            )
+        });
+        let mut canvas = RgbaImage::new(width, height);
+        let mut out = Array4::zeros((self.len(), height as usize, width as usize, 4));
+        for (frame, mut out) in self.iter().zip(out.outer_iter_mut()) {
+            for (x, y, &pixel) in frame.buffer().enumerate_pixels() {
+                canvas
+                    .get_pixel_mut(frame.left() + x, frame.top() + y)
+                    .blend(&pixel);
+            }
+            out.as_slice_mut().unwrap().copy_from_slice(canvas.as_raw());
+        }
+        let delays = self.iter().map(Frame::delay).collect();
+        match order {
+            ChannelOrder::Hwc => (out, delays),
+            ChannelOrder::Chw => (
+                out.permuted_axes([0, 3, 1, 2])
+                    .as_standard_layout()
+                    .into_owned(),
+                delays,
+            ),
+        }
+    }
+}
+
+/// ```
+/// use image::{ImageBuffer, Luma};
+/// use nalgebra::Matrix3;
+/// use nshare::{DepthCamera, IntoNdarray2Points};
+///
+/// let camera = DepthCamera::new(Matrix3::identity(), 0.001);
+/// let mut depth = ImageBuffer::<Luma<u16>, _>::new(3, 2);
+/// depth[(2, 1)] = Luma([500]);
+/// let points = depth.into_ndarray2_points(&camera);
+/// assert_eq!(points, ndarray::array![[1.0, 0.5, 0.5]]);
+/// ```
+#[cfg(feature = "nalgebra")]
+impl<T, Container> IntoNdarray2Points for &ImageBuffer<Luma<T>, Container>
+where
+    T: Primitive + Into<f32> + 'static,
+    Container: Deref<Target = [T]>,
+{
+    type Out = Array2<f32>;
+
+    fn into_ndarray2_points(self, camera: &crate::DepthCamera) -> Self::Out {
+        self.as_ndarray2().into_ndarray2_points(camera)
+    }
+}
+
+/// ```
+/// use image::{Rgb, RgbImage};
+/// use nalgebra::Matrix3;
+/// use nshare::{DepthCamera, IntoNdarray2PointsColored};
+///
+/// let camera = DepthCamera::new(Matrix3::identity(), 1.0);
+/// let depth = ndarray::array![[0.0f32, 2.0]];
+/// let colors = RgbImage::from_fn(2, 1, |x, _| Rgb([255, 0, 51 * x as u8]));
+/// let points = depth.view().into_ndarray2_points_colored(&camera, &colors);
+/// assert_eq!(points, ndarray::array![[2.0, 0.0, 2.0, 1.0, 0.0, 0.2]]);
+/// ```
+#[cfg(feature = "nalgebra")]
+impl<T> IntoNdarray2PointsColored for ArrayView2<'_, T>
+where
+    T: Copy + Into<f32>,
+{
+    type Out = Array2<f32>;
+
+    fn into_ndarray2_points_colored(
+        self,
+        camera: &crate::DepthCamera,
+        colors: &image::RgbImage,
+    ) -> Self::Out {
+        let (width, height) = colors.dimensions();
+        assert_eq!(
+            (height as usize, width as usize),
+            self.dim(),
+            "the colors must have the dimensions of the depth map"
+        );
+        super::depth_impl::back_project(self, camera, 6, |data, row, col| {
+            let pixel = colors.get_pixel(col as u32, row as u32);
+            data.extend(pixel.0.map(|channel| f32::from(channel) / 255.0));
+        })
+    }
+}
+
+/// ```
+/// use image::{ImageBuffer, Luma, RgbImage};
+/// use nalgebra::Matrix3;
+/// use nshare::{DepthCamera, IntoNdarray2PointsColored};
+///
+/// let camera = DepthCamera::new(Matrix3::identity(), 0.001);
+/// let depth = ImageBuffer::<Luma<u16>, _>::from_pixel(4, 3, Luma([1000]));
+/// let colors = RgbImage::new(4, 3);
+/// let points = depth.into_ndarray2_points_colored(&camera, &colors);
+/// assert_eq!(points.dim(), (12, 6));
+/// ```
+#[cfg(feature = "nalgebra")]
+impl<T, Container> IntoNdarray2PointsColored for &ImageBuffer<Luma<T>, Container>
+where
+    T: Primitive + Into<f32> + 'static,
+    Container: Deref<Target = [T]>,
+{
+    type Out = Array2<f32>;
+
+    fn into_ndarray2_points_colored(
+        self,
+        camera: &crate::DepthCamera,
+        colors: &image::RgbImage,
+    ) -> Self::Out {
+        self.as_ndarray2()
+            .into_ndarray2_points_colored(camera, colors)
+    }
+}
+
+/// ```
+/// use image::RgbImage;
+/// use nalgebra::{DMatrix, Matrix3};
+/// use nshare::{DepthCamera, IntoNdarray2PointsColored};
+///
+/// let camera = DepthCamera::new(Matrix3::identity(), 1.0);
+/// let depth = DMatrix::from_element(2, 3, 1.0f32);
+/// let points = depth.into_ndarray2_points_colored(&camera, &RgbImage::new(3, 2));
+/// assert_eq!(points.dim(), (6, 6));
+/// ```
+#[cfg(feature = "nalgebra")]
+impl<T, R, C, S> IntoNdarray2PointsColored for &nalgebra::Matrix<T, R, C, S>
+where
+    T: nalgebra::Scalar + Copy + Into<f32>,
+    R: nalgebra::Dim,
+    C: nalgebra::Dim,
+    S: nalgebra::Storage<T, R, C>,
+{
+    type Out = Array2<f32>;
+
+    fn into_ndarray2_points_colored(
+        self,
+        camera: &crate::DepthCamera,
+        colors: &image::RgbImage,
+    ) -> Self::Out {
+        self.as_ndarray2()
+            .into_ndarray2_points_colored(camera, colors)
+    }
+}
@@ -0,0 +1,156 @@
+//! Implementations for imgref types being converted to ndarray types.
+
+use super::*;
+use imgref::{ImgRef, ImgRefMut, ImgVec};
+use ndarray::{ArrayView2, ArrayView3, ArrayViewMut2, ArrayViewMut3, ShapeBuilder};
+
+/// ```
+/// use imgref::ImgVec;
+/// use nshare::IntoNdarray2;
+/// use ndarray::s;
+///
+/// let img = ImgVec::new(vec![0u8, 1, 2, 3, 4, 5, 6, 7], 4, 2);
+/// // Views of part of an image skip the rest of each row using the stride.
+/// let arr = img.sub_image(1, 0, 2, 2).into_ndarray2();
+/// // ndarray uses (row, col), so the dims get flipped.
+/// assert_eq!(arr.dim(), (2, 2));
+/// assert_eq!(arr, ndarray::array![[1, 2], [5, 6]]);
+/// ```
+impl<'a, T> IntoNdarray2 for ImgRef<'a, T> {
+    type Out = ArrayView2<'a, T>;
+
+    fn into_ndarray2(self) -> Self::Out {
+        let shape = (self.height(), self.width()).strides((self.stride(), 1));
+        ArrayView2::from_shape(shape, self.buf()).unwrap()
+    }
+}
+
+/// ```
+/// use imgref::ImgVec;
+/// use nshare::IntoNdarray2;
+///
+/// let mut img = ImgVec::new(vec![0u8; 8], 4, 2);
+/// img.sub_image_mut(1, 0, 2, 2).into_ndarray2().fill(255);
+/// assert_eq!(img.buf(), &[0, 255, 255, 0, 0, 255, 255, 0]);
+/// ```
+impl<'a, T> IntoNdarray2 for ImgRefMut<'a, T> {
+    type Out = ArrayViewMut2<'a, T>;
+
+    fn into_ndarray2(self) -> Self::Out {
+        let shape = (self.height(), self.width()).strides((self.stride(), 1));
+        ArrayViewMut2::from_shape(shape, self.into_buf()).unwrap()
+    }
+}
+
+/// ```
+/// use imgref::ImgVec;
+/// use nshare::AsNdarray2;
+///
+/// let img = ImgVec::new(vec![0u8, 1, 2, 3, 4, 5], 3, 2);
+/// let arr = img.as_ndarray2();
+/// assert_eq!(arr, ndarray::array![[0, 1, 2], [3, 4, 5]]);
+/// ```
+impl<T> AsNdarray2 for ImgVec<T> {
+    type Out<'a> = ArrayView2<'a, T>
+    where
+        T: 'a;
+
+    fn as_ndarray2(&self) -> Self::Out<'_> {
+        self.as_ref().into_ndarray2()
+    }
+}
+
+/// ```
+/// use imgref::ImgVec;
+/// use nshare::AsNdarray2Mut;
+///
+/// let mut img = ImgVec::new(vec![0u8; 6], 3, 2);
+/// img.as_ndarray2_mut()[(1, 0)] = 255;
+/// assert_eq!(img.buf(), &[0, 0, 0, 255, 0, 0]);
+/// ```
+impl<T> AsNdarray2Mut for ImgVec<T> {
+    type Out<'a> = ArrayViewMut2<'a, T>
+    where
+        T: 'a;
+
+    fn as_ndarray2_mut(&mut self) -> Self::Out<'_> {
+        self.as_mut().into_ndarray2()
+    }
+}
+
+/// ```
+/// use imgref::ImgVec;
+/// use nshare::IntoNdarray3;
+/// use ndarray::s;
+///
+/// let img = ImgVec::new(vec![[0u8, 1, 2], [3, 4, 5], [6, 7, 8], [9, 10, 11]], 2, 2);
+/// let arr = img.sub_image(1, 0, 1, 2).into_ndarray3();
+/// // ndarray uses (channel, row, col), so the dims get flipped.
+/// assert_eq!(arr.dim(), (3, 2, 1));
+/// assert!(arr.slice(s![.., 0, 0]).iter().eq(&[3, 4, 5]));
+/// assert!(arr.slice(s![1, .., 0]).iter().eq(&[4, 10]));
+/// ```
+impl<'a, T, const N: usize> IntoNdarray3 for ImgRef<'a, [T; N]> {
+    type Out = ArrayView3<'a, T>;
+
+    fn into_ndarray3(self) -> Self::Out {
+        let shape = (N, self.height(), self.width()).strides((1, self.stride() * N, N));
+        ArrayView3::from_shape(shape, self.buf().as_flattened()).unwrap()
+    }
+}
+
+/// ```
+/// use imgref::ImgVec;
+/// use nshare::IntoNdarray3;
+/// use ndarray::s;
+///
+/// let mut img = ImgVec::new(vec![[0u8; 3]; 4], 2, 2);
+/// // Set the green channel of the right column.
+/// img.sub_image_mut(1, 0, 1, 2).into_ndarray3().slice_mut(s![1, .., ..]).fill(255);
+/// assert_eq!(img.buf(), &[[0, 0, 0], [0, 255, 0], [0, 0, 0], [0, 255, 0]]);
+/// ```
+impl<'a, T, const N: usize> IntoNdarray3 for ImgRefMut<'a, [T; N]> {
+    type Out = ArrayViewMut3<'a, T>;
+
+    fn into_ndarray3(self) -> Self::Out {
+        let shape = (N, self.height(), self.width()).strides((1, self.stride() * N, N));
+        ArrayViewMut3::from_shape(shape, self.into_buf().as_flattened_mut()).unwrap()
+    }
+}
+
+/// ```
+/// use imgref::ImgVec;
+/// use nshare::AsNdarray3;
+///
+/// let img = ImgVec::new(vec![[0u8, 1, 2], [3, 4, 5]], 2, 1);
+/// let arr = img.as_ndarray3();
+/// assert_eq!(arr.dim(), (3, 1, 2));
+/// assert_eq!(arr[(2, 0, 1)], 5);
+/// ```
+impl<T, const N: usize> AsNdarray3 for ImgVec<[T; N]> {
+    type Out<'a> = ArrayView3<'a, T>
+    where
+        T: 'a;
+
+    fn as_ndarray3(&self) -> Self::Out<'_> {
+        self.as_ref().into_ndarray3()
+    }
+}
+
+/// ```
+/// use imgref::ImgVec;
+/// use nshare::AsNdarray3Mut;
+///
+/// let mut img = ImgVec::new(vec![[0u8; 3]; 2], 2, 1);
+/// img.as_ndarray3_mut()[(2, 0, 1)] = 255;
+/// assert_eq!(img.buf(), &[[0, 0, 0], [0, 0, 255]]);
+/// ```
+impl<T, const N: usize> AsNdarray3Mut for ImgVec<[T; N]> {
+    type Out<'a> = ArrayViewMut3<'a, T>
+    where
+        T: 'a;
+
+    fn as_ndarray3_mut(&mut self) -> Self::Out<'_> {
+        self.as_mut().into_ndarray3()
+    }
+}
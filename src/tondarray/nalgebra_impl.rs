@@ -1,8 +1,18 @@
 //! Implementations for nalgebra types being converted to ndarray types.
 
 use super::*;
-use nalgebra::{dimension::U1, Dim, Dyn, Scalar};
+use crate::points::{
+    matrix_elements, matrix_elements_mut, point_coords, point_coords_mut, vector_coords,
+    vector_coords_mut,
+};
+use crate::{Batch, Col, Labeled, LabeledArray, LabeledView, LabeledViewMut, Row};
+use nalgebra::{dimension::U1, Dim, Dyn, Point, SMatrix, SVector, Scalar};
 use ndarray::ShapeBuilder;
+use ndarray::{Array3, ArrayView2, ArrayView3, ArrayViewMut2, ArrayViewMut3};
+
+extern crate alloc;
+
+use alloc::vec::Vec;
 
 /// ```
 /// use nshare::AsNdarray1;
@@ -279,3 +289,286 @@ where
             .unwrap()
     }
 }
+
+/// Points are viewed in `(point, coordinate)` without copying.
+///
+/// ```
+/// use nalgebra::Point3;
+/// use nshare::IntoNdarray2;
+///
+/// let points = vec![Point3::new(1.0f32, 2.0, 3.0), Point3::new(4.0, 5.0, 6.0)];
+/// let arr = points.as_slice().into_ndarray2();
+/// assert_eq!(arr, ndarray::array![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+/// assert_eq!(arr.mean_axis(ndarray::Axis(0)).unwrap(), ndarray::array![2.5, 3.5, 4.5]);
+/// ```
+impl<'a, T, const D: usize> IntoNdarray2 for &'a [Point<T, D>]
+where
+    T: Scalar,
+{
+    type Out = ArrayView2<'a, T>;
+
+    fn into_ndarray2(self) -> Self::Out {
+        point_coords(self).into_ndarray2()
+    }
+}
+
+/// ```
+/// use nalgebra::Point3;
+/// use nshare::IntoNdarray2;
+///
+/// let mut points = vec![Point3::new(1.0f32, 2.0, 3.0); 4];
+/// // Flatten the cloud onto the ground.
+/// points.as_mut_slice().into_ndarray2().column_mut(2).fill(0.0);
+/// assert_eq!(points[3], Point3::new(1.0, 2.0, 0.0));
+/// ```
+impl<'a, T, const D: usize> IntoNdarray2 for &'a mut [Point<T, D>]
+where
+    T: Scalar,
+{
+    type Out = ArrayViewMut2<'a, T>;
+
+    fn into_ndarray2(self) -> Self::Out {
+        point_coords_mut(self).into_ndarray2()
+    }
+}
+
+/// Vectors are viewed in `(vector, coordinate)` without copying.
+///
+/// ```
+/// use nalgebra::Vector2;
+/// use nshare::IntoNdarray2;
+///
+/// let vectors = [Vector2::new(1, 2), Vector2::new(3, 4), Vector2::new(5, 6)];
+/// let arr = vectors.as_slice().into_ndarray2();
+/// assert_eq!(arr.dim(), (3, 2));
+/// assert_eq!(arr.column(1), ndarray::array![2, 4, 6]);
+/// ```
+impl<'a, T, const D: usize> IntoNdarray2 for &'a [SVector<T, D>]
+where
+    T: Scalar,
+{
+    type Out = ArrayView2<'a, T>;
+
+    fn into_ndarray2(self) -> Self::Out {
+        vector_coords(self).into_ndarray2()
+    }
+}
+
+/// ```
+/// use nalgebra::Vector2;
+/// use nshare::IntoNdarray2;
+///
+/// let mut vectors = [Vector2::new(1, 2), Vector2::new(3, 4)];
+/// vectors.as_mut_slice().into_ndarray2()[(1, 0)] = 0;
+/// assert_eq!(vectors[1], Vector2::new(0, 4));
+/// ```
+impl<'a, T, const D: usize> IntoNdarray2 for &'a mut [SVector<T, D>]
+where
+    T: Scalar,
+{
+    type Out = ArrayViewMut2<'a, T>;
+
+    fn into_ndarray2(self) -> Self::Out {
+        vector_coords_mut(self).into_ndarray2()
+    }
+}
+
+/// Matrices are viewed in `(matrix, row, col)` without copying, so each matrix stays column-major.
+///
+/// ```
+/// use nalgebra::Matrix2x3;
+/// use nshare::IntoNdarray3;
+///
+/// let matrices = [Matrix2x3::new(1, 2, 3, 4, 5, 6), Matrix2x3::zeros()];
+/// let arr = matrices.as_slice().into_ndarray3();
+/// assert_eq!(arr.dim(), (2, 2, 3));
+/// assert_eq!(arr.strides(), &[6, 1, 2]);
+/// assert_eq!(arr.slice(ndarray::s![0, 1, ..]), ndarray::array![4, 5, 6]);
+/// ```
+impl<'a, T, const R: usize, const C: usize> IntoNdarray3 for &'a [SMatrix<T, R, C>]
+where
+    T: Scalar,
+{
+    type Out = ArrayView3<'a, T>;
+
+    fn into_ndarray3(self) -> Self::Out {
+        let shape = (self.len(), R, C).strides((R * C, 1, R));
+        ArrayView3::from_shape(shape, matrix_elements(self)).unwrap()
+    }
+}
+
+/// ```
+/// use nalgebra::Matrix3;
+/// use nshare::IntoNdarray3;
+///
+/// let mut covariances = vec![Matrix3::<f64>::identity(); 10];
+/// // Inflate the variance of the first axis of every matrix at once.
+/// covariances.as_mut_slice().into_ndarray3().slice_mut(ndarray::s![.., 0, 0]).fill(4.0);
+/// assert_eq!(covariances[9][(0, 0)], 4.0);
+/// ```
+impl<'a, T, const R: usize, const C: usize> IntoNdarray3 for &'a mut [SMatrix<T, R, C>]
+where
+    T: Scalar,
+{
+    type Out = ArrayViewMut3<'a, T>;
+
+    fn into_ndarray3(self) -> Self::Out {
+        let shape = (self.len(), R, C).strides((R * C, 1, R));
+        ArrayViewMut3::from_shape(shape, matrix_elements_mut(self)).unwrap()
+    }
+}
+
+/// The matrices are copied into an array in `(matrix, row, col)` and standard layout.
+///
+/// ```
+/// use nalgebra::Matrix2;
+/// use nshare::IntoNdarray3;
+///
+/// let matrices = vec![Matrix2::new(1, 2, 3, 4); 3];
+/// let arr = matrices.into_ndarray3();
+/// assert!(arr.is_standard_layout());
+/// assert_eq!(arr.slice(ndarray::s![2, .., ..]), ndarray::array![[1, 2], [3, 4]]);
+/// ```
+impl<T, const R: usize, const C: usize> IntoNdarray3 for Vec<SMatrix<T, R, C>>
+where
+    T: Scalar,
+{
+    type Out = Array3<T>;
+
+    fn into_ndarray3(self) -> Self::Out {
+        Array3::from_shape_fn((self.len(), R, C), |(i, row, col)| {
+            self[i][(row, col)].clone()
+        })
+    }
+}
+
+/// The matrix is viewed in `(Row, Col)`.
+///
+/// ```
+/// use nalgebra::Matrix2x3;
+/// use nshare::{AsNdarrayLabeled, Col};
+///
+/// let m = Matrix2x3::new(1, 2, 3, 4, 5, 6);
+/// let labeled = m.as_ndarray_labeled();
+/// assert_eq!(labeled.len_of::<Col>(), 3);
+/// // `(x, y)` is `(col, row)`, unlike the index of the matrix.
+/// assert_eq!(labeled.get((2, 0)), Some(&m[(0, 2)]));
+/// ```
+impl<N: Scalar, R: Dim, C: Dim, S> AsNdarrayLabeled for nalgebra::Matrix<N, R, C, S>
+where
+    S: nalgebra::Storage<N, R, C>,
+{
+    type Out<'a> = LabeledView<'a, N, (Row, Col)>
+    where
+        S: 'a;
+
+    fn as_ndarray_labeled(&self) -> Self::Out<'_> {
+        Labeled::new(self.as_ndarray2())
+    }
+}
+
+/// ```
+/// use nalgebra::{Matrix2, Point2};
+/// use nshare::AsNdarrayLabeledMut;
+///
+/// let mut m = Matrix2::<f32>::zeros();
+/// *m.as_ndarray_labeled_mut().get_mut(Point2::new(1, 0)).unwrap() = 1.0;
+/// assert_eq!(m, Matrix2::new(0.0, 1.0, 0.0, 0.0));
+/// ```
+impl<N: Scalar, R: Dim, C: Dim, S> AsNdarrayLabeledMut for nalgebra::Matrix<N, R, C, S>
+where
+    S: nalgebra::StorageMut<N, R, C>,
+{
+    type Out<'a> = LabeledViewMut<'a, N, (Row, Col)>
+    where
+        S: 'a;
+
+    fn as_ndarray_labeled_mut(&mut self) -> Self::Out<'_> {
+        Labeled::new(self.as_ndarray2_mut())
+    }
+}
+
+/// The matrix is moved into an array in `(Row, Col)`.
+///
+/// ```
+/// use nalgebra::DMatrix;
+/// use nshare::{IntoNdarrayLabeled, Row};
+///
+/// let m = DMatrix::<u8>::zeros(3, 4);
+/// assert_eq!(m.into_ndarray_labeled().len_of::<Row>(), 3);
+/// ```
+impl<N: Scalar> IntoNdarrayLabeled
+    for nalgebra::Matrix<N, Dyn, Dyn, nalgebra::VecStorage<N, Dyn, Dyn>>
+where
+    nalgebra::DefaultAllocator:
+        nalgebra::allocator::Allocator<Dyn, Dyn, Buffer<N> = nalgebra::VecStorage<N, Dyn, Dyn>>,
+{
+    type Out = LabeledArray<N, (Row, Col)>;
+
+    fn into_ndarray_labeled(self) -> Self::Out {
+        Labeled::new(self.into_ndarray2())
+    }
+}
+
+/// The matrices are viewed in `(Batch, Row, Col)` without copying.
+///
+/// ```
+/// use nalgebra::Matrix2x3;
+/// use nshare::{Batch, IntoNdarrayLabeled};
+///
+/// let matrices = [Matrix2x3::new(1, 2, 3, 4, 5, 6), Matrix2x3::zeros()];
+/// let labeled = matrices.as_slice().into_ndarray_labeled();
+/// assert_eq!(labeled.len_of::<Batch>(), 2);
+/// // The same entry of every matrix.
+/// assert_eq!(labeled.pixel((2, 1)).unwrap().to_vec(), [6, 0]);
+/// ```
+impl<'a, T, const R: usize, const C: usize> IntoNdarrayLabeled for &'a [SMatrix<T, R, C>]
+where
+    T: Scalar,
+{
+    type Out = LabeledView<'a, T, (Batch, Row, Col)>;
+
+    fn into_ndarray_labeled(self) -> Self::Out {
+        Labeled::new(self.into_ndarray3())
+    }
+}
+
+/// ```
+/// use nalgebra::Matrix2;
+/// use nshare::IntoNdarrayLabeled;
+///
+/// let mut matrices = vec![Matrix2::<f32>::identity(); 4];
+/// matrices.as_mut_slice().into_ndarray_labeled().pixel_mut((1, 0)).unwrap().fill(2.0);
+/// assert_eq!(matrices[3], Matrix2::new(1.0, 2.0, 0.0, 1.0));
+/// ```
+impl<'a, T, const R: usize, const C: usize> IntoNdarrayLabeled for &'a mut [SMatrix<T, R, C>]
+where
+    T: Scalar,
+{
+    type Out = LabeledViewMut<'a, T, (Batch, Row, Col)>;
+
+    fn into_ndarray_labeled(self) -> Self::Out {
+        Labeled::new(self.into_ndarray3())
+    }
+}
+
+/// The matrices are copied into an array in `(Batch, Row, Col)` and standard layout.
+///
+/// ```
+/// use nalgebra::Matrix2;
+/// use nshare::{Batch, Col, IntoNdarrayLabeled, Row};
+///
+/// let matrices = vec![Matrix2::new(1, 2, 3, 4); 3];
+/// let labeled = matrices.into_ndarray_labeled().permute_to::<(Row, Col, Batch)>();
+/// assert_eq!(labeled.as_array().dim(), (2, 2, 3));
+/// ```
+impl<T, const R: usize, const C: usize> IntoNdarrayLabeled for Vec<SMatrix<T, R, C>>
+where
+    T: Scalar,
+{
+    type Out = LabeledArray<T, (Batch, Row, Col)>;
+
+    fn into_ndarray_labeled(self) -> Self::Out {
+        Labeled::new(self.into_ndarray3())
+    }
+}
@@ -0,0 +1,59 @@
+//! Implementations for nalgebra-sparse types being converted to ndarray types.
+
+use super::*;
+use nalgebra_sparse::{CscMatrix, CsrMatrix};
+use ndarray::Array2;
+use num_traits::Zero;
+
+/// ```
+/// use nshare::IntoNdarray2;
+/// use nalgebra_sparse::CsrMatrix;
+///
+/// let m = CsrMatrix::try_from_csr_data(2, 3, vec![0, 1, 3], vec![2, 0, 1], vec![1.0, 2.0, 3.0]).unwrap();
+/// let arr = m.into_ndarray2();
+/// assert_eq!(arr, ndarray::array![
+///     [0.0, 0.0, 1.0],
+///     [2.0, 3.0, 0.0],
+/// ]);
+/// ```
+impl<T> IntoNdarray2 for &CsrMatrix<T>
+where
+    T: Clone + Zero,
+{
+    type Out = Array2<T>;
+
+    fn into_ndarray2(self) -> Self::Out {
+        let mut arr = Array2::zeros((self.nrows(), self.ncols()));
+        for (row, col, value) in self.triplet_iter() {
+            arr[(row, col)] = value.clone();
+        }
+        arr
+    }
+}
+
+/// ```
+/// use nshare::IntoNdarray2;
+/// use nalgebra_sparse::CscMatrix;
+///
+/// let m = CscMatrix::try_from_csc_data(3, 2, vec![0, 1, 3], vec![2, 0, 1], vec![1.0, 2.0, 3.0]).unwrap();
+/// let arr = m.into_ndarray2();
+/// assert_eq!(arr, ndarray::array![
+///     [0.0, 2.0],
+///     [0.0, 3.0],
+///     [1.0, 0.0],
+/// ]);
+/// ```
+impl<T> IntoNdarray2 for &CscMatrix<T>
+where
+    T: Clone + Zero,
+{
+    type Out = Array2<T>;
+
+    fn into_ndarray2(self) -> Self::Out {
+        let mut arr = Array2::zeros((self.nrows(), self.ncols()));
+        for (row, col, value) in self.triplet_iter() {
+            arr[(row, col)] = value.clone();
+        }
+        arr
+    }
+}
@@ -0,0 +1,43 @@
+//! Implementations for packed pixel formats being converted to ndarray types.
+
+use super::*;
+use crate::{PackedImage, PackedRawImage};
+use ndarray::{Array2, Array3};
+
+/// The output has red, green and blue channels, and an alpha channel if the format stores alpha.
+///
+/// ```
+/// use nshare::{IntoNdarray3, PackedFormat, PackedImage};
+///
+/// let data = [0xf800u16, 0x07e0, 0x001f];
+/// let arr = PackedImage::new(&data, 3, 1, PackedFormat::Rgb565).unwrap().into_ndarray3();
+/// // ndarray uses (channel, row, col), so the dims get flipped.
+/// assert_eq!(arr.dim(), (3, 1, 3));
+/// assert_eq!(arr.slice(ndarray::s![.., 0, ..]), ndarray::array![[255, 0, 0], [0, 255, 0], [0, 0, 255]]);
+/// ```
+impl IntoNdarray3 for PackedImage<'_> {
+    type Out = Array3<u8>;
+
+    fn into_ndarray3(self) -> Self::Out {
+        let channels = if self.format().has_alpha() { 4 } else { 3 };
+        Array3::from_shape_fn(
+            (channels, self.height(), self.width()),
+            |(channel, y, x)| self.pixel(x, y)[channel],
+        )
+    }
+}
+
+/// ```
+/// use nshare::{IntoNdarray2, PackedRawImage, RawPacking};
+///
+/// let data = [0xff, 0x00, 0x80, 0x40, 0b00_00_01_11];
+/// let arr = PackedRawImage::new(&data, 4, 1, RawPacking::Raw10).unwrap().into_ndarray2();
+/// assert_eq!(arr, ndarray::array![[0x3ff, 0x001, 0x200, 0x100]]);
+/// ```
+impl IntoNdarray2 for PackedRawImage<'_> {
+    type Out = Array2<u16>;
+
+    fn into_ndarray2(self) -> Self::Out {
+        Array2::from_shape_fn((self.height(), self.width()), |(y, x)| self.sample(x, y))
+    }
+}
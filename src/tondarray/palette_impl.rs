@@ -0,0 +1,201 @@
+//! Implementations for palette types being converted to ndarray types.
+
+use super::*;
+use ndarray::{
+    ArrayBase, ArrayView, ArrayView2, ArrayViewMut, ArrayViewMut2, Axis, Dimension, RawData,
+    ShapeBuilder,
+};
+use palette::cast::{self, ArrayCast};
+use palette::ArrayExt;
+
+/// ```
+/// use nshare::IntoNdarrayComponents;
+/// use palette::LinSrgb;
+///
+/// let colors = vec![LinSrgb::new(0.0f32, 0.5, 1.0), LinSrgb::new(1.0, 0.0, 0.25)];
+/// let arr = colors.as_slice().into_ndarray_components();
+/// assert_eq!(arr, ndarray::array![[0.0, 0.5, 1.0], [1.0, 0.0, 0.25]]);
+/// ```
+impl<'a, C> IntoNdarrayComponents for &'a [C]
+where
+    C: ArrayCast,
+{
+    type Out = ArrayView2<'a, <C::Array as ArrayExt>::Item>;
+
+    fn into_ndarray_components(self) -> Self::Out {
+        let components = <C::Array as ArrayExt>::LENGTH;
+        ArrayView2::from_shape((self.len(), components), cast::into_component_slice(self)).unwrap()
+    }
+}
+
+/// ```
+/// use nshare::IntoNdarrayComponents;
+/// use palette::LinSrgb;
+///
+/// let mut colors = vec![LinSrgb::new(0.0f32, 0.0, 0.0); 2];
+/// // Set the blue channel of every color.
+/// colors.as_mut_slice().into_ndarray_components().column_mut(2).fill(1.0);
+/// assert_eq!(colors[1], LinSrgb::new(0.0, 0.0, 1.0));
+/// ```
+impl<'a, C> IntoNdarrayComponents for &'a mut [C]
+where
+    C: ArrayCast,
+{
+    type Out = ArrayViewMut2<'a, <C::Array as ArrayExt>::Item>;
+
+    fn into_ndarray_components(self) -> Self::Out {
+        let components = <C::Array as ArrayExt>::LENGTH;
+        ArrayViewMut2::from_shape(
+            (self.len(), components),
+            cast::into_component_slice_mut(self),
+        )
+        .unwrap()
+    }
+}
+
+/// ```
+/// use nshare::IntoNdarrayComponents;
+/// use ndarray::Array2;
+/// use palette::Lab;
+///
+/// let grid: Array2<Lab> = Array2::from_elem((2, 3), Lab::new(50.0, 10.0, -10.0));
+/// let arr = grid.view().into_ndarray_components();
+/// assert_eq!(arr.dim(), (2, 3, 3));
+/// assert!(arr.slice(ndarray::s![1, 2, ..]).iter().eq(&[50.0, 10.0, -10.0]));
+/// ```
+impl<'a, C, D> IntoNdarrayComponents for ArrayView<'a, C, D>
+where
+    C: ArrayCast,
+    D: Dimension,
+{
+    type Out = ArrayView<'a, <C::Array as ArrayExt>::Item, D::Larger>;
+
+    fn into_ndarray_components(mut self) -> Self::Out {
+        let inverted = invert_negative_axes(&mut self);
+        let (shape, strides) = component_shape::<C, _>(&self.raw_dim(), self.strides());
+        // SAFETY: `ArrayCast` guarantees that `C` is laid out as an array of its components, and the strides
+        // of the colors are non-negative after inverting the axes.
+        let mut arr =
+            unsafe { ArrayView::from_shape_ptr(shape.strides(strides), self.as_ptr().cast()) };
+        restore_inverted_axes(&mut arr, &inverted);
+        arr
+    }
+}
+
+/// ```
+/// use nshare::IntoNdarrayComponents;
+/// use ndarray::Array2;
+/// use palette::Lab;
+///
+/// let mut grid: Array2<Lab> = Array2::from_elem((2, 2), Lab::new(0.0, 0.0, 0.0));
+/// // Set the lightness of the whole grid.
+/// grid.view_mut().into_ndarray_components().slice_mut(ndarray::s![.., .., 0]).fill(100.0);
+/// assert_eq!(grid[(1, 1)], Lab::new(100.0, 0.0, 0.0));
+/// ```
+impl<'a, C, D> IntoNdarrayComponents for ArrayViewMut<'a, C, D>
+where
+    C: ArrayCast,
+    D: Dimension,
+{
+    type Out = ArrayViewMut<'a, <C::Array as ArrayExt>::Item, D::Larger>;
+
+    fn into_ndarray_components(mut self) -> Self::Out {
+        let inverted = invert_negative_axes(&mut self);
+        let (shape, strides) = component_shape::<C, _>(&self.raw_dim(), self.strides());
+        // SAFETY: `ArrayCast` guarantees that `C` is laid out as an array of its components, and the strides
+        // of the colors are non-negative after inverting the axes.
+        let mut arr = unsafe {
+            ArrayViewMut::from_shape_ptr(shape.strides(strides), self.as_mut_ptr().cast())
+        };
+        restore_inverted_axes(&mut arr, &inverted);
+        arr
+    }
+}
+
+/// Inverts the axes that have negative strides, returning which ones were inverted.
+fn invert_negative_axes<S, D>(arr: &mut ArrayBase<S, D>) -> D
+where
+    S: RawData,
+    D: Dimension,
+{
+    let mut inverted = D::zeros(arr.ndim());
+    for (axis, flag) in inverted.slice_mut().iter_mut().enumerate() {
+        if arr.stride_of(Axis(axis)) < 0 {
+            arr.invert_axis(Axis(axis));
+            *flag = 1;
+        }
+    }
+    inverted
+}
+
+/// Inverts the leading axes flagged by [`invert_negative_axes`] again, so the colors are in the original order.
+fn restore_inverted_axes<S, D, E>(arr: &mut ArrayBase<S, E>, inverted: &D)
+where
+    S: RawData,
+    D: Dimension,
+    E: Dimension,
+{
+    for (axis, &flag) in inverted.slice().iter().enumerate() {
+        if flag == 1 {
+            arr.invert_axis(Axis(axis));
+        }
+    }
+}
+
+/// The shape and strides of the components of colors with the given shape and non-negative strides.
+fn component_shape<C, D>(dim: &D, strides: &[isize]) -> (D::Larger, D::Larger)
+where
+    C: ArrayCast,
+    D: Dimension,
+{
+    let components = <C::Array as ArrayExt>::LENGTH;
+    let mut shape = D::Larger::zeros(dim.ndim() + 1);
+    let mut component_strides = D::Larger::zeros(dim.ndim() + 1);
+    shape.slice_mut()[..dim.ndim()].copy_from_slice(dim.slice());
+    shape[dim.ndim()] = components;
+    for (out, &stride) in component_strides.slice_mut().iter_mut().zip(strides) {
+        *out = stride as usize * components;
+    }
+    component_strides[dim.ndim()] = 1;
+    (shape, component_strides)
+}
+
+#[cfg(feature = "image")]
+mod image_impl {
+    use super::*;
+    use core::ops::Deref;
+    use image::{ImageBuffer, Rgb};
+    use ndarray::Array2;
+    use palette::{FromColor, Srgb};
+
+    /// The subpixels of the image are treated as non-linear sRGB, so the transfer function is applied
+    /// when converting to linear or perceptual color spaces.
+    ///
+    /// ```
+    /// use image::{Rgb, Rgb32FImage};
+    /// use nshare::IntoNdarray2Color;
+    /// use ndarray::Array2;
+    /// use palette::{LinSrgb, Srgb};
+    ///
+    /// let img = Rgb32FImage::from_pixel(2, 1, Rgb([0.5, 0.0, 1.0]));
+    /// let linear: Array2<LinSrgb> = (&img).into_ndarray2_color();
+    /// assert_eq!(linear.dim(), (1, 2));
+    /// assert_eq!(linear[(0, 1)], Srgb::new(0.5f32, 0.0, 1.0).into_linear());
+    /// ```
+    impl<C, Container> IntoNdarray2Color<C> for &ImageBuffer<Rgb<f32>, Container>
+    where
+        C: FromColor<Srgb<f32>>,
+        Container: Deref<Target = [f32]>,
+    {
+        type Out = Array2<C>;
+
+        fn into_ndarray2_color(self) -> Self::Out {
+            let (width, height) = self.dimensions();
+            let colors = self
+                .pixels()
+                .map(|&Rgb([red, green, blue])| C::from_color(Srgb::new(red, green, blue)))
+                .collect();
+            Array2::from_shape_vec((height as usize, width as usize), colors).unwrap()
+        }
+    }
+}
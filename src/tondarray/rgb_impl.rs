@@ -0,0 +1,212 @@
+//! Implementations for rgb types being converted to ndarray types.
+
+#![allow(deprecated)]
+
+use super::*;
+use ndarray::{ArrayView3, ArrayViewMut3, ShapeBuilder, StrideShape};
+use rgb::{ComponentSlice, Rgb, Rgba};
+
+/// ```
+/// use nshare::{AsNdarray3Pixels, ChannelOrder};
+/// use rgb::Rgb;
+///
+/// let pixels = [Rgb::new(0u8, 1, 2), Rgb::new(3, 4, 5), Rgb::new(6, 7, 8), Rgb::new(9, 10, 11)];
+/// let chw = pixels.as_ndarray3_pixels(2, 2, ChannelOrder::Chw);
+/// assert_eq!(chw.dim(), (3, 2, 2));
+/// assert_eq!(chw[(1, 1, 0)], 7);
+/// let hwc = pixels.as_ndarray3_pixels(2, 2, ChannelOrder::Hwc);
+/// assert_eq!(hwc.dim(), (2, 2, 3));
+/// assert_eq!(hwc[(1, 0, 1)], 7);
+/// ```
+impl<T> AsNdarray3Pixels for [Rgb<T>] {
+    type Out<'a> = ArrayView3<'a, T>
+    where
+        T: 'a;
+
+    fn as_ndarray3_pixels(
+        &self,
+        width: usize,
+        height: usize,
+        order: ChannelOrder,
+    ) -> Self::Out<'_> {
+        let shape = pixel_shape(self.len(), 3, width, height, order);
+        ArrayView3::from_shape(shape, ComponentSlice::as_slice(self)).unwrap()
+    }
+}
+
+/// ```
+/// use nshare::{AsNdarray3PixelsMut, ChannelOrder};
+/// use ndarray::s;
+/// use rgb::Rgb;
+///
+/// let mut pixels = vec![Rgb::new(0u8, 0, 0); 4];
+/// // Fill the red channel of the bottom row.
+/// pixels.as_ndarray3_pixels_mut(2, 2, ChannelOrder::Chw).slice_mut(s![0, 1, ..]).fill(255);
+/// assert_eq!(pixels[2], Rgb::new(255, 0, 0));
+/// assert_eq!(pixels[1], Rgb::new(0, 0, 0));
+/// ```
+impl<T> AsNdarray3PixelsMut for [Rgb<T>] {
+    type Out<'a> = ArrayViewMut3<'a, T>
+    where
+        T: 'a;
+
+    fn as_ndarray3_pixels_mut(
+        &mut self,
+        width: usize,
+        height: usize,
+        order: ChannelOrder,
+    ) -> Self::Out<'_> {
+        let shape = pixel_shape(self.len(), 3, width, height, order);
+        ArrayViewMut3::from_shape(shape, ComponentSlice::as_mut_slice(self)).unwrap()
+    }
+}
+
+/// ```
+/// use nshare::{AsNdarray3Pixels, ChannelOrder};
+/// use rgb::Rgba;
+///
+/// let pixels = [Rgba::new(0u16, 1, 2, 65535), Rgba::new(3, 4, 5, 0)];
+/// let arr = pixels.as_ndarray3_pixels(2, 1, ChannelOrder::Chw);
+/// assert_eq!(arr.dim(), (4, 1, 2));
+/// assert!(arr.slice(ndarray::s![3, 0, ..]).iter().eq(&[65535, 0]));
+/// ```
+impl<T> AsNdarray3Pixels for [Rgba<T>] {
+    type Out<'a> = ArrayView3<'a, T>
+    where
+        T: 'a;
+
+    fn as_ndarray3_pixels(
+        &self,
+        width: usize,
+        height: usize,
+        order: ChannelOrder,
+    ) -> Self::Out<'_> {
+        let shape = pixel_shape(self.len(), 4, width, height, order);
+        ArrayView3::from_shape(shape, ComponentSlice::as_slice(self)).unwrap()
+    }
+}
+
+/// ```
+/// use nshare::{AsNdarray3PixelsMut, ChannelOrder};
+/// use ndarray::s;
+/// use rgb::Rgba;
+///
+/// let mut pixels = vec![Rgba::new(0u16, 0, 0, 0); 2];
+/// pixels.as_ndarray3_pixels_mut(2, 1, ChannelOrder::Hwc).slice_mut(s![.., .., 3]).fill(65535);
+/// assert_eq!(pixels, vec![Rgba::new(0, 0, 0, 65535); 2]);
+/// ```
+impl<T> AsNdarray3PixelsMut for [Rgba<T>] {
+    type Out<'a> = ArrayViewMut3<'a, T>
+    where
+        T: 'a;
+
+    fn as_ndarray3_pixels_mut(
+        &mut self,
+        width: usize,
+        height: usize,
+        order: ChannelOrder,
+    ) -> Self::Out<'_> {
+        let shape = pixel_shape(self.len(), 4, width, height, order);
+        ArrayViewMut3::from_shape(shape, ComponentSlice::as_mut_slice(self)).unwrap()
+    }
+}
+
+/// The shape of `len` interleaved pixels with `channels` components each.
+fn pixel_shape(
+    len: usize,
+    channels: usize,
+    width: usize,
+    height: usize,
+    order: ChannelOrder,
+) -> StrideShape<ndarray::Ix3> {
+    assert_eq!(
+        len,
+        width * height,
+        "the number of pixels must match the dimensions of the image"
+    );
+    match order {
+        ChannelOrder::Chw => (channels, height, width).strides((1, width * channels, channels)),
+        ChannelOrder::Hwc => (height, width, channels).into(),
+    }
+}
+
+#[cfg(feature = "imgref")]
+mod imgref_impl {
+    use super::*;
+    use imgref::{ImgRef, ImgRefMut};
+
+    /// ```
+    /// use imgref::ImgVec;
+    /// use nshare::IntoNdarray3;
+    /// use rgb::Rgb;
+    ///
+    /// let img = ImgVec::new(vec![Rgb::new(0u8, 1, 2), Rgb::new(3, 4, 5)], 2, 1);
+    /// let arr = img.sub_image(1, 0, 1, 1).into_ndarray3();
+    /// // ndarray uses (channel, row, col), so the dims get flipped.
+    /// assert_eq!(arr.dim(), (3, 1, 1));
+    /// assert!(arr.iter().eq(&[3, 4, 5]));
+    /// ```
+    impl<'a, T> IntoNdarray3 for ImgRef<'a, Rgb<T>> {
+        type Out = ArrayView3<'a, T>;
+
+        fn into_ndarray3(self) -> Self::Out {
+            let shape = (3, self.height(), self.width()).strides((1, self.stride() * 3, 3));
+            ArrayView3::from_shape(shape, ComponentSlice::as_slice(*self.buf())).unwrap()
+        }
+    }
+
+    /// ```
+    /// use imgref::ImgVec;
+    /// use nshare::IntoNdarray3;
+    /// use rgb::Rgb;
+    ///
+    /// let mut img = ImgVec::new(vec![Rgb::new(0u8, 0, 0); 2], 2, 1);
+    /// img.sub_image_mut(1, 0, 1, 1).into_ndarray3()[(2, 0, 0)] = 255;
+    /// assert_eq!(img.buf(), &[Rgb::new(0, 0, 0), Rgb::new(0, 0, 255)]);
+    /// ```
+    impl<'a, T> IntoNdarray3 for ImgRefMut<'a, Rgb<T>> {
+        type Out = ArrayViewMut3<'a, T>;
+
+        fn into_ndarray3(self) -> Self::Out {
+            let shape = (3, self.height(), self.width()).strides((1, self.stride() * 3, 3));
+            ArrayViewMut3::from_shape(shape, ComponentSlice::as_mut_slice(self.into_buf())).unwrap()
+        }
+    }
+
+    /// ```
+    /// use imgref::ImgVec;
+    /// use nshare::IntoNdarray3;
+    /// use rgb::Rgba;
+    ///
+    /// let img = ImgVec::new(vec![Rgba::new(0u16, 1, 2, 3), Rgba::new(4, 5, 6, 7)], 1, 2);
+    /// let arr = img.as_ref().into_ndarray3();
+    /// assert_eq!(arr.dim(), (4, 2, 1));
+    /// assert!(arr.slice(ndarray::s![3, .., 0]).iter().eq(&[3, 7]));
+    /// ```
+    impl<'a, T> IntoNdarray3 for ImgRef<'a, Rgba<T>> {
+        type Out = ArrayView3<'a, T>;
+
+        fn into_ndarray3(self) -> Self::Out {
+            let shape = (4, self.height(), self.width()).strides((1, self.stride() * 4, 4));
+            ArrayView3::from_shape(shape, ComponentSlice::as_slice(*self.buf())).unwrap()
+        }
+    }
+
+    /// ```
+    /// use imgref::ImgVec;
+    /// use nshare::IntoNdarray3;
+    /// use rgb::Rgba;
+    ///
+    /// let mut img = ImgVec::new(vec![Rgba::new(0u16, 0, 0, 0); 2], 1, 2);
+    /// img.as_mut().into_ndarray3().slice_mut(ndarray::s![3, .., ..]).fill(65535);
+    /// assert_eq!(img.buf(), &[Rgba::new(0, 0, 0, 65535); 2]);
+    /// ```
+    impl<'a, T> IntoNdarray3 for ImgRefMut<'a, Rgba<T>> {
+        type Out = ArrayViewMut3<'a, T>;
+
+        fn into_ndarray3(self) -> Self::Out {
+            let shape = (4, self.height(), self.width()).strides((1, self.stride() * 4, 4));
+            ArrayViewMut3::from_shape(shape, ComponentSlice::as_mut_slice(self.into_buf())).unwrap()
+        }
+    }
+}
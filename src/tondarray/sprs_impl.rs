@@ -0,0 +1,33 @@
+//! Implementations for sprs types being converted to ndarray types.
+
+use super::*;
+use ndarray::Array2;
+use num_traits::Zero;
+use sprs::CsMat;
+
+/// ```
+/// use nshare::IntoNdarray2;
+/// use sprs::CsMat;
+///
+/// let s = CsMat::new_csc((3, 2), vec![0, 1, 3], vec![2, 0, 1], vec![1.0, 2.0, 3.0]);
+/// let arr = s.into_ndarray2();
+/// assert_eq!(arr, ndarray::array![
+///     [0.0, 2.0],
+///     [0.0, 3.0],
+///     [1.0, 0.0],
+/// ]);
+/// ```
+impl<T> IntoNdarray2 for &CsMat<T>
+where
+    T: Clone + Zero,
+{
+    type Out = Array2<T>;
+
+    fn into_ndarray2(self) -> Self::Out {
+        let mut arr = Array2::zeros(self.shape());
+        for (value, index) in self.iter() {
+            arr[index] = value.clone();
+        }
+        arr
+    }
+}
@@ -0,0 +1,176 @@
+//! Implementations for strided slices being converted to ndarray types.
+
+use super::*;
+use crate::strided::unsigned_layout;
+use crate::{StridedSlice, StridedSliceMut};
+use ndarray::{
+    ArrayBase, ArrayView, ArrayView1, ArrayView2, ArrayView3, ArrayViewD, ArrayViewMut,
+    ArrayViewMut1, ArrayViewMut2, ArrayViewMut3, ArrayViewMutD, Axis, Dimension, Ix1, Ix2, Ix3,
+    IxDyn, RawData, ShapeBuilder,
+};
+
+/// ```
+/// use nshare::{IntoNdarray1, StridedSlice};
+///
+/// let data = [0, 1, 2, 3, 4, 5];
+/// // Every other element, backwards.
+/// let s = StridedSlice::new(&data, [3], [-2], 5).unwrap();
+/// assert_eq!(s.into_ndarray1(), ndarray::array![5, 3, 1]);
+/// ```
+impl<'a, T> IntoNdarray1 for StridedSlice<'a, T, 1> {
+    type Out = ArrayView1<'a, T>;
+
+    fn into_ndarray1(self) -> Self::Out {
+        strided_view::<_, Ix1, 1>(self)
+    }
+}
+
+/// ```
+/// use nshare::{IntoNdarray1, StridedSliceMut};
+///
+/// let mut data = [0; 6];
+/// StridedSliceMut::new(&mut data, [3], [2], 1).unwrap().into_ndarray1().fill(1);
+/// assert_eq!(data, [0, 1, 0, 1, 0, 1]);
+/// ```
+impl<'a, T> IntoNdarray1 for StridedSliceMut<'a, T, 1> {
+    type Out = ArrayViewMut1<'a, T>;
+
+    fn into_ndarray1(self) -> Self::Out {
+        strided_view_mut::<_, Ix1, 1>(self)
+    }
+}
+
+/// ```
+/// use nshare::{IntoNdarray2, StridedSlice};
+///
+/// // A 2x2 region of a 3x4 row-major image, starting at row 1 and column 1.
+/// let data: Vec<u8> = (0..12).collect();
+/// let s = StridedSlice::new(&data, [2, 2], [4, 1], 5).unwrap();
+/// assert_eq!(s.into_ndarray2(), ndarray::array![[5, 6], [9, 10]]);
+/// ```
+impl<'a, T> IntoNdarray2 for StridedSlice<'a, T, 2> {
+    type Out = ArrayView2<'a, T>;
+
+    fn into_ndarray2(self) -> Self::Out {
+        strided_view::<_, Ix2, 2>(self)
+    }
+}
+
+/// ```
+/// use nshare::{IntoNdarray2, StridedSliceMut};
+///
+/// let mut data = [0u8; 6];
+/// // A column-major 2x3 matrix.
+/// let s = StridedSliceMut::new(&mut data, [2, 3], [1, 2], 0).unwrap();
+/// s.into_ndarray2().row_mut(0).fill(1);
+/// assert_eq!(data, [1, 0, 1, 0, 1, 0]);
+/// ```
+impl<'a, T> IntoNdarray2 for StridedSliceMut<'a, T, 2> {
+    type Out = ArrayViewMut2<'a, T>;
+
+    fn into_ndarray2(self) -> Self::Out {
+        strided_view_mut::<_, Ix2, 2>(self)
+    }
+}
+
+/// ```
+/// use nshare::{IntoNdarray3, StridedSlice};
+///
+/// // Planes of 2x2 pixels, with a gap of 2 elements between the planes.
+/// let data: Vec<u8> = (0..10).collect();
+/// let s = StridedSlice::new(&data, [2, 2, 2], [6, 2, 1], 0).unwrap();
+/// let arr = s.into_ndarray3();
+/// assert_eq!(arr[(1, 0, 1)], 7);
+/// ```
+impl<'a, T> IntoNdarray3 for StridedSlice<'a, T, 3> {
+    type Out = ArrayView3<'a, T>;
+
+    fn into_ndarray3(self) -> Self::Out {
+        strided_view::<_, Ix3, 3>(self)
+    }
+}
+
+/// ```
+/// use nshare::{IntoNdarray3, StridedSliceMut};
+///
+/// let mut data = [0u8; 8];
+/// let s = StridedSliceMut::contiguous(&mut data, [2, 2, 2]).unwrap();
+/// s.into_ndarray3()[(1, 1, 1)] = 1;
+/// assert_eq!(data[7], 1);
+/// ```
+impl<'a, T> IntoNdarray3 for StridedSliceMut<'a, T, 3> {
+    type Out = ArrayViewMut3<'a, T>;
+
+    fn into_ndarray3(self) -> Self::Out {
+        strided_view_mut::<_, Ix3, 3>(self)
+    }
+}
+
+/// ```
+/// use nshare::{IntoNdarrayDyn, StridedSlice};
+///
+/// let data = [0u8; 16];
+/// let s = StridedSlice::contiguous(&data, [2, 2, 2, 2]).unwrap();
+/// assert_eq!(s.into_ndarray_dyn().shape(), &[2, 2, 2, 2]);
+/// ```
+impl<'a, T, const N: usize> IntoNdarrayDyn for StridedSlice<'a, T, N> {
+    type Out = ArrayViewD<'a, T>;
+
+    fn into_ndarray_dyn(self) -> Self::Out {
+        strided_view::<_, IxDyn, N>(self)
+    }
+}
+
+/// ```
+/// use nshare::{IntoNdarrayDyn, StridedSliceMut};
+///
+/// let mut data = [0u8; 16];
+/// let s = StridedSliceMut::contiguous(&mut data, [2, 2, 2, 2]).unwrap();
+/// s.into_ndarray_dyn()[[1, 1, 1, 1].as_slice()] = 1;
+/// assert_eq!(data[15], 1);
+/// ```
+impl<'a, T, const N: usize> IntoNdarrayDyn for StridedSliceMut<'a, T, N> {
+    type Out = ArrayViewMutD<'a, T>;
+
+    fn into_ndarray_dyn(self) -> Self::Out {
+        strided_view_mut::<_, IxDyn, N>(self)
+    }
+}
+
+/// Builds a dimension of type `D` from the `N` lengths or strides of a layout.
+fn dim<D: Dimension, const N: usize>(values: [usize; N]) -> D {
+    let mut dim = D::zeros(N);
+    dim.slice_mut().copy_from_slice(&values);
+    dim
+}
+
+/// Inverts the axes that have negative strides in the layout.
+fn invert_negative_axes<S, D>(arr: &mut ArrayBase<S, D>, strides: &[isize])
+where
+    S: RawData,
+    D: Dimension,
+{
+    for (axis, &stride) in strides.iter().enumerate() {
+        if stride < 0 {
+            arr.invert_axis(Axis(axis));
+        }
+    }
+}
+
+fn strided_view<T, D: Dimension, const N: usize>(s: StridedSlice<'_, T, N>) -> ArrayView<'_, T, D> {
+    let (low, strides) = unsigned_layout(&s.shape, &s.strides, s.offset);
+    let shape = dim::<D, N>(s.shape).strides(dim(strides));
+    let mut view = ArrayView::from_shape(shape, &s.data[low..]).unwrap();
+    invert_negative_axes(&mut view, &s.strides);
+    view
+}
+
+fn strided_view_mut<T, D: Dimension, const N: usize>(
+    s: StridedSliceMut<'_, T, N>,
+) -> ArrayViewMut<'_, T, D> {
+    let (low, strides) = unsigned_layout(&s.shape, &s.strides, s.offset);
+    let shape = dim::<D, N>(s.shape).strides(dim(strides));
+    let mut view = ArrayViewMut::from_shape(shape, &mut s.data[low..]).unwrap();
+    invert_negative_axes(&mut view, &s.strides);
+    view
+}
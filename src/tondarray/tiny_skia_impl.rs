@@ -0,0 +1,160 @@
+//! Implementations for tiny-skia types being converted to ndarray types.
+//!
+//! The views hold the premultiplied data as it is stored in the pixmap. For a view in
+//! `(row, col, channel)`, call `.permuted_axes([1, 2, 0])` on the result.
+
+use super::*;
+use ndarray::{Array3, ArrayView3, ArrayViewMut3, Axis, ShapeBuilder};
+use tiny_skia::{Pixmap, PixmapMut, PixmapRef, BYTES_PER_PIXEL};
+
+/// ```
+/// use nshare::IntoNdarray3;
+/// use tiny_skia::{Color, Pixmap};
+///
+/// let mut pixmap = Pixmap::new(3, 2).unwrap();
+/// pixmap.fill(Color::from_rgba8(255, 0, 0, 255));
+/// let arr = pixmap.as_ref().into_ndarray3();
+/// // ndarray uses (channel, row, col), so the dims get flipped.
+/// assert_eq!(arr.dim(), (4, 2, 3));
+/// assert!(arr.slice(ndarray::s![.., 1, 2]).iter().eq(&[255, 0, 0, 255]));
+/// // The same data in (row, col, channel).
+/// let hwc = arr.permuted_axes([1, 2, 0]);
+/// assert!(hwc.slice(ndarray::s![1, 2, ..]).iter().eq(&[255, 0, 0, 255]));
+/// ```
+impl<'a> IntoNdarray3 for PixmapRef<'a> {
+    type Out = ArrayView3<'a, u8>;
+
+    fn into_ndarray3(self) -> Self::Out {
+        let shape = pixmap_shape(self.width(), self.height());
+        ArrayView3::from_shape(shape, self.data()).unwrap()
+    }
+}
+
+/// ```
+/// use nshare::AsNdarray3;
+/// use tiny_skia::{Color, Pixmap};
+///
+/// let mut pixmap = Pixmap::new(2, 2).unwrap();
+/// pixmap.fill(Color::from_rgba8(0, 0, 255, 255));
+/// let arr = pixmap.as_ndarray3();
+/// assert_eq!(arr.dim(), (4, 2, 2));
+/// assert_eq!(arr[(2, 1, 1)], 255);
+/// ```
+impl AsNdarray3 for Pixmap {
+    type Out<'a> = ArrayView3<'a, u8>;
+
+    fn as_ndarray3(&self) -> Self::Out<'_> {
+        self.as_ref().into_ndarray3()
+    }
+}
+
+/// ```
+/// use nshare::AsNdarray3Mut;
+/// use tiny_skia::Pixmap;
+///
+/// let mut pixmap = Pixmap::new(2, 1).unwrap();
+/// // Make the left pixel opaque black.
+/// pixmap.as_ndarray3_mut()[(3, 0, 0)] = 255;
+/// assert_eq!(pixmap.data(), &[0, 0, 0, 255, 0, 0, 0, 0]);
+/// ```
+impl AsNdarray3Mut for Pixmap {
+    type Out<'a> = ArrayViewMut3<'a, u8>;
+
+    fn as_ndarray3_mut(&mut self) -> Self::Out<'_> {
+        let shape = pixmap_shape(self.width(), self.height());
+        ArrayViewMut3::from_shape(shape, self.data_mut()).unwrap()
+    }
+}
+
+/// ```
+/// use nshare::AsNdarray3;
+/// use tiny_skia::{Color, PixmapMut};
+///
+/// let mut data = vec![0u8; 2 * 4];
+/// let mut pixmap = PixmapMut::from_bytes(&mut data, 2, 1).unwrap();
+/// pixmap.fill(Color::from_rgba8(0, 255, 0, 255));
+/// assert!(pixmap.as_ndarray3().slice(ndarray::s![1, 0, ..]).iter().all(|&g| g == 255));
+/// ```
+impl<'p> AsNdarray3 for PixmapMut<'p> {
+    type Out<'a> = ArrayView3<'a, u8>
+    where
+        Self: 'a;
+
+    fn as_ndarray3(&self) -> Self::Out<'_> {
+        self.as_ref().into_ndarray3()
+    }
+}
+
+/// ```
+/// use nshare::AsNdarray3Mut;
+/// use tiny_skia::PixmapMut;
+///
+/// let mut data = vec![0u8; 2 * 4];
+/// let mut pixmap = PixmapMut::from_bytes(&mut data, 2, 1).unwrap();
+/// pixmap.as_ndarray3_mut().slice_mut(ndarray::s![3, .., ..]).fill(255);
+/// assert_eq!(data, [0, 0, 0, 255, 0, 0, 0, 255]);
+/// ```
+impl<'p> AsNdarray3Mut for PixmapMut<'p> {
+    type Out<'a> = ArrayViewMut3<'a, u8>
+    where
+        Self: 'a;
+
+    fn as_ndarray3_mut(&mut self) -> Self::Out<'_> {
+        let shape = pixmap_shape(self.width(), self.height());
+        ArrayViewMut3::from_shape(shape, self.data_mut()).unwrap()
+    }
+}
+
+/// ```
+/// use nshare::IntoNdarray3Demultiplied;
+/// use tiny_skia::{ColorU8, Pixmap};
+///
+/// let mut pixmap = Pixmap::new(2, 1).unwrap();
+/// pixmap.pixels_mut()[0] = ColorU8::from_rgba(255, 0, 0, 51).premultiply();
+/// let arr = pixmap.as_ref().into_ndarray3_demultiplied();
+/// assert_eq!(arr.dim(), (4, 1, 2));
+/// assert_eq!(arr[(0, 0, 0)], 1.0);
+/// assert_eq!(arr[(3, 0, 0)], 0.2);
+/// // Transparent pixels have no color.
+/// assert!(arr.slice(ndarray::s![.., 0, 1]).iter().all(|&c| c == 0.0));
+/// ```
+impl IntoNdarray3Demultiplied for PixmapRef<'_> {
+    type Out = Array3<f32>;
+
+    fn into_ndarray3_demultiplied(self) -> Self::Out {
+        let mut arr = Array3::zeros((4, self.height() as usize, self.width() as usize));
+        for (mut out, pixel) in arr.lanes_mut(Axis(0)).into_iter().zip(self.pixels()) {
+            let alpha = pixel.alpha();
+            if alpha != 0 {
+                let demultiply = |c: u8| (c as f32 / alpha as f32).min(1.0);
+                out[0] = demultiply(pixel.red());
+                out[1] = demultiply(pixel.green());
+                out[2] = demultiply(pixel.blue());
+                out[3] = alpha as f32 / 255.0;
+            }
+        }
+        arr
+    }
+}
+
+/// ```
+/// use nshare::IntoNdarray3Demultiplied;
+/// use tiny_skia::{Color, Pixmap};
+///
+/// let mut pixmap = Pixmap::new(1, 1).unwrap();
+/// pixmap.fill(Color::from_rgba8(0, 0, 255, 255));
+/// assert!((&pixmap).into_ndarray3_demultiplied().iter().eq(&[0.0, 0.0, 1.0, 1.0]));
+/// ```
+impl IntoNdarray3Demultiplied for &Pixmap {
+    type Out = Array3<f32>;
+
+    fn into_ndarray3_demultiplied(self) -> Self::Out {
+        self.as_ref().into_ndarray3_demultiplied()
+    }
+}
+
+/// The shape of the premultiplied RGBA data of a pixmap in `(channel, row, col)`.
+fn pixmap_shape(width: u32, height: u32) -> ndarray::StrideShape<ndarray::Ix3> {
+    let (width, height) = (width as usize, height as usize);
+    (BYTES_PER_PIXEL, height, width).strides((1, width * BYTES_PER_PIXEL, BYTES_PER_PIXEL))
+}
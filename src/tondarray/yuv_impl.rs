@@ -0,0 +1,132 @@
+//! Implementations for YUV frames being converted to ndarray types.
+
+use super::*;
+use crate::{YuvFormat, YuvFrame, YuvPlane};
+use ndarray::{Array3, ArrayView2, ArrayView3, ShapeBuilder};
+
+/// Views of the planes of a frame.
+impl<B> YuvFrame<B>
+where
+    B: AsRef<[u8]>,
+{
+    /// Views the luma of every pixel, in (row, col).
+    ///
+    /// ```
+    /// use nshare::{YuvFormat, YuvFrame};
+    ///
+    /// let frame = YuvFrame::new([16, 128, 235, 128], YuvFormat::Yuyv, 2, 1).unwrap();
+    /// assert_eq!(frame.y_view(), ndarray::array![[16, 235]]);
+    /// ```
+    pub fn y_view(&self) -> ArrayView2<'_, u8> {
+        let (samples, rows, bytes) = self.format().plane_dims(0, self.width(), self.height());
+        self.sample_view(0, 0, rows, samples, bytes)
+    }
+
+    /// Views the U samples in (row, col), which has fewer rows or columns than the frame when it is subsampled.
+    ///
+    /// ```
+    /// use nshare::{YuvFormat, YuvFrame};
+    ///
+    /// // A 2x2 NV12 frame has a single pair of chroma samples.
+    /// let frame = YuvFrame::new([0, 0, 0, 0, 100, 200], YuvFormat::Nv12, 2, 2).unwrap();
+    /// assert_eq!(frame.u_view(), ndarray::array![[100]]);
+    /// ```
+    pub fn u_view(&self) -> ArrayView2<'_, u8> {
+        self.chroma_view(0)
+    }
+
+    /// Views the V samples in (row, col), which has fewer rows or columns than the frame when it is subsampled.
+    ///
+    /// ```
+    /// use nshare::{YuvFormat, YuvFrame};
+    ///
+    /// let frame = YuvFrame::new([0, 0, 0, 0, 100, 200], YuvFormat::Nv12, 2, 2).unwrap();
+    /// assert_eq!(frame.v_view(), ndarray::array![[200]]);
+    /// ```
+    pub fn v_view(&self) -> ArrayView2<'_, u8> {
+        self.chroma_view(1)
+    }
+
+    /// Views the bytes of `plane` as they are laid out, in `(channel, row, col)`.
+    ///
+    /// Planar formats have one channel per plane, the UV plane of NV12 has the channels U and V,
+    /// and the plane of YUYV has the channels Y and alternating U and V.
+    ///
+    /// ```
+    /// use nshare::{YuvFormat, YuvFrame};
+    ///
+    /// let frame = YuvFrame::new([0u8; 24], YuvFormat::Nv12, 4, 4).unwrap();
+    /// assert_eq!(frame.plane_view(0).dim(), (1, 4, 4));
+    /// assert_eq!(frame.plane_view(1).dim(), (2, 2, 2));
+    /// ```
+    pub fn plane_view(&self, plane: usize) -> ArrayView3<'_, u8> {
+        assert!(
+            plane < self.format().plane_count(),
+            "the plane must be in the format"
+        );
+        let (samples, rows, bytes) = self.format().plane_dims(plane, self.width(), self.height());
+        // Empty planes are not bounds checked, so their offset may be past the end of the buffer.
+        if samples == 0 || rows == 0 {
+            return ArrayView3::from_shape((bytes, rows, samples), &[]).unwrap();
+        }
+        let YuvPlane { offset, stride } = self.planes()[plane];
+        let shape = (bytes, rows, samples).strides((1, stride, bytes));
+        ArrayView3::from_shape(shape, &self.buffer().as_ref()[offset..]).unwrap()
+    }
+
+    /// Views the samples of a chroma channel, with `channel` 0 for U and 1 for V.
+    fn chroma_view(&self, channel: usize) -> ArrayView2<'_, u8> {
+        let (width, height) = (self.width(), self.height());
+        match self.format() {
+            YuvFormat::I420 => {
+                let (samples, rows, _) = self.format().plane_dims(1 + channel, width, height);
+                self.sample_view(1 + channel, 0, rows, samples, 1)
+            }
+            YuvFormat::Nv12 => {
+                let (samples, rows, _) = self.format().plane_dims(1, width, height);
+                self.sample_view(1, channel, rows, samples, 2)
+            }
+            YuvFormat::Yuyv => self.sample_view(0, 1 + 2 * channel, height, width / 2, 4),
+        }
+    }
+
+    /// Views `rows` by `cols` samples of `plane` that are `step` bytes apart, starting `skip` bytes into each row.
+    fn sample_view(
+        &self,
+        plane: usize,
+        skip: usize,
+        rows: usize,
+        cols: usize,
+        step: usize,
+    ) -> ArrayView2<'_, u8> {
+        // Empty planes are not bounds checked, so their offset may be past the end of the buffer.
+        if rows == 0 || cols == 0 {
+            return ArrayView2::from_shape((rows, cols), &[]).unwrap();
+        }
+        let YuvPlane { offset, stride } = self.planes()[plane];
+        let data = &self.buffer().as_ref()[offset + skip..];
+        ArrayView2::from_shape((rows, cols).strides((stride, step)), data).unwrap()
+    }
+}
+
+/// ```
+/// use nshare::{IntoNdarray3, YuvFormat, YuvFrame};
+///
+/// let frame = YuvFrame::new([16, 128, 235, 128], YuvFormat::Yuyv, 2, 1).unwrap();
+/// let arr = (&frame).into_ndarray3();
+/// // ndarray uses (channel, row, col), so the dims get flipped.
+/// assert_eq!(arr.dim(), (3, 1, 2));
+/// assert!(arr.slice(ndarray::s![.., 0, 1]).iter().eq(&[255, 255, 255]));
+/// ```
+impl<B> IntoNdarray3 for &YuvFrame<B>
+where
+    B: AsRef<[u8]>,
+{
+    type Out = Array3<u8>;
+
+    fn into_ndarray3(self) -> Self::Out {
+        let mut rgb = Array3::zeros((self.height(), self.width(), 3));
+        self.to_rgb(rgb.as_slice_mut().unwrap());
+        rgb.permuted_axes([2, 0, 1])
+    }
+}
@@ -0,0 +1,30 @@
+#[cfg(feature = "image")]
+mod image_impl;
+#[cfg(feature = "ndarray")]
+mod ndarray_impl;
+
+use crate::{PackedFormat, RawPacking};
+
+/// Packs an image into pixels of a [`PackedFormat`], in row-major order without padding.
+///
+/// The result can be borrowed again with [`PackedImage::new`](crate::PackedImage::new).
+///
+/// This uses an associated type to avoid ambiguity for the compiler.
+/// By calling this, the compiler always knows the returned type.
+pub trait IntoPacked {
+    type Out;
+
+    fn into_packed(self, format: PackedFormat) -> Self::Out;
+}
+
+/// Packs a raw image into bytes as by [`RawPacking`], in rows of [`RawPacking::row_bytes`] bytes.
+///
+/// The result can be borrowed again with [`PackedRawImage::new`](crate::PackedRawImage::new).
+///
+/// This uses an associated type to avoid ambiguity for the compiler.
+/// By calling this, the compiler always knows the returned type.
+pub trait IntoPackedRaw {
+    type Out;
+
+    fn into_packed_raw(self, packing: RawPacking) -> Self::Out;
+}
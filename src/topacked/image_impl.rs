@@ -0,0 +1,85 @@
+//! Implementations for image types being packed.
+
+extern crate alloc;
+
+use super::*;
+use alloc::vec::Vec;
+use core::ops::Deref;
+use image::{ImageBuffer, Luma, Rgb, Rgba};
+
+/// Pixels are opaque.
+///
+/// ```
+/// use image::{Rgb, RgbImage};
+/// use nshare::{IntoPacked, PackedFormat};
+///
+/// let img = RgbImage::from_pixel(2, 1, Rgb([0, 255, 0]));
+/// assert_eq!((&img).into_packed(PackedFormat::Bgr565), vec![0x07e0; 2]);
+/// ```
+impl<Container> IntoPacked for &ImageBuffer<Rgb<u8>, Container>
+where
+    Container: Deref<Target = [u8]>,
+{
+    type Out = Vec<u16>;
+
+    fn into_packed(self, format: PackedFormat) -> Self::Out {
+        self.pixels()
+            .map(|&Rgb([r, g, b])| format.pack([r, g, b, 255]))
+            .collect()
+    }
+}
+
+/// ```
+/// use image::{Rgba, RgbaImage};
+/// use nshare::{IntoPacked, PackedFormat};
+///
+/// let img = RgbaImage::from_pixel(1, 1, Rgba([255, 255, 255, 127]));
+/// assert_eq!((&img).into_packed(PackedFormat::Argb1555), vec![0x7fff]);
+/// ```
+impl<Container> IntoPacked for &ImageBuffer<Rgba<u8>, Container>
+where
+    Container: Deref<Target = [u8]>,
+{
+    type Out = Vec<u16>;
+
+    fn into_packed(self, format: PackedFormat) -> Self::Out {
+        self.pixels()
+            .map(|&Rgba(pixel)| format.pack(pixel))
+            .collect()
+    }
+}
+
+/// ```
+/// use image::{ImageBuffer, Luma};
+/// use nshare::{IntoPackedRaw, PackedRawImage, RawPacking};
+///
+/// let img: ImageBuffer<Luma<u16>, Vec<u16>> = ImageBuffer::from_fn(5, 1, |x, _| Luma([x as u16 * 200]));
+/// let packed = (&img).into_packed_raw(RawPacking::Raw10);
+/// // 5 samples need two groups of 5 bytes, the last one padded.
+/// assert_eq!(packed.len(), 10);
+/// let raw = PackedRawImage::new(&packed, 5, 1, RawPacking::Raw10).unwrap();
+/// assert_eq!(raw.sample(4, 0), 800);
+/// ```
+impl<Container> IntoPackedRaw for &ImageBuffer<Luma<u16>, Container>
+where
+    Container: Deref<Target = [u16]>,
+{
+    type Out = Vec<u8>;
+
+    fn into_packed_raw(self, packing: RawPacking) -> Self::Out {
+        let width = self.width() as usize;
+        let row_bytes = packing.row_bytes(width);
+        let mut packed = alloc::vec![0; row_bytes * self.height() as usize];
+        if row_bytes == 0 {
+            return packed;
+        }
+        for (row, out) in self
+            .as_raw()
+            .chunks_exact(width)
+            .zip(packed.chunks_exact_mut(row_bytes))
+        {
+            packing.pack_row(row, out);
+        }
+        packed
+    }
+}
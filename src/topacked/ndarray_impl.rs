@@ -0,0 +1,69 @@
+//! Implementations for ndarray types being packed.
+
+extern crate alloc;
+
+use super::*;
+use alloc::vec::Vec;
+use ndarray::{ArrayView2, ArrayView3, Axis};
+
+/// Inputs are in `(channel, row, col)` with red, green and blue channels, and optionally alpha.
+/// Without an alpha channel, pixels are opaque.
+///
+/// ```
+/// use nshare::{IntoPacked, PackedFormat};
+///
+/// let arr = ndarray::Array3::from_shape_vec((3, 1, 2), vec![255u8, 0, 0, 0, 0, 255]).unwrap();
+/// assert_eq!(arr.view().into_packed(PackedFormat::Rgb565), vec![0xf800, 0x001f]);
+/// assert_eq!(arr.view().into_packed(PackedFormat::Argb1555), vec![0xfc00, 0x801f]);
+/// ```
+impl IntoPacked for ArrayView3<'_, u8> {
+    type Out = Vec<u16>;
+
+    fn into_packed(self, format: PackedFormat) -> Self::Out {
+        let channels = self.len_of(Axis(0));
+        assert!(
+            channels == 3 || channels == 4,
+            "the array must have 3 or 4 channels"
+        );
+        let (_, height, width) = self.dim();
+        (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|(x, y)| {
+                let alpha = if channels == 4 { self[(3, y, x)] } else { 255 };
+                format.pack([self[(0, y, x)], self[(1, y, x)], self[(2, y, x)], alpha])
+            })
+            .collect()
+    }
+}
+
+/// Inputs are in (row, col).
+///
+/// ```
+/// use nshare::{IntoPackedRaw, RawPacking};
+///
+/// let arr = ndarray::array![[0xab1u16, 0xcd2], [0, 0xfff]];
+/// assert_eq!(arr.view().into_packed_raw(RawPacking::Raw12), vec![0xab, 0xcd, 0x21, 0x00, 0xff, 0xf0]);
+/// ```
+impl IntoPackedRaw for ArrayView2<'_, u16> {
+    type Out = Vec<u8>;
+
+    fn into_packed_raw(self, packing: RawPacking) -> Self::Out {
+        let (height, width) = self.dim();
+        let row_bytes = packing.row_bytes(width);
+        let mut packed = alloc::vec![0; row_bytes * height];
+        if row_bytes == 0 {
+            return packed;
+        }
+        let mut samples = Vec::with_capacity(width);
+        for (row, out) in self
+            .rows()
+            .into_iter()
+            .zip(packed.chunks_exact_mut(row_bytes))
+        {
+            samples.clear();
+            samples.extend(row.iter().copied());
+            packing.pack_row(&samples, out);
+        }
+        packed
+    }
+}
@@ -0,0 +1,15 @@
+#[cfg(feature = "ndarray")]
+mod ndarray_impl;
+
+/// Converts a type holding color components into a type holding colors of type `C`.
+///
+/// The components of each color must be on the last axis and adjacent in memory, which is the
+/// layout produced by [`IntoNdarrayComponents`](crate::IntoNdarrayComponents).
+///
+/// The color type is a type parameter of the trait since the same components may be viewed as any
+/// color type with the same number of them.
+pub trait IntoPalette<C> {
+    type Out;
+
+    fn into_palette(self) -> Self::Out;
+}
@@ -0,0 +1,136 @@
+//! Implementations for ndarray types being converted to palette types.
+
+use super::*;
+use ndarray::{
+    ArrayBase, ArrayView, ArrayViewMut, Axis, Dimension, RawData, RemoveAxis, ShapeBuilder,
+};
+use palette::cast::ArrayCast;
+use palette::ArrayExt;
+
+/// ```
+/// use nshare::IntoPalette;
+/// use ndarray::{array, ArrayView1};
+/// use palette::LinSrgb;
+///
+/// let arr = array![[0.0f32, 0.5, 1.0], [1.0, 0.0, 0.25]];
+/// let colors: ArrayView1<LinSrgb<f32>> = arr.view().into_palette();
+/// assert_eq!(colors[1], LinSrgb::new(1.0, 0.0, 0.25));
+/// ```
+impl<'a, T, C, D> IntoPalette<C> for ArrayView<'a, T, D>
+where
+    C: ArrayCast + 'a,
+    C::Array: ArrayExt<Item = T>,
+    D: RemoveAxis,
+{
+    type Out = ArrayView<'a, C, D::Smaller>;
+
+    fn into_palette(mut self) -> Self::Out {
+        let inverted = invert_negative_axes(&mut self);
+        let (shape, strides) = color_shape::<C, _>(&self.raw_dim(), self.strides());
+        // SAFETY: `color_shape` checked that the components of every color are adjacent, and `ArrayCast`
+        // guarantees that `C` is laid out as an array of its components.
+        let mut colors =
+            unsafe { ArrayView::from_shape_ptr(shape.strides(strides), self.as_ptr() as *const C) };
+        restore_inverted_axes(&mut colors, &inverted);
+        colors
+    }
+}
+
+/// ```
+/// use nshare::IntoPalette;
+/// use ndarray::{ArrayViewMut2, Array3};
+/// use palette::Lab;
+///
+/// let mut arr = Array3::<f32>::zeros((2, 2, 3));
+/// let mut colors: ArrayViewMut2<Lab> = arr.view_mut().into_palette();
+/// colors[(1, 0)] = Lab::new(50.0, 10.0, -10.0);
+/// assert!(arr.slice(ndarray::s![1, 0, ..]).iter().eq(&[50.0, 10.0, -10.0]));
+/// ```
+impl<'a, T, C, D> IntoPalette<C> for ArrayViewMut<'a, T, D>
+where
+    C: ArrayCast + 'a,
+    C::Array: ArrayExt<Item = T>,
+    D: RemoveAxis,
+{
+    type Out = ArrayViewMut<'a, C, D::Smaller>;
+
+    fn into_palette(mut self) -> Self::Out {
+        let inverted = invert_negative_axes(&mut self);
+        let (shape, strides) = color_shape::<C, _>(&self.raw_dim(), self.strides());
+        // SAFETY: `color_shape` checked that the components of every color are adjacent, and `ArrayCast`
+        // guarantees that `C` is laid out as an array of its components.
+        let mut colors = unsafe {
+            ArrayViewMut::from_shape_ptr(shape.strides(strides), self.as_mut_ptr() as *mut C)
+        };
+        restore_inverted_axes(&mut colors, &inverted);
+        colors
+    }
+}
+
+/// Inverts the axes before the last one that have negative strides, returning which ones were inverted.
+fn invert_negative_axes<S, D>(arr: &mut ArrayBase<S, D>) -> D::Smaller
+where
+    S: RawData,
+    D: RemoveAxis,
+{
+    let mut inverted = D::Smaller::zeros(arr.ndim() - 1);
+    for (axis, flag) in inverted.slice_mut().iter_mut().enumerate() {
+        if arr.stride_of(Axis(axis)) < 0 {
+            arr.invert_axis(Axis(axis));
+            *flag = 1;
+        }
+    }
+    inverted
+}
+
+/// Inverts the axes flagged by [`invert_negative_axes`] again, so the colors are in the original order.
+fn restore_inverted_axes<S, D>(arr: &mut ArrayBase<S, D>, inverted: &D)
+where
+    S: RawData,
+    D: Dimension,
+{
+    for (axis, &flag) in inverted.slice().iter().enumerate() {
+        if flag == 1 {
+            arr.invert_axis(Axis(axis));
+        }
+    }
+}
+
+/// The shape and strides of the colors in an array of their components, where the last axis holds the components.
+///
+/// The strides of the components must not be negative.
+fn color_shape<C, D>(dim: &D, strides: &[isize]) -> (D::Smaller, D::Smaller)
+where
+    C: ArrayCast,
+    D: RemoveAxis,
+{
+    let components = <C::Array as ArrayExt>::LENGTH;
+    let last = Axis(dim.ndim() - 1);
+    assert_eq!(
+        dim[last.index()],
+        components,
+        "the last axis must hold the components of one color"
+    );
+    assert!(
+        components <= 1 || strides[last.index()] == 1,
+        "the components of each color must be adjacent in memory"
+    );
+    let shape = dim.remove_axis(last);
+    let mut color_strides = D::Smaller::zeros(shape.ndim());
+    for ((out, &stride), &len) in color_strides
+        .slice_mut()
+        .iter_mut()
+        .zip(strides)
+        .zip(shape.slice())
+    {
+        // The stride of an axis with a single color is never used to step to another one.
+        if len > 1 {
+            assert!(
+                (stride as usize).is_multiple_of(components),
+                "the strides must step over whole colors"
+            );
+            *out = stride as usize / components;
+        }
+    }
+    (shape, color_strides)
+}
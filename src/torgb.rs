@@ -0,0 +1,26 @@
+#[cfg(feature = "ndarray")]
+mod ndarray_impl;
+
+/// Converts a 3d type with 3 channels to pixels of the rgb crate's `Rgb` type.
+///
+/// Coordinates of the source are in `(channel, row, col)`, and the pixels are returned in row-major order.
+///
+/// This uses an associated type to avoid ambiguity for the compiler.
+/// By calling this, the compiler always knows the returned type.
+pub trait IntoRgb {
+    type Out;
+
+    fn into_rgb(self) -> Self::Out;
+}
+
+/// Converts a 3d type with 4 channels to pixels of the rgb crate's `Rgba` type.
+///
+/// Coordinates of the source are in `(channel, row, col)`, and the pixels are returned in row-major order.
+///
+/// This uses an associated type to avoid ambiguity for the compiler.
+/// By calling this, the compiler always knows the returned type.
+pub trait IntoRgba {
+    type Out;
+
+    fn into_rgba(self) -> Self::Out;
+}
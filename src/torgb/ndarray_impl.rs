@@ -0,0 +1,85 @@
+//! Implementations for ndarray types being converted to rgb types.
+
+use super::*;
+use ndarray::{ArrayBase, Axis, Data, Ix3};
+use rgb::{Rgb, Rgba};
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+/// ```
+/// use nshare::IntoRgb;
+/// use rgb::Rgb;
+///
+/// let mut arr = ndarray::Array3::<u8>::zeros((3, 2, 2));
+/// arr[(1, 0, 1)] = 255;
+/// let pixels = arr.view().into_rgb();
+/// assert_eq!(pixels, vec![Rgb::new(0, 0, 0), Rgb::new(0, 255, 0), Rgb::new(0, 0, 0), Rgb::new(0, 0, 0)]);
+///
+/// // Arrays with the channels last can be permuted first.
+/// let hwc = ndarray::Array3::<u8>::ones((2, 2, 3));
+/// assert_eq!(hwc.view().permuted_axes([2, 0, 1]).into_rgb(), vec![Rgb::new(1, 1, 1); 4]);
+/// ```
+impl<T, S> IntoRgb for ArrayBase<S, Ix3>
+where
+    T: Copy,
+    S: Data<Elem = T>,
+{
+    type Out = Vec<Rgb<T>>;
+
+    fn into_rgb(self) -> Self::Out {
+        assert_eq!(
+            self.len_of(Axis(0)),
+            3,
+            "the channel axis must have a length of 3"
+        );
+        let (r, g, b) = (
+            self.index_axis(Axis(0), 0),
+            self.index_axis(Axis(0), 1),
+            self.index_axis(Axis(0), 2),
+        );
+        r.iter()
+            .zip(g.iter())
+            .zip(b.iter())
+            .map(|((&r, &g), &b)| Rgb { r, g, b })
+            .collect()
+    }
+}
+
+/// ```
+/// use nshare::IntoRgba;
+/// use rgb::Rgba;
+///
+/// let mut arr = ndarray::Array3::<u16>::zeros((4, 1, 2));
+/// arr[(3, 0, 1)] = 65535;
+/// let pixels = arr.view().into_rgba();
+/// assert_eq!(pixels, vec![Rgba::new(0, 0, 0, 0), Rgba::new(0, 0, 0, 65535)]);
+/// ```
+impl<T, S> IntoRgba for ArrayBase<S, Ix3>
+where
+    T: Copy,
+    S: Data<Elem = T>,
+{
+    type Out = Vec<Rgba<T>>;
+
+    fn into_rgba(self) -> Self::Out {
+        assert_eq!(
+            self.len_of(Axis(0)),
+            4,
+            "the channel axis must have a length of 4"
+        );
+        let (r, g, b, a) = (
+            self.index_axis(Axis(0), 0),
+            self.index_axis(Axis(0), 1),
+            self.index_axis(Axis(0), 2),
+            self.index_axis(Axis(0), 3),
+        );
+        r.iter()
+            .zip(g.iter())
+            .zip(b.iter())
+            .zip(a.iter())
+            .map(|(((&r, &g), &b), &a)| Rgba { r, g, b, a })
+            .collect()
+    }
+}
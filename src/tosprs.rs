@@ -0,0 +1,32 @@
+#[cfg(feature = "nalgebra")]
+mod nalgebra_impl;
+#[cfg(feature = "nalgebra-sparse")]
+mod nalgebra_sparse_impl;
+#[cfg(feature = "ndarray")]
+mod ndarray_impl;
+
+/// Converts a sparse matrix type to a sprs sparse matrix type.
+///
+/// The index and value buffers are moved into the sprs matrix without being copied.
+///
+/// This uses an associated type to avoid ambiguity for the compiler.
+/// By calling this, the compiler always knows the returned type.
+pub trait IntoSprs {
+    type Out;
+
+    fn into_sprs(self) -> Self::Out;
+}
+
+/// Converts a dense 2d type to a sprs sparse matrix type, dropping the values within `tolerance` of zero.
+///
+/// A value `v` is dropped when `-tolerance <= v <= tolerance`, so a tolerance of zero only drops
+/// exact zeros. NaN is never dropped.
+///
+/// This uses an associated type to avoid ambiguity for the compiler.
+/// By calling this, the compiler always knows the returned type.
+pub trait IntoSprsWithTolerance {
+    type Elem;
+    type Out;
+
+    fn into_sprs_with_tolerance(self, tolerance: Self::Elem) -> Self::Out;
+}
@@ -0,0 +1,39 @@
+//! Implementations for nalgebra types being converted to sprs types.
+
+use super::*;
+use crate::sparse::compress_lanes;
+use core::ops::Neg;
+use nalgebra::{Dim, Matrix, Scalar, Storage};
+use sprs::CsMat;
+
+/// ```
+/// use nshare::IntoSprsWithTolerance;
+/// use nalgebra::Matrix2x3;
+///
+/// let m = Matrix2x3::new(
+///     1.0, 0.0, 1e-9,
+///     0.0, -2.0, 3.0,
+/// );
+/// let s = m.into_sprs_with_tolerance(1e-6);
+/// // nalgebra matrices are column-major, so they become CSC matrices.
+/// assert!(s.is_csc());
+/// assert_eq!(s.shape(), (2, 3));
+/// assert_eq!(s.nnz(), 3);
+/// assert_eq!(s.get(1, 1), Some(&-2.0));
+/// assert_eq!(s.get(0, 2), None);
+/// ```
+impl<T, R, C, S> IntoSprsWithTolerance for Matrix<T, R, C, S>
+where
+    T: Scalar + PartialOrd + Neg<Output = T>,
+    R: Dim,
+    C: Dim,
+    S: Storage<T, R, C>,
+{
+    type Elem = T;
+    type Out = CsMat<T>;
+
+    fn into_sprs_with_tolerance(self, tolerance: T) -> Self::Out {
+        let (indptr, indices, data) = compress_lanes(self.column_iter(), &tolerance);
+        CsMat::new_csc(self.shape(), indptr, indices, data)
+    }
+}
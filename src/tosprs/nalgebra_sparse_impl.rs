@@ -0,0 +1,49 @@
+//! Implementations for nalgebra-sparse types being converted to sprs types.
+
+use super::*;
+use nalgebra_sparse::{CscMatrix, CsrMatrix};
+use sprs::CsMat;
+
+/// ```
+/// use nshare::IntoSprs;
+/// use nalgebra_sparse::CsrMatrix;
+///
+/// let m = CsrMatrix::try_from_csr_data(2, 3, vec![0, 1, 3], vec![2, 0, 1], vec![1.0, 2.0, 3.0]).unwrap();
+/// let s = m.into_sprs();
+/// assert!(s.is_csr());
+/// assert_eq!(s.shape(), (2, 3));
+/// assert_eq!(s.get(0, 2), Some(&1.0));
+/// assert_eq!(s.get(1, 1), Some(&3.0));
+/// assert_eq!(s.get(0, 0), None);
+/// ```
+impl<T> IntoSprs for CsrMatrix<T> {
+    type Out = CsMat<T>;
+
+    fn into_sprs(self) -> Self::Out {
+        let shape = (self.nrows(), self.ncols());
+        let (row_offsets, col_indices, values) = self.disassemble();
+        CsMat::new(shape, row_offsets, col_indices, values)
+    }
+}
+
+/// ```
+/// use nshare::IntoSprs;
+/// use nalgebra_sparse::CscMatrix;
+///
+/// let m = CscMatrix::try_from_csc_data(3, 2, vec![0, 1, 3], vec![2, 0, 1], vec![1.0, 2.0, 3.0]).unwrap();
+/// let s = m.into_sprs();
+/// assert!(s.is_csc());
+/// assert_eq!(s.shape(), (3, 2));
+/// assert_eq!(s.get(2, 0), Some(&1.0));
+/// assert_eq!(s.get(1, 1), Some(&3.0));
+/// assert_eq!(s.get(0, 0), None);
+/// ```
+impl<T> IntoSprs for CscMatrix<T> {
+    type Out = CsMat<T>;
+
+    fn into_sprs(self) -> Self::Out {
+        let shape = (self.nrows(), self.ncols());
+        let (col_offsets, row_indices, values) = self.disassemble();
+        CsMat::new_csc(shape, col_offsets, row_indices, values)
+    }
+}
@@ -0,0 +1,36 @@
+//! Implementations for ndarray types being converted to sprs types.
+
+use super::*;
+use crate::sparse::compress_lanes;
+use core::ops::Neg;
+use ndarray::{ArrayBase, Data, Ix2};
+use sprs::CsMat;
+
+/// ```
+/// use nshare::IntoSprsWithTolerance;
+///
+/// let arr = ndarray::array![
+///     [1.0, 0.0, 1e-9],
+///     [0.0, -2.0, 3.0],
+/// ];
+/// let s = arr.view().into_sprs_with_tolerance(1e-6);
+/// // Arrays are row-major, so they become CSR matrices.
+/// assert!(s.is_csr());
+/// assert_eq!(s.shape(), (2, 3));
+/// assert_eq!(s.nnz(), 3);
+/// assert_eq!(s.get(1, 1), Some(&-2.0));
+/// assert_eq!(s.get(0, 2), None);
+/// ```
+impl<T, S> IntoSprsWithTolerance for ArrayBase<S, Ix2>
+where
+    T: Clone + PartialOrd + Neg<Output = T>,
+    S: Data<Elem = T>,
+{
+    type Elem = T;
+    type Out = CsMat<T>;
+
+    fn into_sprs_with_tolerance(self, tolerance: T) -> Self::Out {
+        let (indptr, indices, data) = compress_lanes(self.rows(), &tolerance);
+        CsMat::new(self.dim(), indptr, indices, data)
+    }
+}
@@ -0,0 +1,16 @@
+#[cfg(feature = "image")]
+mod image_impl;
+#[cfg(feature = "ndarray")]
+mod ndarray_impl;
+
+/// Converts a type with straight alpha into a tiny-skia `Pixmap`, premultiplying the color channels by alpha.
+///
+/// Like `Pixmap::new`, this gives `None` for an empty or too large input, since a `Pixmap` cannot be empty.
+///
+/// This uses an associated type to avoid ambiguity for the compiler.
+/// By calling this, the compiler always knows the returned type.
+pub trait IntoPixmap {
+    type Out;
+
+    fn into_pixmap(self) -> Self::Out;
+}
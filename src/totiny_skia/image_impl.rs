@@ -0,0 +1,33 @@
+//! Implementations for image types being converted to tiny-skia types.
+
+use super::*;
+use core::ops::Deref;
+use image::{ImageBuffer, Rgba};
+use tiny_skia::{ColorU8, Pixmap};
+
+/// ```
+/// use image::{Rgba, RgbaImage};
+/// use nshare::IntoPixmap;
+///
+/// let img = RgbaImage::from_pixel(2, 1, Rgba([255, 100, 0, 128]));
+/// let pixmap = (&img).into_pixmap().unwrap();
+/// assert_eq!(pixmap.width(), 2);
+/// // The color channels are premultiplied by alpha.
+/// assert_eq!(&pixmap.data()[..4], &[128, 50, 0, 128]);
+/// assert!((&RgbaImage::new(0, 3)).into_pixmap().is_none());
+/// ```
+impl<Container> IntoPixmap for &ImageBuffer<Rgba<u8>, Container>
+where
+    Container: Deref<Target = [u8]>,
+{
+    type Out = Option<Pixmap>;
+
+    fn into_pixmap(self) -> Self::Out {
+        let (width, height) = self.dimensions();
+        let mut pixmap = Pixmap::new(width, height)?;
+        for (out, &Rgba([r, g, b, a])) in pixmap.pixels_mut().iter_mut().zip(self.pixels()) {
+            *out = ColorU8::from_rgba(r, g, b, a).premultiply();
+        }
+        Some(pixmap)
+    }
+}
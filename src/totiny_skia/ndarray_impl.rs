@@ -0,0 +1,46 @@
+//! Implementations for ndarray types being converted to tiny-skia types.
+
+use super::*;
+use ndarray::{ArrayBase, Axis, Data, Ix3};
+use tiny_skia::{Color, Pixmap};
+
+/// The input is in `(channel, row, col)` with 4 channels of straight RGBA in the range `0.0..=1.0`.
+/// Values outside of that range are clamped.
+///
+/// ```
+/// use nshare::IntoPixmap;
+///
+/// let mut arr = ndarray::Array3::<f32>::zeros((4, 1, 2));
+/// arr.slice_mut(ndarray::s![0, .., ..]).fill(1.0);
+/// arr.slice_mut(ndarray::s![3, .., ..]).fill(0.5);
+/// let pixmap = arr.view().into_pixmap().unwrap();
+/// assert_eq!((pixmap.width(), pixmap.height()), (2, 1));
+/// assert_eq!(&pixmap.data()[..4], &[128, 0, 0, 128]);
+/// assert!(ndarray::Array3::<f32>::zeros((4, 0, 2)).into_pixmap().is_none());
+/// ```
+impl<S> IntoPixmap for ArrayBase<S, Ix3>
+where
+    S: Data<Elem = f32>,
+{
+    type Out = Option<Pixmap>;
+
+    fn into_pixmap(self) -> Self::Out {
+        let (channels, height, width) = self.dim();
+        assert_eq!(channels, 4, "the channel axis must have a length of 4");
+        let mut pixmap = Pixmap::new(u32::try_from(width).ok()?, u32::try_from(height).ok()?)?;
+        let pixels = self.lanes(Axis(0));
+        for (out, rgba) in pixmap.pixels_mut().iter_mut().zip(pixels) {
+            let clamp = |c: f32| c.clamp(0.0, 1.0);
+            *out = Color::from_rgba(
+                clamp(rgba[0]),
+                clamp(rgba[1]),
+                clamp(rgba[2]),
+                clamp(rgba[3]),
+            )
+            .expect("the channels must not be NaN")
+            .premultiply()
+            .to_color_u8();
+        }
+        Some(pixmap)
+    }
+}
@@ -0,0 +1,517 @@
+//! YUV frames as they come from cameras and video decoders, which have no type in the image crate.
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
+use core::fmt;
+
+/// The layout of the planes of a [`YuvFrame`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum YuvFormat {
+    /// Three planes of Y, U and V, with the chroma planes subsampled by two in both directions.
+    I420,
+    /// A plane of Y and a plane of interleaved U and V, subsampled by two in both directions.
+    Nv12,
+    /// A single plane of `Y0 U Y1 V` groups, with the chroma shared by each pair of pixels in a row.
+    Yuyv,
+}
+
+impl YuvFormat {
+    /// The number of planes in a frame of this format.
+    pub fn plane_count(self) -> usize {
+        match self {
+            Self::I420 => 3,
+            Self::Nv12 => 2,
+            Self::Yuyv => 1,
+        }
+    }
+
+    /// The number of samples per row, the number of rows and the number of bytes per sample of `plane`.
+    pub(crate) fn plane_dims(
+        self,
+        plane: usize,
+        width: usize,
+        height: usize,
+    ) -> (usize, usize, usize) {
+        let (chroma_width, chroma_height) = (width.div_ceil(2), height.div_ceil(2));
+        match (self, plane) {
+            (Self::I420 | Self::Nv12, 0) => (width, height, 1),
+            (Self::I420, _) => (chroma_width, chroma_height, 1),
+            (Self::Nv12, _) => (chroma_width, chroma_height, 2),
+            (Self::Yuyv, _) => (width, height, 2),
+        }
+    }
+}
+
+/// The matrix used to convert between YUV and RGB.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum YuvMatrix {
+    /// ITU-R BT.601, used by standard definition video and JPEG.
+    #[default]
+    Bt601,
+    /// ITU-R BT.709, used by high definition video.
+    Bt709,
+}
+
+impl YuvMatrix {
+    /// The weights of red and blue in the luma.
+    fn weights(self) -> (f32, f32) {
+        match self {
+            Self::Bt601 => (0.299, 0.114),
+            Self::Bt709 => (0.2126, 0.0722),
+        }
+    }
+}
+
+/// The range of the values of a [`YuvFrame`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum YuvRange {
+    /// Luma is in `16..=235` and chroma in `16..=240`, as in most video.
+    #[default]
+    Limited,
+    /// Luma and chroma use all of `0..=255`, as in JPEG.
+    Full,
+}
+
+/// How the values of a [`YuvFrame`] map to RGB.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct YuvColorSpace {
+    pub matrix: YuvMatrix,
+    pub range: YuvRange,
+}
+
+impl YuvColorSpace {
+    /// BT.601 in limited range, which is what most cameras produce.
+    pub const BT601: Self = Self {
+        matrix: YuvMatrix::Bt601,
+        range: YuvRange::Limited,
+    };
+
+    /// BT.709 in limited range, which is what most high definition video uses.
+    pub const BT709: Self = Self {
+        matrix: YuvMatrix::Bt709,
+        range: YuvRange::Limited,
+    };
+
+    /// The scale of luma and chroma around their offsets.
+    fn scales(self) -> (f32, f32, f32) {
+        match self.range {
+            YuvRange::Limited => (16.0, 219.0, 224.0),
+            YuvRange::Full => (0.0, 255.0, 255.0),
+        }
+    }
+
+    /// Converts one YUV sample to RGB.
+    fn to_rgb(self, [y, u, v]: [u8; 3]) -> [u8; 3] {
+        let (kr, kb) = self.matrix.weights();
+        let (y_offset, y_scale, c_scale) = self.scales();
+        let y = (f32::from(y) - y_offset) / y_scale;
+        let pb = (f32::from(u) - 128.0) / c_scale;
+        let pr = (f32::from(v) - 128.0) / c_scale;
+        let r = y + 2.0 * (1.0 - kr) * pr;
+        let b = y + 2.0 * (1.0 - kb) * pb;
+        let g = (y - kr * r - kb * b) / (1.0 - kr - kb);
+        [r, g, b].map(|x| quantize(x * 255.0))
+    }
+
+    /// Converts one RGB pixel to luma and the unscaled chroma differences.
+    fn to_luma_chroma(self, [r, g, b]: [u8; 3]) -> (u8, f32, f32) {
+        let (kr, kb) = self.matrix.weights();
+        let (y_offset, y_scale, _) = self.scales();
+        let [r, g, b] = [r, g, b].map(|x| f32::from(x) / 255.0);
+        let y = kr * r + (1.0 - kr - kb) * g + kb * b;
+        let pb = (b - y) / (2.0 * (1.0 - kb));
+        let pr = (r - y) / (2.0 * (1.0 - kr));
+        (quantize(y_offset + y * y_scale), pb, pr)
+    }
+
+    /// Scales a chroma difference to a chroma sample.
+    fn chroma(self, difference: f32) -> u8 {
+        quantize(128.0 + difference * self.scales().2)
+    }
+}
+
+fn quantize(x: f32) -> u8 {
+    (x.clamp(0.0, 255.0) + 0.5) as u8
+}
+
+/// Where a plane of a [`YuvFrame`] is in the buffer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct YuvPlane {
+    /// The position in bytes of the first sample of the plane.
+    pub offset: usize,
+    /// The distance in bytes between the starts of neighboring rows.
+    pub stride: usize,
+}
+
+/// The reason a layout was rejected by the constructors of [`YuvFrame`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum YuvError {
+    /// Computing the size of a plane overflowed.
+    Overflow,
+    /// The format has `expected` planes, but `found` were given.
+    PlaneCount { expected: usize, found: usize },
+    /// The rows of `plane` need `min` bytes, which is more than the `stride`.
+    Stride {
+        plane: usize,
+        stride: usize,
+        min: usize,
+    },
+    /// The last row of `plane` ends at `end`, past the end of a buffer of `len` bytes.
+    OutOfBounds {
+        plane: usize,
+        end: usize,
+        len: usize,
+    },
+    /// The format shares chroma between pairs of pixels in a row, but the width is odd.
+    OddWidth { width: usize },
+}
+
+impl fmt::Display for YuvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Overflow => write!(f, "the size of a plane overflows"),
+            Self::PlaneCount { expected, found } => write!(
+                f,
+                "the format has {expected} planes, but {found} were given"
+            ),
+            Self::Stride { plane, stride, min } => write!(
+                f,
+                "the rows of plane {plane} need {min} bytes, but the stride is {stride}"
+            ),
+            Self::OutOfBounds { plane, end, len } => write!(
+                f,
+                "plane {plane} ends at byte {end}, but the buffer has {len}"
+            ),
+            Self::OddWidth { width } => write!(f, "the format needs an even width, not {width}"),
+        }
+    }
+}
+
+impl core::error::Error for YuvError {}
+
+/// A YUV frame over a borrowed or owned buffer of bytes.
+///
+/// The planes are validated on construction, so reading samples and converting the frame never fails.
+/// Images are converted to and from RGB with [`YuvFrame::to_rgb`] and [`YuvFrame::from_rgb`] using the
+/// [`YuvColorSpace`] of the frame, and the planes can be viewed as ndarray arrays.
+#[derive(Debug, Clone)]
+pub struct YuvFrame<B> {
+    buffer: B,
+    format: YuvFormat,
+    width: usize,
+    height: usize,
+    planes: [YuvPlane; 3],
+    color_space: YuvColorSpace,
+}
+
+impl<B> YuvFrame<B>
+where
+    B: AsRef<[u8]>,
+{
+    /// Lays out the planes one after another, with rows without padding.
+    ///
+    /// ```
+    /// use nshare::{YuvFormat, YuvFrame};
+    ///
+    /// // A 4x2 I420 frame has 8 bytes of luma and 2 bytes of each chroma plane.
+    /// let frame = YuvFrame::new([0u8; 12], YuvFormat::I420, 4, 2).unwrap();
+    /// assert_eq!(frame.planes()[2].offset, 10);
+    /// ```
+    pub fn new(
+        buffer: B,
+        format: YuvFormat,
+        width: usize,
+        height: usize,
+    ) -> Result<Self, YuvError> {
+        let (planes, _) = packed_planes(format, width, height)?;
+        Self::with_planes(
+            buffer,
+            format,
+            width,
+            height,
+            &planes[..format.plane_count()],
+        )
+    }
+
+    /// Uses the given offset and stride for each plane, in the order Y, U, V or Y, UV.
+    ///
+    /// ```
+    /// use nshare::{YuvError, YuvFormat, YuvFrame, YuvPlane};
+    ///
+    /// // An NV12 frame with rows padded to 8 bytes.
+    /// let planes = [YuvPlane { offset: 0, stride: 8 }, YuvPlane { offset: 16, stride: 8 }];
+    /// assert!(YuvFrame::with_planes([0u8; 22], YuvFormat::Nv12, 6, 2, &planes).is_ok());
+    /// assert_eq!(
+    ///     YuvFrame::with_planes([0u8; 20], YuvFormat::Nv12, 6, 2, &planes).unwrap_err(),
+    ///     YuvError::OutOfBounds { plane: 1, end: 22, len: 20 },
+    /// );
+    /// ```
+    pub fn with_planes(
+        buffer: B,
+        format: YuvFormat,
+        width: usize,
+        height: usize,
+        planes: &[YuvPlane],
+    ) -> Result<Self, YuvError> {
+        if format == YuvFormat::Yuyv && !width.is_multiple_of(2) {
+            return Err(YuvError::OddWidth { width });
+        }
+        if planes.len() != format.plane_count() {
+            return Err(YuvError::PlaneCount {
+                expected: format.plane_count(),
+                found: planes.len(),
+            });
+        }
+        let len = buffer.as_ref().len();
+        for (index, plane) in planes.iter().enumerate() {
+            let (samples, rows, bytes) = format.plane_dims(index, width, height);
+            let min = samples.checked_mul(bytes).ok_or(YuvError::Overflow)?;
+            if min > plane.stride {
+                return Err(YuvError::Stride {
+                    plane: index,
+                    stride: plane.stride,
+                    min,
+                });
+            }
+            if rows == 0 || min == 0 {
+                continue;
+            }
+            let end = plane
+                .stride
+                .checked_mul(rows - 1)
+                .and_then(|last| last.checked_add(plane.offset))
+                .and_then(|last| last.checked_add(min))
+                .ok_or(YuvError::Overflow)?;
+            if end > len {
+                return Err(YuvError::OutOfBounds {
+                    plane: index,
+                    end,
+                    len,
+                });
+            }
+        }
+        let mut all_planes = [YuvPlane::default(); 3];
+        all_planes[..planes.len()].copy_from_slice(planes);
+        Ok(Self {
+            buffer,
+            format,
+            width,
+            height,
+            planes: all_planes,
+            color_space: YuvColorSpace::default(),
+        })
+    }
+
+    /// Sets the color space used to convert the frame to RGB, which is [`YuvColorSpace::BT601`] by default.
+    pub fn with_color_space(self, color_space: YuvColorSpace) -> Self {
+        Self {
+            color_space,
+            ..self
+        }
+    }
+
+    /// The layout of the planes.
+    pub fn format(&self) -> YuvFormat {
+        self.format
+    }
+
+    /// The width of the frame in pixels.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// The height of the frame in pixels.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The offset and stride of each plane.
+    pub fn planes(&self) -> &[YuvPlane] {
+        &self.planes[..self.format.plane_count()]
+    }
+
+    /// The color space used to convert the frame to RGB.
+    pub fn color_space(&self) -> YuvColorSpace {
+        self.color_space
+    }
+
+    /// The buffer holding the planes.
+    pub fn buffer(&self) -> &B {
+        &self.buffer
+    }
+
+    /// Returns the buffer holding the planes.
+    pub fn into_buffer(self) -> B {
+        self.buffer
+    }
+
+    /// The Y, U and V samples of the pixel at column `x` and row `y`.
+    ///
+    /// ```
+    /// use nshare::{YuvFormat, YuvFrame};
+    ///
+    /// let frame = YuvFrame::new([16, 32, 128, 200], YuvFormat::Yuyv, 2, 1).unwrap();
+    /// assert_eq!(frame.sample(1, 0), [128, 32, 200]);
+    /// ```
+    pub fn sample(&self, x: usize, y: usize) -> [u8; 3] {
+        assert!(
+            x < self.width && y < self.height,
+            "the pixel must be in the frame"
+        );
+        let data = self.buffer.as_ref();
+        self.positions(x, y).map(|position| data[position])
+    }
+
+    /// The positions in the buffer of the Y, U and V samples of the pixel at column `x` and row `y`.
+    fn positions(&self, x: usize, y: usize) -> [usize; 3] {
+        let [luma, first, second] = self.planes;
+        match self.format {
+            YuvFormat::I420 => [
+                luma.offset + y * luma.stride + x,
+                first.offset + y / 2 * first.stride + x / 2,
+                second.offset + y / 2 * second.stride + x / 2,
+            ],
+            YuvFormat::Nv12 => {
+                let chroma = first.offset + y / 2 * first.stride + x / 2 * 2;
+                [luma.offset + y * luma.stride + x, chroma, chroma + 1]
+            }
+            YuvFormat::Yuyv => {
+                let row = luma.offset + y * luma.stride;
+                [row + x * 2, row + x / 2 * 4 + 1, row + x / 2 * 4 + 3]
+            }
+        }
+    }
+
+    /// Writes the frame as interleaved RGB subpixels in row-major order into `rgb`.
+    ///
+    /// ```
+    /// use nshare::{YuvColorSpace, YuvFormat, YuvFrame, YuvRange};
+    ///
+    /// let mut rgb = [0u8; 6];
+    /// YuvFrame::new([16, 128, 235, 128], YuvFormat::Yuyv, 2, 1)
+    ///     .unwrap()
+    ///     .to_rgb(&mut rgb);
+    /// // Limited range maps 16 to black and 235 to white.
+    /// assert_eq!(rgb, [0, 0, 0, 255, 255, 255]);
+    /// ```
+    pub fn to_rgb(&self, rgb: &mut [u8]) {
+        assert_eq!(
+            rgb.len(),
+            self.width * self.height * 3,
+            "the output must hold three subpixels for every pixel"
+        );
+        if self.width == 0 {
+            return;
+        }
+        for (y, row) in rgb.chunks_exact_mut(self.width * 3).enumerate() {
+            for (x, pixel) in row.chunks_exact_mut(3).enumerate() {
+                pixel.copy_from_slice(&self.color_space.to_rgb(self.sample(x, y)));
+            }
+        }
+    }
+}
+
+impl<B> YuvFrame<B>
+where
+    B: AsRef<[u8]> + AsMut<[u8]>,
+{
+    /// Overwrites the frame with interleaved RGB subpixels in row-major order, using its color space.
+    ///
+    /// The chroma of each block of pixels that shares it is the average of their chroma.
+    ///
+    /// ```
+    /// use nshare::{YuvFormat, YuvFrame};
+    ///
+    /// let mut buffer = [0u8; 4];
+    /// let mut frame = YuvFrame::new(&mut buffer, YuvFormat::Yuyv, 2, 1).unwrap();
+    /// frame.write_rgb(&[0, 0, 0, 255, 255, 255]);
+    /// assert_eq!(buffer, [16, 128, 235, 128]);
+    /// ```
+    pub fn write_rgb(&mut self, rgb: &[u8]) {
+        let (width, height) = (self.width, self.height);
+        assert_eq!(
+            rgb.len(),
+            width * height * 3,
+            "the input must hold three subpixels for every pixel"
+        );
+        // The pixels sharing a chroma sample are `block_width` by `block_height`.
+        let (block_width, block_height) = match self.format {
+            YuvFormat::I420 | YuvFormat::Nv12 => (2, 2),
+            YuvFormat::Yuyv => (2, 1),
+        };
+        for block_y in (0..height).step_by(block_height) {
+            for block_x in (0..width).step_by(block_width) {
+                let (mut pb, mut pr, mut count) = (0.0, 0.0, 0.0);
+                for y in block_y..(block_y + block_height).min(height) {
+                    for x in block_x..(block_x + block_width).min(width) {
+                        let index = (y * width + x) * 3;
+                        let pixel = [rgb[index], rgb[index + 1], rgb[index + 2]];
+                        let (luma, pixel_pb, pixel_pr) = self.color_space.to_luma_chroma(pixel);
+                        let [luma_position, ..] = self.positions(x, y);
+                        self.buffer.as_mut()[luma_position] = luma;
+                        pb += pixel_pb;
+                        pr += pixel_pr;
+                        count += 1.0;
+                    }
+                }
+                let [_, u, v] = self.positions(block_x, block_y);
+                let data = self.buffer.as_mut();
+                data[u] = self.color_space.chroma(pb / count);
+                data[v] = self.color_space.chroma(pr / count);
+            }
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl YuvFrame<Vec<u8>> {
+    /// Converts interleaved RGB subpixels in row-major order to a frame with planes laid out as by
+    /// [`YuvFrame::new`].
+    ///
+    /// ```
+    /// use nshare::{YuvColorSpace, YuvFormat, YuvFrame};
+    ///
+    /// let rgb = [255, 0, 0].repeat(4);
+    /// let frame = YuvFrame::from_rgb(&rgb, 2, 2, YuvFormat::I420, YuvColorSpace::BT709).unwrap();
+    /// assert_eq!(frame.buffer().len(), 6);
+    /// let mut back = [0u8; 12];
+    /// frame.to_rgb(&mut back);
+    /// assert!(back.chunks(3).all(|pixel| pixel[0] >= 250 && pixel[1] <= 5 && pixel[2] <= 5));
+    /// ```
+    pub fn from_rgb(
+        rgb: &[u8],
+        width: usize,
+        height: usize,
+        format: YuvFormat,
+        color_space: YuvColorSpace,
+    ) -> Result<Self, YuvError> {
+        let (_, len) = packed_planes(format, width, height)?;
+        let mut frame =
+            Self::new(vec![0; len], format, width, height)?.with_color_space(color_space);
+        frame.write_rgb(rgb);
+        Ok(frame)
+    }
+}
+
+/// The planes of a frame laid out one after another with rows without padding, and the size of the buffer.
+fn packed_planes(
+    format: YuvFormat,
+    width: usize,
+    height: usize,
+) -> Result<([YuvPlane; 3], usize), YuvError> {
+    let mut planes = [YuvPlane::default(); 3];
+    let mut offset = 0usize;
+    for (index, plane) in planes.iter_mut().enumerate().take(format.plane_count()) {
+        let (samples, rows, bytes) = format.plane_dims(index, width, height);
+        let stride = samples.checked_mul(bytes).ok_or(YuvError::Overflow)?;
+        *plane = YuvPlane { offset, stride };
+        offset = stride
+            .checked_mul(rows)
+            .and_then(|size| size.checked_add(offset))
+            .ok_or(YuvError::Overflow)?;
+    }
+    Ok((planes, offset))
+}
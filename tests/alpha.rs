@@ -0,0 +1,40 @@
+#![cfg(all(feature = "ndarray", feature = "image"))]
+
+use image::{ImageBuffer, LumaA, Rgba, RgbaImage};
+use nshare::{AsNdarray3Alpha, AsNdarray3AlphaMut, IntoImageWithAlpha};
+
+#[test]
+fn compositing_over_an_opaque_background() {
+    let mut background = RgbaImage::from_pixel(3, 2, Rgba([0, 0, 200, 255]));
+    let foreground = RgbaImage::from_fn(3, 2, |x, _| Rgba([255, 0, 0, (x * 255 / 2) as u8]));
+    let (src_color, src_alpha) = foreground.as_ndarray3_alpha();
+    let (mut dst_color, _) = background.as_ndarray3_alpha_mut();
+    for (mut dst, src) in dst_color.outer_iter_mut().zip(src_color.outer_iter()) {
+        ndarray::Zip::from(&mut dst)
+            .and(&src)
+            .and(&src_alpha)
+            .for_each(|d, &s, &a| {
+                let a = u16::from(a);
+                *d = ((u16::from(s) * a + u16::from(*d) * (255 - a)) / 255) as u8;
+            });
+    }
+    assert_eq!(background[(0, 1)], Rgba([0, 0, 200, 255]));
+    assert_eq!(background[(1, 1)], Rgba([127, 0, 100, 255]));
+    assert_eq!(background[(2, 0)], Rgba([255, 0, 0, 255]));
+}
+
+#[test]
+fn wide_luma_alpha_survives_a_round_trip() {
+    let img = ImageBuffer::from_fn(4, 3, |x, y| LumaA([x as u16 * 1000, y as u16 * 20000]));
+    let (color, alpha) = img.as_ndarray3_alpha();
+    let back: ImageBuffer<LumaA<u16>, _> = (color, alpha).into_image_with_alpha();
+    assert_eq!(back, img);
+}
+
+#[test]
+#[should_panic(expected = "the dimensions of the colors")]
+fn mismatched_alpha_is_rejected() {
+    let color = ndarray::Array3::<u8>::zeros((3, 2, 2));
+    let alpha = ndarray::Array2::<u8>::zeros((2, 3));
+    let _: RgbaImage = (color.view(), alpha.view()).into_image_with_alpha();
+}
@@ -0,0 +1,55 @@
+#![cfg(all(feature = "image", feature = "ndarray"))]
+
+use image::{Delay, Frame, Rgba, RgbaImage};
+use nshare::{ChannelOrder, IntoImageFrames, IntoNdarray4Frames};
+
+#[test]
+fn opaque_frames_round_trip_in_both_orders() {
+    // Opaque frames replace the canvas completely, so compositing gives back the same pixels.
+    let arr = ndarray::Array4::from_shape_fn((3, 2, 3, 4), |(t, y, x, c)| match c {
+        3 => 255,
+        _ => (t * 64 + y * 16 + x * 4 + c) as u8,
+    });
+    let delays: Vec<_> = (1..=3)
+        .map(|ms| Delay::from_numer_denom_ms(ms * 10, 1))
+        .collect();
+
+    let frames = arr.view().into_image_frames(&delays, ChannelOrder::Hwc);
+    let (out, out_delays) = frames.as_slice().into_ndarray4_frames(ChannelOrder::Hwc);
+    assert_eq!(out, arr);
+    assert_eq!(out_delays, delays);
+
+    let chw = arr.view().permuted_axes([0, 3, 1, 2]);
+    let frames = chw.into_image_frames(&delays, ChannelOrder::Chw);
+    let (out, _) = frames.as_slice().into_ndarray4_frames(ChannelOrder::Chw);
+    assert_eq!(out, chw);
+}
+
+#[test]
+fn partial_frames_are_composited_onto_the_canvas() {
+    let delay = Delay::from_numer_denom_ms(100, 1);
+    let red = RgbaImage::from_pixel(2, 2, Rgba([255, 0, 0, 255]));
+    let blue = RgbaImage::from_pixel(1, 1, Rgba([0, 0, 255, 255]));
+    let clear = RgbaImage::from_pixel(1, 1, Rgba([0, 255, 0, 0]));
+    let frames = [
+        Frame::from_parts(red, 0, 0, delay),
+        Frame::from_parts(blue, 1, 1, delay),
+        Frame::from_parts(clear, 0, 0, delay),
+    ];
+    let (arr, _) = frames.as_slice().into_ndarray4_frames(ChannelOrder::Hwc);
+    assert_eq!(arr.dim(), (3, 2, 2, 4));
+    // The second frame only covers the bottom right pixel and keeps the rest of the first frame.
+    assert_eq!(
+        arr.slice(ndarray::s![1, 0, 0, ..]).to_vec(),
+        [255, 0, 0, 255]
+    );
+    assert_eq!(
+        arr.slice(ndarray::s![1, 1, 1, ..]).to_vec(),
+        [0, 0, 255, 255]
+    );
+    // A transparent frame leaves the canvas unchanged.
+    assert_eq!(
+        arr.slice(ndarray::s![2, .., .., ..]),
+        arr.slice(ndarray::s![1, .., .., ..])
+    );
+}
@@ -0,0 +1,86 @@
+#![cfg(feature = "ndarray")]
+
+use nshare::{BayerImage, BayerPattern, Demosaic, IntoNdarray3Demosaiced};
+
+/// The samples of a gray scene with a sharp vertical edge, which are the same through every filter color.
+fn gray_edge(width: usize, height: usize) -> Vec<u8> {
+    (0..width * height)
+        .map(|i| if i % width < width / 2 { 40 } else { 220 })
+        .collect()
+}
+
+#[test]
+fn edge_aware_demosaicing_fringes_less_than_bilinear() {
+    for pattern in [
+        BayerPattern::Rggb,
+        BayerPattern::Bggr,
+        BayerPattern::Grbg,
+        BayerPattern::Gbrg,
+    ] {
+        let raw = gray_edge(8, 8);
+        let bayer = BayerImage::new(&raw, 8, 8, pattern).unwrap();
+        // A gray scene has no color, so any difference between the channels is fringing.
+        let fringing = |algorithm| {
+            let rgb = bayer.into_ndarray3_demosaiced(algorithm);
+            (0..8)
+                .flat_map(|y| (0..8).map(move |x| (x, y)))
+                .map(|(x, y)| {
+                    let [r, g, b] = [0, 1, 2].map(|c| i32::from(rgb[(c, y, x)]));
+                    (r - g).abs() + (b - g).abs()
+                })
+                .sum::<i32>()
+        };
+        let bilinear = fringing(Demosaic::Bilinear);
+        let edge_aware = fringing(Demosaic::EdgeAware);
+        assert!(bilinear > 0);
+        assert!(
+            edge_aware < bilinear,
+            "{pattern:?}: {edge_aware} vs {bilinear}"
+        );
+    }
+}
+
+#[test]
+fn tiny_images_demosaic_flat_fields_exactly() {
+    // Each filter color sees its own constant, so every pixel must come out as the same color.
+    let [red, green, blue] = [200u8, 100, 30];
+    for (width, height) in [(2, 2), (2, 4), (4, 2)] {
+        for pattern in [
+            BayerPattern::Rggb,
+            BayerPattern::Bggr,
+            BayerPattern::Grbg,
+            BayerPattern::Gbrg,
+        ] {
+            let (top, bottom) = match pattern {
+                BayerPattern::Rggb => ([red, green], [green, blue]),
+                BayerPattern::Bggr => ([blue, green], [green, red]),
+                BayerPattern::Grbg => ([green, red], [blue, green]),
+                BayerPattern::Gbrg => ([green, blue], [red, green]),
+            };
+            let raw: Vec<u8> = (0..height)
+                .flat_map(|y| {
+                    (0..width).map(move |x| {
+                        if y % 2 == 0 {
+                            top[x % 2]
+                        } else {
+                            bottom[x % 2]
+                        }
+                    })
+                })
+                .collect();
+            let bayer = BayerImage::new(&raw, width, height, pattern).unwrap();
+            for algorithm in [Demosaic::Bilinear, Demosaic::EdgeAware] {
+                let rgb = bayer.into_ndarray3_demosaiced(algorithm);
+                for (c, expected) in [red, green, blue].into_iter().enumerate() {
+                    assert!(
+                        rgb.index_axis(ndarray::Axis(0), c)
+                            .iter()
+                            .all(|&v| v == expected),
+                        "{pattern:?} {width}x{height} {algorithm:?}: channel {c} is {:?}",
+                        rgb.index_axis(ndarray::Axis(0), c)
+                    );
+                }
+            }
+        }
+    }
+}
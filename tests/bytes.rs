@@ -0,0 +1,93 @@
+#![cfg(feature = "ndarray")]
+
+use nshare::{AsBytes, ByteOrder, BytesError, IntoNdarray2, TypedBytes};
+
+#[test]
+fn typed_bytes_borrow_aligned_native_data() {
+    let values = [1u32, 2, 3, 4, 5, 6, 7];
+    let bytes = values.as_bytes();
+
+    let typed = TypedBytes::<u32, 2>::new(&bytes[4..], [2, 3], ByteOrder::NATIVE).unwrap();
+    assert!(typed.as_strided().is_ok());
+    let arr = typed.into_ndarray2();
+    assert!(arr.is_view());
+    assert_eq!(arr, ndarray::array![[2, 3, 4], [5, 6, 7]]);
+}
+
+#[test]
+fn typed_bytes_copy_misaligned_data() {
+    let values = [0x0102u16, 0x0304, 0x0506, 0x0708, 0x090a];
+    // Skipping one byte of `u16` storage always leaves the elements at odd addresses.
+    let bytes = &values.as_bytes()[1..9];
+
+    let typed = TypedBytes::<u16, 2>::new(bytes, [2, 2], ByteOrder::NATIVE).unwrap();
+    assert_eq!(
+        typed.as_strided().unwrap_err(),
+        BytesError::Misaligned { align: 2 }
+    );
+    let arr = typed.into_ndarray2();
+    assert!(arr.is_owned());
+    let expected: Vec<u16> = bytes
+        .chunks_exact(2)
+        .map(|pair| u16::from_ne_bytes([pair[0], pair[1]]))
+        .collect();
+    assert!(arr.iter().eq(&expected));
+}
+
+#[test]
+fn typed_bytes_swap_foreign_byte_order() {
+    let foreign = match ByteOrder::NATIVE {
+        ByteOrder::Little => ByteOrder::Big,
+        ByteOrder::Big => ByteOrder::Little,
+    };
+    let values = [1u16, 2, 3, 4];
+    let swapped: Vec<u16> = values.iter().map(|x| x.swap_bytes()).collect();
+
+    let typed = TypedBytes::<u16, 2>::new(swapped.as_bytes(), [2, 2], foreign).unwrap();
+    assert_eq!(
+        typed.as_strided().unwrap_err(),
+        BytesError::ByteOrder { order: foreign }
+    );
+    let arr = typed.into_ndarray2();
+    assert!(arr.is_owned());
+    assert_eq!(arr, ndarray::array![[1, 2], [3, 4]]);
+}
+
+#[test]
+fn only_contiguous_arrays_have_bytes() {
+    let arr = ndarray::Array2::from_shape_fn((3, 4), |(row, col)| (row * 4 + col) as u8);
+    assert_eq!(arr.as_bytes(), Some(&(0..12).collect::<Vec<u8>>()[..]));
+    // Whole rows are still contiguous, every other column is not.
+    assert_eq!(
+        arr.slice(ndarray::s![1.., ..]).as_bytes(),
+        Some(&(4..12).collect::<Vec<u8>>()[..])
+    );
+    assert_eq!(arr.slice(ndarray::s![.., ..;2]).as_bytes(), None);
+}
+
+#[cfg(feature = "nalgebra")]
+#[test]
+fn typed_bytes_to_nalgebra_borrow_or_copy() {
+    use nshare::IntoNalgebra;
+
+    let values = [1u32, 2, 3, 4, 5, 6, 7];
+    let typed =
+        TypedBytes::<u32, 2>::new(&values.as_bytes()[4..], [2, 3], ByteOrder::NATIVE).unwrap();
+    let m = typed.into_nalgebra();
+    assert!(m.is_view());
+    assert_eq!(m.view(), nalgebra::Matrix2x3::new(2, 3, 4, 5, 6, 7));
+
+    let values = [0x0102u16, 0x0304, 0x0506, 0x0708, 0x090a];
+    let bytes = &values.as_bytes()[1..9];
+    let typed = TypedBytes::<u16, 2>::new(bytes, [2, 2], ByteOrder::NATIVE).unwrap();
+    let m = typed.into_nalgebra();
+    assert!(m.is_owned());
+    let expected: Vec<u16> = bytes
+        .chunks_exact(2)
+        .map(|pair| u16::from_ne_bytes([pair[0], pair[1]]))
+        .collect();
+    assert_eq!(
+        m.into_owned(),
+        nalgebra::DMatrix::from_row_slice(2, 2, &expected)
+    );
+}
@@ -0,0 +1,67 @@
+#![cfg(all(feature = "nalgebra", feature = "ndarray"))]
+
+use nalgebra::Matrix3;
+use nshare::{DepthCamera, IntoNdarray2Depth, IntoNdarray2Points};
+
+fn camera() -> DepthCamera {
+    let intrinsics = Matrix3::new(525.0, 0.0, 319.5, 0.0, 525.0, 239.5, 0.0, 0.0, 1.0);
+    DepthCamera::new(intrinsics, 0.001)
+}
+
+#[test]
+fn depth_maps_survive_projection_and_back_projection() {
+    let camera = camera();
+    let depth = ndarray::Array2::from_shape_fn((48, 64), |(row, col)| {
+        // Leave a border of missing measurements.
+        match row % 10 == 0 || col % 10 == 0 {
+            true => 0u16,
+            false => 800 + (row * 7 + col * 3) as u16,
+        }
+    });
+    let points = depth.view().into_ndarray2_points(&camera);
+    assert_eq!(points.nrows(), depth.iter().filter(|&&d| d != 0).count());
+    let projected = points.view().into_ndarray2_depth(&camera, depth.dim());
+    for (&raw, &back) in depth.iter().zip(&projected) {
+        assert!((f32::from(raw) - back).abs() < 1e-2, "{raw} {back}");
+    }
+}
+
+#[test]
+fn depths_outside_the_range_are_skipped() {
+    let camera = DepthCamera {
+        min_depth: 0.5,
+        max_depth: 2.0,
+        ..camera()
+    };
+    let depth = ndarray::array![[100u16, 500, 1000, 2000, 3000, u16::MAX]];
+    let points = depth.view().into_ndarray2_points(&camera);
+    assert_eq!(points.column(2), ndarray::array![0.5, 1.0, 2.0]);
+}
+
+#[cfg(feature = "image")]
+#[test]
+#[should_panic(expected = "the dimensions of the depth map")]
+fn misaligned_colors_are_rejected() {
+    use nshare::IntoNdarray2PointsColored;
+
+    let depth = ndarray::Array2::<f32>::ones((4, 4));
+    depth
+        .view()
+        .into_ndarray2_points_colored(&camera(), &image::RgbImage::new(4, 3));
+}
+
+#[cfg(feature = "image")]
+#[test]
+fn depth_images_round_trip_exactly() {
+    use image::{ImageBuffer, Luma};
+    use nshare::IntoImageDepth;
+
+    let camera = camera();
+    let depth = ImageBuffer::<Luma<u16>, _>::from_fn(64, 48, |x, y| match (x + y) % 7 {
+        0 => Luma([0]),
+        _ => Luma([700 + (x * 11 + y * 5) as u16]),
+    });
+    let points = depth.into_ndarray2_points(&camera);
+    let back = points.view().into_image_depth(&camera, depth.dimensions());
+    assert_eq!(back, depth);
+}
@@ -0,0 +1,42 @@
+#![cfg(all(
+    feature = "embedded-graphics",
+    feature = "nalgebra",
+    target_endian = "little"
+))]
+
+use embedded_graphics::framebuffer::{buffer_size, Framebuffer};
+use embedded_graphics::image::GetPixel;
+use embedded_graphics::pixelcolor::raw::{LittleEndian, RawU16};
+use embedded_graphics::pixelcolor::Rgb565;
+use embedded_graphics::prelude::*;
+use nshare::IntoNalgebra;
+
+type Display = Framebuffer<Rgb565, RawU16, LittleEndian, 8, 4, { buffer_size::<Rgb565>(8, 4) }>;
+
+#[repr(C, align(2))]
+struct Layout {
+    even: Display,
+    pad: u8,
+    odd: Display,
+}
+
+#[test]
+fn rgb565_framebuffers_are_viewed_as_raw_words() {
+    let mut layout = Layout {
+        even: Display::new(),
+        pad: 0,
+        odd: Display::new(),
+    };
+    layout.even.set_pixel(Point::new(5, 3), Rgb565::GREEN);
+    let m = (&layout.even).into_nalgebra().unwrap();
+    assert_eq!(m[(3, 5)], RawU16::from(Rgb565::GREEN).into_inner());
+    assert_eq!(m.iter().filter(|&&word| word != 0).count(), 1);
+
+    (&mut layout.even).into_nalgebra().unwrap()[(0, 7)] = RawU16::from(Rgb565::RED).into_inner();
+    assert_eq!(layout.even.pixel(Point::new(7, 0)), Some(Rgb565::RED));
+
+    // The second framebuffer starts at an odd address, where no `u16` can be.
+    assert_eq!(layout.pad, 0);
+    assert!((&layout.odd).into_nalgebra().is_none());
+    assert!((&mut layout.odd).into_nalgebra().is_none());
+}
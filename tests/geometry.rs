@@ -0,0 +1,60 @@
+#![cfg(all(feature = "nalgebra", feature = "ndarray"))]
+
+use nalgebra::{Isometry3, Matrix3, Rotation3, Similarity3, UnitQuaternion, Vector3};
+use nshare::{AsNdarray2, GeometryError, IntoNalgebraGeometry, IntoNdarray2, IntoNdarray3};
+
+fn poses() -> Vec<Isometry3<f64>> {
+    (0..5)
+        .map(|i| {
+            let i = i as f64;
+            Isometry3::new(
+                Vector3::new(i, -i, 2.0 * i),
+                Vector3::new(0.1 * i, 0.2, -0.3 * i),
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn batches_round_trip() {
+    let poses = poses();
+    let arr = poses.as_slice().into_ndarray3();
+    assert_eq!(arr.dim(), (5, 4, 4));
+    let back: Vec<Isometry3<f64>> = arr.view().into_nalgebra_geometry().unwrap();
+    for (pose, back) in poses.iter().zip(&back) {
+        assert!((pose.to_homogeneous() - back.to_homogeneous()).amax() < 1e-12);
+    }
+
+    let rotations: Vec<_> = poses.iter().map(|pose| pose.rotation).collect();
+    let arr = rotations.as_slice().into_ndarray2();
+    let back: Vec<UnitQuaternion<f64>> = arr.view().into_nalgebra_geometry().unwrap();
+    assert_eq!(back, rotations);
+}
+
+#[test]
+fn rounded_rotations_are_accepted() {
+    // Rotations written out with a few digits are close enough for single precision.
+    let rotation = Rotation3::from_euler_angles(0.1f32, 0.2, 0.3);
+    let rounded = rotation
+        .as_ndarray2()
+        .mapv(|value| (value * 1e5).round() / 1e5);
+    let back: Rotation3<f32> = rounded.view().into_nalgebra_geometry().unwrap();
+    assert!((back.matrix() - rotation.matrix()).amax() < 1e-4);
+    // The rounding is not carried into the rotation, which is orthonormal again.
+    let identity = back * back.inverse();
+    assert!((identity.matrix() - Matrix3::identity()).amax() <= f32::EPSILON);
+}
+
+#[test]
+fn errors_are_reported() {
+    let mut arr = poses()[1].into_ndarray2();
+    arr[(0, 0)] *= 1.1;
+    let iso: Result<Isometry3<f64>, _> = arr.view().into_nalgebra_geometry();
+    assert_eq!(iso, Err(GeometryError::NotRotation));
+    let sim: Result<Similarity3<f64>, _> = arr.view().into_nalgebra_geometry();
+    assert_eq!(sim, Err(GeometryError::NotRotation));
+
+    arr[(3, 0)] = 1.0;
+    let iso: Result<Isometry3<f64>, _> = arr.view().into_nalgebra_geometry();
+    assert_eq!(iso, Err(GeometryError::NotAffine));
+}
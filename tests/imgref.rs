@@ -0,0 +1,19 @@
+#![cfg(all(feature = "imgref", feature = "ndarray"))]
+
+use ndarray::Axis;
+use nshare::IntoImgref;
+
+#[test]
+fn split_views_have_no_imgref() {
+    let mut arr = ndarray::Array2::<u8>::zeros((3, 4));
+    let (left, right) = arr.view_mut().split_at(Axis(1), 2);
+    // Each half skips over the other between its rows, so neither can lend a buffer.
+    assert!(left.into_imgref().is_none());
+    assert!(right.view().into_imgref().is_none());
+
+    let (top, bottom) = arr.view_mut().split_at(Axis(0), 1);
+    let mut top = top.into_imgref().unwrap();
+    top[(3usize, 0usize)] = 7;
+    assert_eq!(bottom.into_imgref().unwrap().buf().len(), 8);
+    assert_eq!(arr[(0, 3)], 7);
+}
@@ -0,0 +1,45 @@
+#![cfg(all(feature = "ndarray", feature = "image", feature = "nalgebra"))]
+
+use image::{Rgb, RgbImage};
+use nalgebra::{DMatrix, Point2};
+use nshare::{AsNdarrayLabeled, Channel, Col, IntoNdarrayLabeled, Row};
+
+fn gradient() -> RgbImage {
+    RgbImage::from_fn(5, 3, |x, y| Rgb([x as u8, y as u8, (x * y) as u8]))
+}
+
+#[test]
+fn pixels_are_found_by_label_in_every_layout() {
+    let img = gradient();
+    let planar = img.as_ndarray_labeled();
+    let interleaved = planar.view().permute_to::<(Row, Col, Channel)>();
+    let columns_first = planar.view().permute_to::<(Col, Channel, Row)>();
+    for (x, y, pixel) in img.enumerate_pixels() {
+        let xy = (x as usize, y as usize);
+        assert_eq!(planar.pixel(xy).unwrap().to_vec(), pixel.0);
+        assert_eq!(interleaved.pixel(xy).unwrap().to_vec(), pixel.0);
+        assert_eq!(
+            columns_first
+                .pixel(Point2::new(xy.0, xy.1))
+                .unwrap()
+                .to_vec(),
+            pixel.0
+        );
+    }
+    assert!(planar.pixel((5, 0)).is_none());
+    assert!(interleaved.pixel((0, 3)).is_none());
+}
+
+#[test]
+fn owned_arrays_keep_their_labels() {
+    let labeled = gradient()
+        .into_ndarray_labeled()
+        .permute_to::<(Row, Col, Channel)>();
+    assert_eq!(labeled.axis::<Channel>(), ndarray::Axis(2));
+    assert_eq!(labeled.as_array().dim(), (3, 5, 3));
+
+    let m = DMatrix::from_fn(2, 3, |row, col| row * 10 + col);
+    let labeled = m.clone().into_ndarray_labeled();
+    assert_eq!(labeled.get((2, 1)), Some(&m[(1, 2)]));
+    assert_eq!(labeled.get((1, 2)), None);
+}
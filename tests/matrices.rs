@@ -0,0 +1,49 @@
+#![cfg(all(feature = "nalgebra", feature = "ndarray"))]
+
+use nalgebra::Matrix2x3;
+use nshare::{IntoNalgebraMatrices, IntoNdarray3};
+
+fn matrices() -> Vec<Matrix2x3<i32>> {
+    (0..4)
+        .map(|i| Matrix2x3::from_fn(|r, c| i * 100 + r as i32 * 10 + c as i32))
+        .collect()
+}
+
+#[test]
+fn views_and_copies_agree() {
+    let matrices = matrices();
+    let view = matrices.as_slice().into_ndarray3();
+    let owned = matrices.clone().into_ndarray3();
+    assert_eq!(view, owned);
+    for ((i, r, c), &value) in owned.indexed_iter() {
+        assert_eq!(matrices[i][(r, c)], value);
+    }
+    // The standard layout copy is row-major within each matrix, but is still read back correctly.
+    let back: Vec<Matrix2x3<i32>> = owned.into_nalgebra_matrices().unwrap();
+    assert_eq!(back, matrices);
+}
+
+#[test]
+fn views_share_memory_with_the_matrices() {
+    let matrices = matrices();
+    let view = matrices.as_slice().into_ndarray3();
+    let back: &[Matrix2x3<i32>] = view.into_nalgebra_matrices();
+    assert_eq!(back.as_ptr(), matrices.as_ptr());
+}
+
+#[test]
+#[should_panic(expected = "column-major and contiguous")]
+fn gaps_between_matrices_are_rejected() {
+    let matrices = matrices();
+    let view = matrices.as_slice().into_ndarray3();
+    let _: &[Matrix2x3<i32>] = view
+        .slice_move(ndarray::s![..;2, .., ..])
+        .into_nalgebra_matrices();
+}
+
+#[test]
+#[should_panic(expected = "column-major and contiguous")]
+fn row_major_views_are_rejected() {
+    let arr = matrices().into_ndarray3();
+    let _: &[Matrix2x3<i32>] = arr.view().into_nalgebra_matrices();
+}
@@ -0,0 +1,13 @@
+#![cfg(all(feature = "image", feature = "ndarray"))]
+
+use image::GrayImage;
+use nshare::{IntoNdarray3Normalized, Normalization};
+
+#[test]
+#[should_panic(
+    expected = "the normalization mean must be empty, a single value or one value per channel, \
+                           but it has 3 values for 1 channel"
+)]
+fn rgb_normalization_of_a_gray_image_panics() {
+    GrayImage::new(2, 2).into_ndarray3_normalized(&Normalization::IMAGENET);
+}
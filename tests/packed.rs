@@ -0,0 +1,43 @@
+#![cfg(feature = "ndarray")]
+
+use nshare::{IntoNdarray2, IntoPackedRaw, PackedError, PackedFormat, PackedRawImage, RawPacking};
+
+#[test]
+fn packed_formats_round_trip_every_pixel() {
+    for format in [
+        PackedFormat::Rgb565,
+        PackedFormat::Bgr565,
+        PackedFormat::Argb1555,
+    ] {
+        // Every format uses all 16 bits, so every pixel must survive unpacking and packing again.
+        for pixel in 0..=u16::MAX {
+            assert_eq!(format.pack(format.unpack(pixel)), pixel, "{format:?}");
+        }
+    }
+}
+
+#[test]
+fn raw_packing_round_trips_with_incomplete_groups() {
+    for packing in [RawPacking::Raw10, RawPacking::Raw12] {
+        let max = (1u16 << packing.bits()) - 1;
+        for width in 1..=9 {
+            let arr = ndarray::Array2::from_shape_fn((3, width), |(y, x)| {
+                ((y * 977 + x * 131) as u16) & max
+            });
+            let packed = arr.view().into_packed_raw(packing);
+            let raw = PackedRawImage::new(&packed, width, 3, packing).unwrap();
+            assert_eq!(raw.into_ndarray2(), arr, "{packing:?} {width}");
+        }
+    }
+}
+
+#[test]
+fn huge_packed_rows_overflow_instead_of_wrapping() {
+    for packing in [RawPacking::Raw10, RawPacking::Raw12] {
+        assert_eq!(
+            PackedRawImage::new(&[], usize::MAX, 1, packing).unwrap_err(),
+            PackedError::Overflow,
+            "{packing:?}"
+        );
+    }
+}
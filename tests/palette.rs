@@ -0,0 +1,33 @@
+#![cfg(feature = "palette")]
+
+use ndarray::{s, Array2, ArrayView2};
+use nshare::{IntoNdarrayComponents, IntoPalette};
+use palette::LinSrgb;
+
+#[test]
+fn palette_views_follow_inverted_and_sliced_axes() {
+    let grid = Array2::from_shape_fn((3, 4), |(row, col)| {
+        LinSrgb::new(row as f32, col as f32, (row * 4 + col) as f32)
+    });
+    let view = grid.slice(s![..;-1, 1..;2]);
+
+    let components = view.into_ndarray_components();
+    assert_eq!(components.dim(), (3, 2, 3));
+    for ((row, col), color) in view.indexed_iter() {
+        assert!(components.slice(s![row, col, ..]).iter().eq(&[
+            color.red,
+            color.green,
+            color.blue
+        ]));
+    }
+
+    let colors: ArrayView2<LinSrgb<f32>> = components.into_palette();
+    assert_eq!(colors, view);
+}
+
+#[test]
+#[should_panic(expected = "adjacent")]
+fn palette_views_reject_split_components() {
+    let arr = Array2::<f32>::zeros((3, 2));
+    let _: ndarray::ArrayView1<LinSrgb<f32>> = arr.t().into_palette();
+}
@@ -0,0 +1,45 @@
+#![cfg(all(feature = "nalgebra", feature = "ndarray"))]
+
+use nalgebra::{Point3, Vector3};
+use nshare::{IntoNalgebra, IntoNalgebraPoints, IntoNdarray2};
+
+#[test]
+fn point_views_share_memory_with_the_points() {
+    let mut points: Vec<_> = (0..5)
+        .map(|i| Point3::new(i as f32, 10.0 * i as f32, 100.0 * i as f32))
+        .collect();
+    let ptr = points.as_ptr().cast::<f32>();
+    assert_eq!(points.as_slice().into_ndarray2().as_ptr(), ptr);
+    assert_eq!(points.as_slice().into_nalgebra().as_ptr(), ptr);
+
+    let arr = points.as_mut_slice().into_ndarray2();
+    let back: &mut [Point3<f32>] = arr.into_nalgebra_points();
+    assert_eq!(back.as_ptr().cast::<f32>(), ptr);
+    back[4].z = -1.0;
+    assert_eq!(points[4], Point3::new(4.0, 40.0, -1.0));
+}
+
+#[test]
+fn vector_views_match_the_matrix_of_columns() {
+    let vectors: Vec<_> = (0..4).map(|i| Vector3::new(i, i + 1, i + 2)).collect();
+    let arr = vectors.as_slice().into_ndarray2();
+    let m = vectors.as_slice().into_nalgebra();
+    assert_eq!(m, nalgebra::Matrix3xX::from_columns(&vectors));
+    for ((i, j), &value) in arr.indexed_iter() {
+        assert_eq!(m[(j, i)], value);
+    }
+}
+
+#[test]
+#[should_panic(expected = "standard layout")]
+fn transposed_arrays_are_not_viewed_as_points() {
+    let arr = ndarray::Array2::<f32>::zeros((3, 3));
+    let _: &[Point3<f32>] = arr.t().into_nalgebra_points();
+}
+
+#[test]
+#[should_panic(expected = "one column per coordinate")]
+fn arrays_of_the_wrong_width_are_not_viewed_as_points() {
+    let arr = ndarray::Array2::<f32>::zeros((3, 2));
+    let _: &[Point3<f32>] = arr.view().into_nalgebra_points();
+}
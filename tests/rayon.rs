@@ -0,0 +1,90 @@
+//! The parallel copies must give exactly the same results as the serial conversions.
+#![cfg(feature = "rayon")]
+
+use image::{Rgb, RgbImage};
+use ndarray::{Array2, Array3};
+use nshare::{
+    ChannelOrder, IntoImage, IntoImagePar, IntoImageQuantized, IntoImageQuantizedPar, IntoNalgebra,
+    IntoNalgebraPar, IntoNdarray3, IntoNdarray3Normalized, IntoNdarray3NormalizedPar,
+    IntoNdarray3Planar, IntoNdarray3PlanarPar, Normalization, Quantization, Scaling,
+};
+use std::rc::Rc;
+
+#[test]
+fn array2_into_dmatrix_matches_serial() {
+    let arr = Array2::from_shape_fn((123, 457), |(row, col)| (row * 1000 + col) as f64);
+    let m = arr.clone().into_nalgebra_par();
+    assert_eq!(m, arr.clone().into_nalgebra());
+    assert_eq!(m.shape(), arr.dim());
+    for ((row, col), &value) in arr.indexed_iter() {
+        assert_eq!(m[(row, col)], value);
+    }
+}
+
+#[test]
+fn normalized_image_matches_serial() {
+    let img = RgbImage::from_fn(311, 97, |x, y| Rgb([x as u8, y as u8, (x ^ y) as u8]));
+    for order in [ChannelOrder::Chw, ChannelOrder::Hwc] {
+        let normalization = Normalization {
+            order,
+            ..Normalization::IMAGENET
+        };
+        let nd = img.into_ndarray3_normalized_par(&normalization);
+        assert_eq!(nd, img.into_ndarray3_normalized(&normalization));
+        for (x, y, pixel) in img.enumerate_pixels() {
+            for c in 0..3 {
+                let expected = (pixel[c] as f32 * (1.0 / (255.0 * normalization.std[c])))
+                    - normalization.mean[c] / normalization.std[c];
+                let (x, y) = (x as usize, y as usize);
+                let actual = match order {
+                    ChannelOrder::Chw => nd[(c, y, x)],
+                    ChannelOrder::Hwc => nd[(y, x, c)],
+                };
+                assert_eq!(actual.to_bits(), expected.to_bits());
+            }
+        }
+    }
+}
+
+#[test]
+fn quantized_image_matches_serial() {
+    let arr = Array3::from_shape_fn((3, 97, 311), |(c, row, col)| {
+        ((c + 1) * row * col) as f32 / 1000.0 - 5.0
+    });
+    let quantization = Quantization {
+        scaling: Scaling::AutoChannel,
+        ..Quantization::UNIT
+    };
+    let img: RgbImage = arr.view().into_image_quantized_par(&quantization);
+    assert_eq!(img, arr.view().into_image_quantized(&quantization));
+    for c in 0..3 {
+        let channel = arr.index_axis(ndarray::Axis(0), c);
+        let min = channel.iter().cloned().fold(f32::INFINITY, f32::min) as f64;
+        let max = channel.iter().cloned().fold(f32::NEG_INFINITY, f32::max) as f64;
+        for ((row, col), &value) in channel.indexed_iter() {
+            let scaled = (value as f64 - min) / (max - min) * 255.0;
+            let expected = (scaled + 0.5) as u8;
+            assert_eq!(img[(col as u32, row as u32)][c], expected);
+        }
+    }
+}
+
+#[test]
+fn planar_image_matches_serial() {
+    let img = RgbImage::from_fn(311, 97, |x, y| Rgb([x as u8, y as u8, (x ^ y) as u8]));
+    let planar = img.into_ndarray3_planar_par();
+    assert!(planar.is_standard_layout());
+    assert_eq!(planar, img.into_ndarray3_planar());
+    assert_eq!(planar, img.clone().into_ndarray3());
+    let back: RgbImage = planar.view().into_image_par();
+    assert_eq!(back, img);
+    assert_eq!(back, planar.view().into_image());
+}
+
+#[test]
+fn serial_conversions_accept_elements_that_are_not_send() {
+    // Enabling `rayon` must not add bounds to the serial conversions.
+    let arr = Array2::from_shape_fn((2, 3), |(row, col)| Rc::new(row * 10 + col));
+    let m = arr.into_nalgebra();
+    assert_eq!(*m[(1, 2)], 12);
+}
@@ -0,0 +1,31 @@
+#![cfg(all(feature = "sprs", feature = "nalgebra-sparse"))]
+
+use nshare::{
+    IntoNalgebra, IntoNalgebraCsc, IntoNalgebraCsr, IntoNdarray2, IntoSprs, IntoSprsWithTolerance,
+};
+
+#[test]
+fn sparse_round_trip_preserves_values() {
+    let arr = ndarray::array![
+        [0.0, 1.5, 0.0, 0.0],
+        [0.0, 0.0, 0.0, 0.0],
+        [-2.0, 0.0, f64::NAN, 3.0],
+    ];
+    let s = arr.view().into_sprs_with_tolerance(0.0);
+    // NaN is never dropped.
+    assert_eq!(s.nnz(), 4);
+
+    let csc = s.clone().into_nalgebra_csc();
+    let csr = csc.into_sprs().into_nalgebra_csr();
+    let back = csr.into_sprs();
+    assert!(back.is_csr());
+    assert_eq!(back.indptr().raw_storage(), s.indptr().raw_storage());
+    assert_eq!(back.indices(), s.indices());
+
+    let dense = back.into_ndarray2();
+    let m = back.into_nalgebra();
+    for ((row, col), &value) in arr.indexed_iter() {
+        assert!(dense[(row, col)] == value || value.is_nan() && dense[(row, col)].is_nan());
+        assert!(m[(row, col)] == value || value.is_nan() && m[(row, col)].is_nan());
+    }
+}
@@ -0,0 +1,44 @@
+#![cfg(feature = "ndarray")]
+
+use ndarray::{s, Array3};
+use nshare::{IntoNdarray2, IntoNdarray3, StridedError, StridedSlice, StridedSliceMut};
+
+#[test]
+fn strided_views_match_sliced_arrays() {
+    let arr = Array3::from_shape_fn((3, 4, 5), |(z, y, x)| z * 100 + y * 10 + x);
+    let data = arr.as_slice().unwrap();
+    // The same layout as `s![1.., ..;-2, 1..4]`, which starts at `(1, 3, 1)`.
+    let s = StridedSlice::new(data, [2, 2, 3], [20, -10, 1], 20 + 15 + 1).unwrap();
+    assert_eq!(s.into_ndarray3(), arr.slice(s![1.., ..;-2, 1..4]));
+}
+
+#[test]
+fn strided_views_reverse_both_axes() {
+    let mut data: Vec<i32> = (0..6).collect();
+    let s = StridedSliceMut::new(&mut data, [2, 3], [-3, -1], 5).unwrap();
+    let mut view = s.into_ndarray2();
+    assert_eq!(view, ndarray::array![[5, 4, 3], [2, 1, 0]]);
+    view[(0, 0)] = -1;
+    assert_eq!(data[5], -1);
+}
+
+#[test]
+fn strided_layouts_are_checked() {
+    let mut data = [0u8; 12];
+    assert_eq!(
+        StridedSlice::new(&data, [3, 4], [-4, 1], 4).unwrap_err(),
+        StridedError::BeforeStart
+    );
+    assert_eq!(
+        StridedSlice::new(&data, [2, usize::MAX], [1, isize::MAX], 0).unwrap_err(),
+        StridedError::Overflow
+    );
+    // Broadcasting is fine for shared views, but not for mutable ones.
+    assert!(StridedSlice::new(&data, [3, 4], [0, 1], 0).is_ok());
+    assert_eq!(
+        StridedSliceMut::new(&mut data, [3, 4], [0, 1], 0).unwrap_err(),
+        StridedError::Aliasing { axis: 0 }
+    );
+    // Empty layouts never alias and only need the offset to be in bounds.
+    assert!(StridedSliceMut::new(&mut data, [0, 4], [0, 0], 12).is_ok());
+}
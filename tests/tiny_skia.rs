@@ -0,0 +1,38 @@
+#![cfg(all(feature = "tiny-skia", feature = "image", feature = "ndarray"))]
+
+use image::{Rgba, RgbaImage};
+use nshare::{IntoImageDemultiplied, IntoNdarray3Demultiplied, IntoPixmap};
+
+#[test]
+fn premultiplication_round_trips() {
+    let img = RgbaImage::from_fn(16, 16, |x, y| {
+        Rgba([(x * 16) as u8, (y * 16) as u8, 200, (x * 16 + y) as u8])
+    });
+    let pixmap = (&img).into_pixmap().unwrap();
+
+    let straight = (&pixmap).into_image_demultiplied();
+    for (before, after) in img.pixels().zip(straight.pixels()) {
+        if before[3] == 255 {
+            assert_eq!(before, after);
+        } else if before[3] != 0 {
+            // Premultiplying loses precision for translucent pixels.
+            let tolerance = 255 / before[3] as i32 + 1;
+            for c in 0..3 {
+                assert!((before[c] as i32 - after[c] as i32).abs() <= tolerance);
+            }
+            assert_eq!(before[3], after[3]);
+        }
+    }
+
+    let arr = (&pixmap).into_ndarray3_demultiplied();
+    assert_eq!(arr.view().into_pixmap().unwrap().data(), pixmap.data());
+}
+
+#[test]
+fn empty_inputs_have_no_pixmap() {
+    for (width, height) in [(0, 0), (0, 5), (5, 0)] {
+        assert!((&RgbaImage::new(width, height)).into_pixmap().is_none());
+        let arr = ndarray::Array3::<f32>::zeros((4, height as usize, width as usize));
+        assert!(arr.view().into_pixmap().is_none());
+    }
+}
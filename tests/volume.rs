@@ -0,0 +1,40 @@
+#![cfg(all(feature = "image", feature = "ndarray"))]
+
+use ndarray::Axis;
+use nshare::{IntoImageSlice, IntoNdarray3};
+
+#[test]
+fn slices_stack_back_into_the_volume() {
+    let volume =
+        ndarray::Array3::from_shape_fn((5, 3, 4), |(z, y, x)| (z * 100 + y * 10 + x) as u16);
+    let slices: Vec<_> = (0..5)
+        .map(|z| volume.view().into_image_slice(Axis(0), z))
+        .collect();
+    assert_eq!(slices.into_ndarray3(), volume);
+}
+
+#[test]
+fn slices_follow_the_remaining_axes() {
+    let volume =
+        ndarray::Array3::from_shape_fn((5, 3, 4), |(z, y, x)| (z * 100 + y * 10 + x) as u16);
+    // A view with reversed strides must give the same slices as the data it refers to.
+    let flipped = volume.slice(ndarray::s![..;-1, .., ..]);
+    for axis in 0..3 {
+        for index in 0..volume.len_of(Axis(axis)) {
+            let img = flipped.into_image_slice(Axis(axis), index);
+            let expected = flipped.index_axis(Axis(axis), index);
+            let (height, width) = expected.dim();
+            assert_eq!(img.dimensions(), (width as u32, height as u32));
+            for ((y, x), &value) in expected.indexed_iter() {
+                assert_eq!(img[(x as u32, y as u32)].0, [value]);
+            }
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "the same dimensions")]
+fn slices_of_different_dimensions_are_rejected() {
+    let slices = vec![image::GrayImage::new(2, 2), image::GrayImage::new(2, 3)];
+    slices.into_ndarray3();
+}
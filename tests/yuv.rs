@@ -0,0 +1,80 @@
+#![cfg(feature = "ndarray")]
+
+use nshare::{YuvColorSpace, YuvFormat, YuvFrame, YuvMatrix, YuvPlane, YuvRange};
+
+#[test]
+fn yuv_round_trips_flat_colors() {
+    let colors = [[0, 0, 0], [255, 255, 255], [200, 30, 90], [20, 180, 240]];
+    for format in [YuvFormat::I420, YuvFormat::Nv12, YuvFormat::Yuyv] {
+        for matrix in [YuvMatrix::Bt601, YuvMatrix::Bt709] {
+            for range in [YuvRange::Limited, YuvRange::Full] {
+                let color_space = YuvColorSpace { matrix, range };
+                for color in colors {
+                    let rgb = color.repeat(4 * 3);
+                    let frame = YuvFrame::from_rgb(&rgb, 4, 3, format, color_space).unwrap();
+                    let mut back = vec![0; rgb.len()];
+                    frame.to_rgb(&mut back);
+                    for (&a, &b) in rgb.iter().zip(&back) {
+                        assert!(
+                            a.abs_diff(b) <= 2,
+                            "{format:?} {color_space:?} {color:?} became {back:?}"
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn yuv_views_skip_row_padding() {
+    // A 3x3 I420 frame with rows padded to 4 bytes, so the chroma planes are 2x2.
+    let mut buffer = vec![0u8; 12 + 8 + 8];
+    for y in 0..3 {
+        for x in 0..3 {
+            buffer[y * 4 + x] = (y * 3 + x) as u8;
+        }
+    }
+    for (i, value) in [10, 11, 12, 13].into_iter().enumerate() {
+        buffer[12 + i / 2 * 4 + i % 2] = value;
+        buffer[20 + i / 2 * 4 + i % 2] = value + 10;
+    }
+    let planes = [
+        YuvPlane {
+            offset: 0,
+            stride: 4,
+        },
+        YuvPlane {
+            offset: 12,
+            stride: 4,
+        },
+        YuvPlane {
+            offset: 20,
+            stride: 4,
+        },
+    ];
+    let frame = YuvFrame::with_planes(&buffer, YuvFormat::I420, 3, 3, &planes).unwrap();
+
+    assert_eq!(
+        frame.y_view(),
+        ndarray::array![[0, 1, 2], [3, 4, 5], [6, 7, 8]]
+    );
+    assert_eq!(frame.u_view(), ndarray::array![[10, 11], [12, 13]]);
+    assert_eq!(frame.v_view(), ndarray::array![[20, 21], [22, 23]]);
+    assert_eq!(frame.sample(2, 2), [8, 13, 23]);
+}
+
+#[test]
+fn empty_planes_view_without_panicking() {
+    let planes = [YuvPlane {
+        offset: 10,
+        stride: 0,
+    }; 3];
+    let frame = YuvFrame::with_planes(&[0u8; 4], YuvFormat::I420, 0, 0, &planes).unwrap();
+    assert_eq!(frame.y_view().dim(), (0, 0));
+    assert_eq!(frame.u_view().dim(), (0, 0));
+    assert_eq!(frame.v_view().dim(), (0, 0));
+    for plane in 0..3 {
+        assert_eq!(frame.plane_view(plane).len(), 0);
+    }
+}